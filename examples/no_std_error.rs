@@ -0,0 +1,60 @@
+//! Builds and formats keebler's error types from a `#![no_std]` crate using
+//! only `core`, guarding against them picking up a dependency on `std`.
+//!
+//! keebler itself still links `std`, so this example requires the `std`
+//! feature until the library can be built without it.
+#![no_std]
+
+use core::fmt::Write;
+use keebler::{FileErr, ValidationError};
+
+/// A fixed-capacity formatting buffer, standing in for an allocator-free
+/// `String`.
+struct Buffer {
+    data: [u8; 128],
+    len: usize,
+}
+
+impl Buffer {
+    fn new() -> Self {
+        Self {
+            data: [0; 128],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.data[..self.len]).unwrap()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        let dest = self.data.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+        dest.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+fn main() {
+    let mut buf = Buffer::new();
+    write!(buf, "{}", FileErr::InvalidFile).unwrap();
+    assert_eq!("not an elf formatted file", buf.as_str());
+
+    let mut buf = Buffer::new();
+    write!(
+        buf,
+        "{}",
+        ValidationError::EhSizeMismatch {
+            expected: 64,
+            found: 52
+        }
+    )
+    .unwrap();
+    assert_eq!(
+        "eh_size 52 does not match the expected header size 64",
+        buf.as_str()
+    );
+}
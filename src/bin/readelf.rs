@@ -5,64 +5,316 @@ use std::fs::File;
 use std::io::prelude::*;
 use std::process;
 
+/// NumberBase selects how offsets, sizes and addresses are rendered in the
+/// header tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberBase {
+    Hex,
+    Decimal,
+}
+
+impl NumberBase {
+    fn format<N: std::fmt::LowerHex + std::fmt::Display>(self, n: N) -> String {
+        match self {
+            Self::Hex => format!("0x{:x}", n),
+            Self::Decimal => format!("{}", n),
+        }
+    }
+}
+
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut base = NumberBase::Hex;
+    let args: Vec<String> = env::args()
+        .filter(|arg| match arg.as_str() {
+            "--decimal" => {
+                base = NumberBase::Decimal;
+                false
+            }
+            "--hex" => {
+                base = NumberBase::Hex;
+                false
+            }
+            _ => true,
+        })
+        .collect();
     let args_len = args.len();
 
-    match args_len {
-        2 => read_file(&args[1]).expect("Unable to open file"),
+    let result = match args_len {
+        2 => read_file(&args[1], |input| {
+            parse_and_print_formatted_header(input, base)
+        }),
+        3 if args[1] == "-n" => read_file(&args[2], print_notes),
+        3 if args[1] == "-S" => read_file(&args[2], |input| print_section_headers(input, base, 0)),
+        4 if args[1] == "-S" => match section_flag_mask(&args[2]) {
+            Ok(mask) => read_file(&args[3], |input| print_section_headers(input, base, mask)),
+            Err(error) => Err(error),
+        },
+        4 if args[1] == "-p" => match args[2].parse::<usize>() {
+            Ok(index) => read_file(&args[3], |input| dump_segment(input, index)),
+            Err(_) => Err(format!("invalid segment index: {}", args[2])),
+        },
         _ => {
-            println!("Usage: readelf [file]");
+            println!("Usage: readelf [--decimal|--hex] [-n] [-S [WAX]] [-p segment-index] [file]");
             process::exit(64);
         }
+    };
+
+    if let Err(error) = result {
+        eprintln!("readelf: {}", error);
+        process::exit(1);
     }
 }
 
-fn read_file(filename: &str) -> Result<(), String> {
+fn read_file<F>(filename: &str, print: F) -> Result<(), String>
+where
+    F: Fn(&[u8]) -> Result<(), String>,
+{
     let mut f = File::open(filename).expect("file not found");
 
     let mut contents = Vec::new();
     match f.read_to_end(&mut contents) {
-        Ok(_) => parse_and_print_formatted_header(&contents),
+        Ok(_) => print(&contents),
         Err(error) => Err(format!("error: {}", error)),
     }
 }
 
-fn parse_and_print_formatted_header(input: &[u8]) -> Result<(), String> {
-    let ident = EiIdentParser.parse(input)?.unwrap();
+/// Unwraps a parser match, reporting input the parser rejected as a non-ELF
+/// file rather than panicking.
+fn elf_match<T>(status: MatchStatus<&[u8], T>) -> Result<T, String> {
+    match status {
+        MatchStatus::Match((_, value)) => Ok(value),
+        MatchStatus::NoMatch(_) => Err("not an ELF file".to_string()),
+    }
+}
+
+fn dump_segment(input: &[u8], index: usize) -> Result<(), String> {
+    let ident = elf_match(EiIdentParser::new().parse(input)?)?;
+    let (vaddr, data) = match (ident.ei_class, ident.ei_data) {
+        (EiClass::ThirtyTwoBit, EiData::Little) => {
+            let eh = elf_match(ElfHeaderParser::elf32_le().parse(input)?)?;
+            segment_data(&eh.program_headers, index, input)?
+        }
+        (EiClass::ThirtyTwoBit, EiData::Big) => {
+            let eh = elf_match(ElfHeaderParser::elf32_be().parse(input)?)?;
+            segment_data(&eh.program_headers, index, input)?
+        }
+        (EiClass::SixtyFourBit, EiData::Little) => {
+            let eh = elf_match(ElfHeaderParser::elf64_le().parse(input)?)?;
+            segment_data(&eh.program_headers, index, input)?
+        }
+        (EiClass::SixtyFourBit, EiData::Big) => {
+            let eh = elf_match(ElfHeaderParser::elf64_be().parse(input)?)?;
+            segment_data(&eh.program_headers, index, input)?
+        }
+    };
+
+    if data.is_empty() {
+        println!("Segment {} has no data to dump.", index);
+        return Ok(());
+    }
+
+    println!("\nHex dump of segment {}:", index);
+    for (line, chunk) in data.chunks(16).enumerate() {
+        let hex: Vec<String> = chunk
+            .chunks(4)
+            .map(|word| word.iter().map(|b| format!("{:02x}", b)).collect())
+            .collect();
+        let ascii: String = chunk
+            .iter()
+            .map(|&b| if b.is_ascii_graphic() { b as char } else { '.' })
+            .collect();
+
+        println!(
+            "  0x{:08x} {: <36}{}",
+            vaddr.wrapping_add(line as u64 * 16),
+            hex.join(" "),
+            ascii
+        );
+    }
+
+    Ok(())
+}
+
+fn segment_data<'a, P: ProgramHeader>(
+    headers: &[P],
+    index: usize,
+    input: &'a [u8],
+) -> Result<(u64, &'a [u8]), String> {
+    let header = headers.get(index).ok_or_else(|| {
+        format!(
+            "segment index {} out of range, file has {} segments",
+            index,
+            headers.len()
+        )
+    })?;
+
+    header
+        .data(input)
+        .map(|data| (header.p_vaddr(), data))
+        .ok_or_else(|| format!("segment {} extends past the end of the file", index))
+}
+
+fn print_notes(input: &[u8]) -> Result<(), String> {
+    let ident = elf_match(EiIdentParser::new().parse(input)?)?;
+    let notes = match (ident.ei_class, ident.ei_data) {
+        (EiClass::ThirtyTwoBit, EiData::Little) => {
+            elf_match(ElfHeaderParser::elf32_le().parse(input)?)?.notes(input)
+        }
+        (EiClass::ThirtyTwoBit, EiData::Big) => {
+            elf_match(ElfHeaderParser::elf32_be().parse(input)?)?.notes(input)
+        }
+        (EiClass::SixtyFourBit, EiData::Little) => {
+            elf_match(ElfHeaderParser::elf64_le().parse(input)?)?.notes(input)
+        }
+        (EiClass::SixtyFourBit, EiData::Big) => {
+            elf_match(ElfHeaderParser::elf64_be().parse(input)?)?.notes(input)
+        }
+    };
+
+    if notes.is_empty() {
+        println!("\nThere are no notes in this file.");
+        return Ok(());
+    }
+
+    println!("\nDisplaying notes:");
+    println!("  {: <20} {: <10}\tDescription", "Owner", "Data size");
+    for note in notes.iter() {
+        println!(
+            "  {: <20} 0x{:08x}\t{}",
+            String::from_utf8_lossy(note.name),
+            note.desc.len(),
+            note_type_name(note)
+        );
+        if let Some(description) = describe_note(ident.ei_data, note) {
+            println!("    {}", description);
+        }
+    }
+
+    Ok(())
+}
+
+fn note_type_name(note: &Note) -> String {
+    match (note.name, note.n_type) {
+        (b"GNU", NT_GNU_ABI_TAG) => "NT_GNU_ABI_TAG (ABI version tag)".to_string(),
+        (b"GNU", NT_GNU_BUILD_ID) => "NT_GNU_BUILD_ID (unique build ID bitstring)".to_string(),
+        (b"CORE", NT_AUXV) => "NT_AUXV (auxiliary vector)".to_string(),
+        (_, n_type) => format!("Unknown note type: (0x{:08x})", n_type),
+    }
+}
+
+fn describe_note(encoding: EiData, note: &Note) -> Option<String> {
+    match (note.name, note.n_type) {
+        (b"GNU", NT_GNU_BUILD_ID) => Some(format!(
+            "Build ID: {}",
+            note.desc
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        )),
+        (b"GNU", NT_GNU_ABI_TAG) if note.desc.len() >= 16 => {
+            let words: Vec<u32> = note.desc[..16]
+                .chunks(4)
+                .map(|word| {
+                    let bytes = [word[0], word[1], word[2], word[3]];
+                    match encoding {
+                        EiData::Little => u32::from_le_bytes(bytes),
+                        EiData::Big => u32::from_be_bytes(bytes),
+                    }
+                })
+                .collect();
+            let os = match words[0] {
+                0 => "Linux".to_string(),
+                1 => "Hurd".to_string(),
+                2 => "Solaris".to_string(),
+                3 => "FreeBSD".to_string(),
+                os => format!("Unknown ({})", os),
+            };
+
+            Some(format!(
+                "OS: {}, ABI: {}.{}.{}",
+                os, words[1], words[2], words[3]
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// Converts a filter such as `AX` into the sh_flags bits a section must carry
+/// to be listed, following the flag key letters used by binutils' readelf.
+fn section_flag_mask(filter: &str) -> Result<u64, String> {
+    filter.chars().try_fold(0, |mask, flag| match flag {
+        'W' => Ok(mask | SHF_WRITE),
+        'A' => Ok(mask | SHF_ALLOC),
+        'X' => Ok(mask | SHF_EXECINSTR),
+        _ => Err(format!("invalid section flag filter: {}", filter)),
+    })
+}
+
+/// Prints the section headers carrying every flag in `mask`, or all section
+/// headers for an empty mask.
+fn print_section_headers(input: &[u8], base: NumberBase, mask: u64) -> Result<(), String> {
+    let ident = elf_match(EiIdentParser::new().parse(input)?)?;
+    match (ident.ei_class, ident.ei_data) {
+        (EiClass::ThirtyTwoBit, EiData::Little) => {
+            let eh = elf_match(ElfHeaderParser::elf32_le().parse(input)?)?;
+            let headers = filter_sections(&eh.section_headers, mask);
+            print_formatted_32bit_section_header(&headers, base);
+        }
+        (EiClass::ThirtyTwoBit, EiData::Big) => {
+            let eh = elf_match(ElfHeaderParser::elf32_be().parse(input)?)?;
+            let headers = filter_sections(&eh.section_headers, mask);
+            print_formatted_32bit_section_header(&headers, base);
+        }
+        (EiClass::SixtyFourBit, EiData::Little) => {
+            let eh = elf_match(ElfHeaderParser::elf64_le().parse(input)?)?;
+            let headers = filter_sections(&eh.section_headers, mask);
+            print_formatted_64bit_section_header(&headers, base);
+        }
+        (EiClass::SixtyFourBit, EiData::Big) => {
+            let eh = elf_match(ElfHeaderParser::elf64_be().parse(input)?)?;
+            let headers = filter_sections(&eh.section_headers, mask);
+            print_formatted_64bit_section_header(&headers, base);
+        }
+    };
+
+    Ok(())
+}
+
+fn filter_sections<S: SectionHeader + Copy>(headers: &[S], mask: u64) -> Vec<S> {
+    headers
+        .iter()
+        .filter(|h| h.sh_flags() & mask == mask)
+        .copied()
+        .collect()
+}
+
+fn parse_and_print_formatted_header(input: &[u8], base: NumberBase) -> Result<(), String> {
+    let ident = elf_match(EiIdentParser::new().parse(input)?)?;
     match (ident.ei_class, ident.ei_data) {
         (EiClass::ThirtyTwoBit, EiData::Little) => {
-            let eh = ElfHeaderParser::<u32, LittleEndian>::new()
-                .parse(&input)?
-                .unwrap();
+            let eh = elf_match(ElfHeaderParser::elf32_le().parse(&input)?)?;
 
-            print_formatted_file_header(ident, eh.file_header);
-            print_formatted_32bit_program_headers(&eh.program_headers);
-            print_formatted_32bit_section_header(&eh.section_headers);
+            print_formatted_file_header(ident, eh.file_header, base);
+            print_formatted_32bit_program_headers(&eh.program_headers, base);
+            print_formatted_32bit_section_header(&eh.section_headers, base);
         }
         (EiClass::ThirtyTwoBit, EiData::Big) => {
-            let eh = ElfHeaderParser::<u32, BigEndian>::new()
-                .parse(&input)?
-                .unwrap();
-            print_formatted_file_header(ident, eh.file_header);
-            print_formatted_32bit_program_headers(&eh.program_headers);
-            print_formatted_32bit_section_header(&eh.section_headers);
+            let eh = elf_match(ElfHeaderParser::elf32_be().parse(&input)?)?;
+            print_formatted_file_header(ident, eh.file_header, base);
+            print_formatted_32bit_program_headers(&eh.program_headers, base);
+            print_formatted_32bit_section_header(&eh.section_headers, base);
         }
         (EiClass::SixtyFourBit, EiData::Little) => {
-            let eh = ElfHeaderParser::<u64, LittleEndian>::new()
-                .parse(&input)?
-                .unwrap();
-            print_formatted_file_header(ident, eh.file_header);
-            print_formatted_64bit_program_headers(&eh.program_headers);
-            print_formatted_64bit_section_header(&eh.section_headers);
+            let eh = elf_match(ElfHeaderParser::elf64_le().parse(&input)?)?;
+            print_formatted_file_header(ident, eh.file_header, base);
+            print_formatted_64bit_program_headers(&eh.program_headers, base);
+            print_formatted_64bit_section_header(&eh.section_headers, base);
         }
         (EiClass::SixtyFourBit, EiData::Big) => {
-            let eh = ElfHeaderParser::<u64, BigEndian>::new()
-                .parse(&input)?
-                .unwrap();
-            print_formatted_file_header(ident, eh.file_header);
-            print_formatted_64bit_program_headers(&eh.program_headers);
-            print_formatted_64bit_section_header(&eh.section_headers);
+            let eh = elf_match(ElfHeaderParser::elf64_be().parse(&input)?)?;
+            print_formatted_file_header(ident, eh.file_header, base);
+            print_formatted_64bit_program_headers(&eh.program_headers, base);
+            print_formatted_64bit_section_header(&eh.section_headers, base);
         }
     };
 
@@ -72,6 +324,7 @@ fn parse_and_print_formatted_header(input: &[u8]) -> Result<(), String> {
 fn print_formatted_file_header<A: std::fmt::LowerHex + std::fmt::Display>(
     ident: EiIdent,
     header: FileHeader<A>,
+    base: NumberBase,
 ) {
     println!(
         "ELF Header:
@@ -83,9 +336,9 @@ fn print_formatted_file_header<A: std::fmt::LowerHex + std::fmt::Display>(
   Type:                              {}
   Machine:                           {}
   Version:                           {}
-  Entry point address:               0x{:x}
-  Start of program headers:          0x{:x}
-  Start of section headers:          0x{:x}
+  Entry point address:               {}
+  Start of program headers:          {}
+  Start of section headers:          {}
   Flags:                             0x{:x}
   Size of this header:               {} (bytes)
   Size of program headers:           {} (bytes)
@@ -101,9 +354,9 @@ fn print_formatted_file_header<A: std::fmt::LowerHex + std::fmt::Display>(
         header.r#type,
         header.machine,
         header.version,
-        header.entry_point,
-        header.ph_offset,
-        header.sh_offset,
+        base.format(header.entry_point),
+        base.format(header.ph_offset),
+        base.format(header.sh_offset),
         header.flags,
         header.eh_size,
         header.phent_size,
@@ -114,7 +367,7 @@ fn print_formatted_file_header<A: std::fmt::LowerHex + std::fmt::Display>(
     );
 }
 
-fn print_formatted_32bit_program_headers(headers: &[ProgramHeader32]) {
+fn print_formatted_32bit_program_headers(headers: &[ProgramHeader32], base: NumberBase) {
     println!(
         "\nProgram Headers:
   {: <16}{: <12}{: <12}{: <12}{: <12}{: <12}{: <12}{: <12}",
@@ -122,20 +375,20 @@ fn print_formatted_32bit_program_headers(headers: &[ProgramHeader32]) {
     );
     for h in headers.iter() {
         println!(
-            "  {: <16}0x{: <10}0x{: <10}0x{: <10}0x{: <10}0x{: <10}0x{: <10}0x{: <10}",
-            h.r#type.to_string(),
-            format!("{:x}", h.offset),
-            format!("{:x}", h.vaddr),
-            format!("{:x}", h.paddr),
-            format!("{:x}", h.filesz),
-            format!("{:x}", h.memsz),
+            "  {: <16}{: <12}{: <12}{: <12}{: <12}{: <12}0x{: <10}{: <12}",
+            h.r#type.type_str(),
+            base.format(h.offset),
+            base.format(h.vaddr),
+            base.format(h.paddr),
+            base.format(h.filesz),
+            base.format(h.memsz),
             format!("{:x}", h.flags),
-            format!("{:x}", h.align)
+            base.format(h.align)
         )
     }
 }
 
-fn print_formatted_64bit_program_headers(headers: &[ProgramHeader64]) {
+fn print_formatted_64bit_program_headers(headers: &[ProgramHeader64], base: NumberBase) {
     println!(
         "\nProgram Headers:
   {: <16}{: <12}{: <12}{: <12}{: <12}{: <12}{: <12}{: <12}",
@@ -143,20 +396,20 @@ fn print_formatted_64bit_program_headers(headers: &[ProgramHeader64]) {
     );
     for h in headers.iter() {
         println!(
-            "  {: <16}0x{: <10}0x{: <10}0x{: <10}0x{: <10}0x{: <10}0x{: <10}0x{: <10}",
-            h.r#type.to_string(),
-            format!("{:x}", h.offset),
-            format!("{:x}", h.vaddr),
-            format!("{:x}", h.paddr),
-            format!("{:x}", h.filesz),
-            format!("{:x}", h.memsz),
+            "  {: <16}{: <12}{: <12}{: <12}{: <12}{: <12}0x{: <10}{: <12}",
+            h.r#type.type_str(),
+            base.format(h.offset),
+            base.format(h.vaddr),
+            base.format(h.paddr),
+            base.format(h.filesz),
+            base.format(h.memsz),
             format!("{:x}", h.flags),
-            format!("{:x}", h.align)
+            base.format(h.align)
         )
     }
 }
 
-fn print_formatted_32bit_section_header(headers: &[SectionHeader32]) {
+fn print_formatted_32bit_section_header(headers: &[SectionHeader32], base: NumberBase) {
     println!(
         "\nSection Headers:
   {: <16}{: <24}{: <24}{: <24}{: <24}
@@ -165,23 +418,23 @@ fn print_formatted_32bit_section_header(headers: &[SectionHeader32]) {
     );
     for h in headers.iter() {
         println!(
-            "  {: <16}{: <12}0x{: <24}0x{: <24}0x{: <24}
-    0x{: <24}0x{: <24}0x{: <24}0x{: <24}0x{: <24}",
-            h.sh_name.to_string(),
-            h.sh_type.to_string(),
-            format!("{:x}", h.sh_addr),
-            format!("{:x}", h.sh_offset),
-            format!("{:x}", h.sh_size),
-            format!("{:x}", h.sh_entsize),
-            format!("{:x}", h.sh_flags as u32),
+            "  {: <16}{: <12}{: <26}{: <26}{: <26}
+    {: <26}0x{: <24}0x{: <24}0x{: <24}{: <26}",
+            h.sh_name,
+            h.sh_type.type_str(),
+            base.format(h.sh_addr),
+            base.format(h.sh_offset),
+            base.format(h.sh_size),
+            base.format(h.sh_entsize),
+            format!("{:x}", h.sh_flags()),
             format!("{:x}", h.sh_link),
             format!("{:x}", h.sh_info),
-            format!("{:x}", h.sh_addr_align)
+            base.format(h.sh_addr_align)
         )
     }
 }
 
-fn print_formatted_64bit_section_header(headers: &[SectionHeader64]) {
+fn print_formatted_64bit_section_header(headers: &[SectionHeader64], base: NumberBase) {
     println!(
         "\nSection Headers:
   {: <16}{: <24}{: <24}{: <24}{: <24}
@@ -190,18 +443,18 @@ fn print_formatted_64bit_section_header(headers: &[SectionHeader64]) {
     );
     for h in headers.iter() {
         println!(
-            "  {: <16}{: <12}0x{: <24}0x{: <24}0x{: <24}
-    0x{: <24}0x{: <24}0x{: <24}0x{: <24}0x{: <24}",
-            h.sh_name.to_string(),
-            h.sh_type.to_string(),
-            format!("{:x}", h.sh_addr),
-            format!("{:x}", h.sh_offset),
-            format!("{:x}", h.sh_size),
-            format!("{:x}", h.sh_entsize),
-            format!("{:x}", h.sh_flags as u64),
+            "  {: <16}{: <12}{: <26}{: <26}{: <26}
+    {: <26}0x{: <24}0x{: <24}0x{: <24}{: <26}",
+            h.sh_name,
+            h.sh_type.type_str(),
+            base.format(h.sh_addr),
+            base.format(h.sh_offset),
+            base.format(h.sh_size),
+            base.format(h.sh_entsize),
+            format!("{:x}", h.sh_flags()),
             format!("{:x}", h.sh_link),
             format!("{:x}", h.sh_info),
-            format!("{:x}", h.sh_addr_align)
+            base.format(h.sh_addr_align)
         )
     }
 }
@@ -4,16 +4,63 @@ use parcel::prelude::v1::*;
 // Type Metadata
 
 /// AddressWidth represents a variant of address size. This should, for the
-/// most part be either u32 or u64 for ELF.
-pub trait AddressWidth {}
+/// most part be either u32 or u64 for ELF. Each width carries the header types
+/// and sizes of its corresponding ELF class.
+pub trait AddressWidth: Sized {
+    type ProgramHeader;
+    type SectionHeader;
+
+    const CLASS: EiClass;
+    const EH_SIZE: u16;
+    const PHENT_SIZE: u16;
+    const SHENT_SIZE: u16;
+
+    /// Decodes an address from exactly `size_of::<Self>()` bytes of the
+    /// passed byte order, returning `None` for a slice of any other length.
+    fn from_bytes(endianness: EiData, bytes: &[u8]) -> Option<Self>;
+}
 
 type ElfAddr32 = u32;
 
-impl AddressWidth for ElfAddr32 {}
+impl AddressWidth for ElfAddr32 {
+    type ProgramHeader = ProgramHeader32;
+    type SectionHeader = SectionHeader32;
+
+    const CLASS: EiClass = EiClass::ThirtyTwoBit;
+    const EH_SIZE: u16 = 52;
+    const PHENT_SIZE: u16 = 32;
+    const SHENT_SIZE: u16 = 40;
+
+    fn from_bytes(endianness: EiData, bytes: &[u8]) -> Option<Self> {
+        use std::convert::TryInto;
+
+        bytes.try_into().ok().map(|ep| match endianness {
+            EiData::Little => u32::from_le_bytes(ep),
+            EiData::Big => u32::from_be_bytes(ep),
+        })
+    }
+}
 
 type ElfAddr64 = u64;
 
-impl AddressWidth for ElfAddr64 {}
+impl AddressWidth for ElfAddr64 {
+    type ProgramHeader = ProgramHeader64;
+    type SectionHeader = SectionHeader64;
+
+    const CLASS: EiClass = EiClass::SixtyFourBit;
+    const EH_SIZE: u16 = 64;
+    const PHENT_SIZE: u16 = 56;
+    const SHENT_SIZE: u16 = 64;
+
+    fn from_bytes(endianness: EiData, bytes: &[u8]) -> Option<Self> {
+        use std::convert::TryInto;
+
+        bytes.try_into().ok().map(|ep| match endianness {
+            EiData::Little => u64::from_le_bytes(ep),
+            EiData::Big => u64::from_be_bytes(ep),
+        })
+    }
+}
 
 /// Serialize defines a trait for serializing a type to a corresponding binary format.
 pub trait Serialize<A, E> {
@@ -23,16 +70,150 @@ pub trait Serialize<A, E> {
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum FileErr {
     InvalidFile,
+    /// The file declares more entries or larger tables than the configured
+    /// `ParseLimits` allow.
+    LimitExceeded,
 }
 
-impl std::fmt::Debug for FileErr {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+// FileErr and ValidationError are formatted purely in terms of `core` so that
+// they remain usable without `std`.
+impl core::fmt::Debug for FileErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::InvalidFile => write!(f, "not an elf formatted file"),
+            Self::LimitExceeded => write!(f, "file exceeds the configured parse limits"),
+        }
+    }
+}
+
+impl core::fmt::Display for FileErr {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FileErr {}
+
+/// ValidationError describes an inconsistency found while cross-checking the
+/// headers of a parsed file.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The PT_PHDR segment's offset doesn't match the file header's phoff.
+    PhdrOffsetMismatch { expected: u64, found: u64 },
+    /// The PT_PHDR segment's filesz doesn't match phnum * phentsize.
+    PhdrSizeMismatch { expected: u64, found: u64 },
+    /// The PT_PHDR segment's vaddr doesn't match the address the program
+    /// header table is loaded at.
+    PhdrAddressMismatch { expected: u64, found: u64 },
+    /// The program header table isn't covered by any PT_LOAD segment.
+    PhdrNotLoaded,
+    /// A relocatable file was given program headers.
+    RelocatableWithProgramHeaders,
+    /// An executable file has no PT_LOAD segment.
+    ExecutableWithoutLoadSegment,
+    /// A header table holds more entries than its 16-bit count can record.
+    TooManyHeaders,
+    /// The file header's eh_size doesn't match the header size of its class.
+    EhSizeMismatch { expected: u16, found: u16 },
+    /// The file header's shstrndx is neither a valid section index nor
+    /// SHN_XINDEX.
+    ShstrndxOutOfRange { shstrndx: u16, shnum: u16 },
+    /// A symbol table's sh_info places its first non-local symbol past the
+    /// end of the table.
+    SymbolInfoOutOfRange { sh_info: u32, symbol_count: u64 },
+    /// The file range of the program header at index `segment` partially
+    /// overlaps the file header or one of the header tables.
+    SegmentOverlapsHeaders { segment: usize },
+    /// The reserved section header at index 0 holds a nonzero value in a
+    /// field not used to extend the file header's counts.
+    NullSectionNotEmpty { field: &'static str },
+}
+
+impl core::fmt::Debug for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PhdrOffsetMismatch { expected, found } => write!(
+                f,
+                "PT_PHDR offset 0x{:x} does not match phoff 0x{:x}",
+                found, expected
+            ),
+            Self::PhdrSizeMismatch { expected, found } => write!(
+                f,
+                "PT_PHDR filesz 0x{:x} does not match program header table size 0x{:x}",
+                found, expected
+            ),
+            Self::PhdrAddressMismatch { expected, found } => write!(
+                f,
+                "PT_PHDR vaddr 0x{:x} does not match load address 0x{:x}",
+                found, expected
+            ),
+            Self::PhdrNotLoaded => {
+                write!(f, "program header table is not part of a loadable segment")
+            }
+            Self::RelocatableWithProgramHeaders => {
+                write!(f, "relocatable files must not have program headers")
+            }
+            Self::ExecutableWithoutLoadSegment => {
+                write!(f, "executable files require at least one PT_LOAD segment")
+            }
+            Self::TooManyHeaders => write!(f, "header table exceeds 65535 entries"),
+            Self::EhSizeMismatch { expected, found } => write!(
+                f,
+                "eh_size {} does not match the expected header size {}",
+                found, expected
+            ),
+            Self::ShstrndxOutOfRange { shstrndx, shnum } => write!(
+                f,
+                "shstrndx {} is out of range for {} section headers",
+                shstrndx, shnum
+            ),
+            Self::SymbolInfoOutOfRange {
+                sh_info,
+                symbol_count,
+            } => write!(
+                f,
+                "sh_info {} exceeds the symbol table's {} symbols",
+                sh_info, symbol_count
+            ),
+            Self::SegmentOverlapsHeaders { segment } => write!(
+                f,
+                "segment {} partially overlaps the file's header tables",
+                segment
+            ),
+            Self::NullSectionNotEmpty { field } => {
+                write!(f, "section header 0 has a nonzero {}", field)
+            }
         }
     }
 }
 
+impl core::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self, f)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+/// ParseOptions configures how tolerant the header parsers are of malformed
+/// input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// When `false`, unrecognized `ei_version`, `ei_osabi` and file header
+    /// `version` values are retained as `EiVersion::Unknown`,
+    /// `EiOsAbi::Unknown` and `Version::Other` rather than failing to match. Unrecognized machines are accepted in
+    /// either mode as `Machine::Other`.
+    pub strict: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self { strict: true }
+    }
+}
+
 /// EiClass contains a 1-byte value representing whether a type is 32 or 64-bit
 /// respectively.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -162,20 +343,28 @@ impl<'a> parcel::Parser<'a, &'a [u8], EiData> for EiDataParser {
 
 /// EiVersion represents which version of ELF header is being used.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
 pub enum EiVersion {
-    One = 0x01,
+    One,
+    /// Unknown represents an unrecognized version accepted by a lenient
+    /// parse, carrying the original value.
+    Unknown(u8),
 }
 
 impl std::fmt::Display for EiVersion {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", *self as u8)
+        match self {
+            EiVersion::Unknown(value) => write!(f, "<unknown: {:x}>", value),
+            _ => write!(f, "{}", u8::from(*self)),
+        }
     }
 }
 
 impl From<EiVersion> for u8 {
     fn from(src: EiVersion) -> Self {
-        src as u8
+        match src {
+            EiVersion::One => 0x01,
+            EiVersion::Unknown(value) => value,
+        }
     }
 }
 
@@ -184,7 +373,7 @@ struct EiVersionParser;
 
 impl<'a> parcel::Parser<'a, &'a [u8], EiVersion> for EiVersionParser {
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], EiVersion> {
-        expect_byte(EiVersion::One as u8)
+        expect_byte(u8::from(EiVersion::One))
             .map(|_| EiVersion::One)
             .parse(input)
     }
@@ -193,31 +382,56 @@ impl<'a> parcel::Parser<'a, &'a [u8], EiVersion> for EiVersionParser {
 /// EiOsAbi represents the target systems ABI.
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u8)]
 pub enum EiOsAbi {
-    SysV = 0x00,
-    HPUX = 0x01,
-    NetBSD = 0x02,
-    Linux = 0x03,
-    GNUHurd = 0x04,
-    Solaris = 0x06,
-    AIX = 0x07,
-    IRIX = 0x08,
-    FreeBSD = 0x09,
-    Tru64 = 0x0A,
-    Novell = 0x0B,
-    OpenBSD = 0x0C,
-    OpenVMS = 0x0D,
-    NonStop = 0x0E,
-    Aros = 0x0F,
-    Fenix = 0x10,
-    CloudABI = 0x11,
-    OpenVOS = 0x12,
+    SysV,
+    HPUX,
+    NetBSD,
+    Linux,
+    GNUHurd,
+    Solaris,
+    AIX,
+    IRIX,
+    FreeBSD,
+    Tru64,
+    Novell,
+    OpenBSD,
+    OpenVMS,
+    NonStop,
+    Aros,
+    Fenix,
+    CloudABI,
+    OpenVOS,
+    /// Standalone represents an embedded application with no OS/ABI.
+    Standalone,
+    /// Unknown represents an unrecognized OS/ABI accepted by a lenient parse,
+    /// carrying the original value.
+    Unknown(u8),
 }
 
 impl From<EiOsAbi> for u8 {
     fn from(src: EiOsAbi) -> Self {
-        src as u8
+        match src {
+            EiOsAbi::SysV => 0x00,
+            EiOsAbi::HPUX => 0x01,
+            EiOsAbi::NetBSD => 0x02,
+            EiOsAbi::Linux => 0x03,
+            EiOsAbi::GNUHurd => 0x04,
+            EiOsAbi::Solaris => 0x06,
+            EiOsAbi::AIX => 0x07,
+            EiOsAbi::IRIX => 0x08,
+            EiOsAbi::FreeBSD => 0x09,
+            EiOsAbi::Tru64 => 0x0A,
+            EiOsAbi::Novell => 0x0B,
+            EiOsAbi::OpenBSD => 0x0C,
+            EiOsAbi::OpenVMS => 0x0D,
+            EiOsAbi::NonStop => 0x0E,
+            EiOsAbi::Aros => 0x0F,
+            EiOsAbi::Fenix => 0x10,
+            EiOsAbi::CloudABI => 0x11,
+            EiOsAbi::OpenVOS => 0x12,
+            EiOsAbi::Standalone => 0xFF,
+            EiOsAbi::Unknown(value) => value,
+        }
     }
 }
 
@@ -227,24 +441,25 @@ struct EiOsAbiParser;
 impl<'a> parcel::Parser<'a, &'a [u8], EiOsAbi> for EiOsAbiParser {
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], EiOsAbi> {
         parcel::one_of(vec![
-            expect_byte(EiOsAbi::SysV as u8).map(|_| EiOsAbi::SysV),
-            expect_byte(EiOsAbi::HPUX as u8).map(|_| EiOsAbi::HPUX),
-            expect_byte(EiOsAbi::NetBSD as u8).map(|_| EiOsAbi::NetBSD),
-            expect_byte(EiOsAbi::Linux as u8).map(|_| EiOsAbi::Linux),
-            expect_byte(EiOsAbi::GNUHurd as u8).map(|_| EiOsAbi::GNUHurd),
-            expect_byte(EiOsAbi::Solaris as u8).map(|_| EiOsAbi::Solaris),
-            expect_byte(EiOsAbi::AIX as u8).map(|_| EiOsAbi::AIX),
-            expect_byte(EiOsAbi::IRIX as u8).map(|_| EiOsAbi::IRIX),
-            expect_byte(EiOsAbi::FreeBSD as u8).map(|_| EiOsAbi::FreeBSD),
-            expect_byte(EiOsAbi::Tru64 as u8).map(|_| EiOsAbi::Tru64),
-            expect_byte(EiOsAbi::Novell as u8).map(|_| EiOsAbi::Novell),
-            expect_byte(EiOsAbi::OpenBSD as u8).map(|_| EiOsAbi::OpenBSD),
-            expect_byte(EiOsAbi::OpenVMS as u8).map(|_| EiOsAbi::OpenVMS),
-            expect_byte(EiOsAbi::NonStop as u8).map(|_| EiOsAbi::NonStop),
-            expect_byte(EiOsAbi::Aros as u8).map(|_| EiOsAbi::Aros),
-            expect_byte(EiOsAbi::Fenix as u8).map(|_| EiOsAbi::Fenix),
-            expect_byte(EiOsAbi::CloudABI as u8).map(|_| EiOsAbi::CloudABI),
-            expect_byte(EiOsAbi::OpenVOS as u8).map(|_| EiOsAbi::OpenVOS),
+            expect_byte(u8::from(EiOsAbi::SysV)).map(|_| EiOsAbi::SysV),
+            expect_byte(u8::from(EiOsAbi::HPUX)).map(|_| EiOsAbi::HPUX),
+            expect_byte(u8::from(EiOsAbi::NetBSD)).map(|_| EiOsAbi::NetBSD),
+            expect_byte(u8::from(EiOsAbi::Linux)).map(|_| EiOsAbi::Linux),
+            expect_byte(u8::from(EiOsAbi::GNUHurd)).map(|_| EiOsAbi::GNUHurd),
+            expect_byte(u8::from(EiOsAbi::Solaris)).map(|_| EiOsAbi::Solaris),
+            expect_byte(u8::from(EiOsAbi::AIX)).map(|_| EiOsAbi::AIX),
+            expect_byte(u8::from(EiOsAbi::IRIX)).map(|_| EiOsAbi::IRIX),
+            expect_byte(u8::from(EiOsAbi::FreeBSD)).map(|_| EiOsAbi::FreeBSD),
+            expect_byte(u8::from(EiOsAbi::Tru64)).map(|_| EiOsAbi::Tru64),
+            expect_byte(u8::from(EiOsAbi::Novell)).map(|_| EiOsAbi::Novell),
+            expect_byte(u8::from(EiOsAbi::OpenBSD)).map(|_| EiOsAbi::OpenBSD),
+            expect_byte(u8::from(EiOsAbi::OpenVMS)).map(|_| EiOsAbi::OpenVMS),
+            expect_byte(u8::from(EiOsAbi::NonStop)).map(|_| EiOsAbi::NonStop),
+            expect_byte(u8::from(EiOsAbi::Aros)).map(|_| EiOsAbi::Aros),
+            expect_byte(u8::from(EiOsAbi::Fenix)).map(|_| EiOsAbi::Fenix),
+            expect_byte(u8::from(EiOsAbi::CloudABI)).map(|_| EiOsAbi::CloudABI),
+            expect_byte(u8::from(EiOsAbi::OpenVOS)).map(|_| EiOsAbi::OpenVOS),
+            expect_byte(u8::from(EiOsAbi::Standalone)).map(|_| EiOsAbi::Standalone),
         ])
         .parse(input)
     }
@@ -256,21 +471,23 @@ impl std::fmt::Display for EiOsAbi {
             EiOsAbi::SysV => "UNIX - System V",
             EiOsAbi::HPUX => "UNIX - HP-UX",
             EiOsAbi::NetBSD => "UNIX - NetBSD",
-            EiOsAbi::Linux => "Linux C6000",
-            EiOsAbi::GNUHurd => "Unix - GNU",
+            EiOsAbi::Linux => "UNIX - GNU",
+            EiOsAbi::GNUHurd => "GNU/Hurd",
             EiOsAbi::Solaris => "UNIX - Solaris",
             EiOsAbi::AIX => "UNIX - AIX",
             EiOsAbi::IRIX => "UNIX - IRIX",
             EiOsAbi::FreeBSD => "UNIX - FreeBSD",
             EiOsAbi::Tru64 => "UNIX - TRU64",
             EiOsAbi::Novell => "Novell - Modesto",
-            EiOsAbi::OpenBSD => "Unix - OpenBSD",
+            EiOsAbi::OpenBSD => "UNIX - OpenBSD",
             EiOsAbi::OpenVMS => "VMS - OpenVMS",
             EiOsAbi::NonStop => "HP - Non-Stop Kernel",
-            EiOsAbi::Aros => "Aros",
+            EiOsAbi::Aros => "AROS",
             EiOsAbi::Fenix => "FenixOS",
             EiOsAbi::CloudABI => "Nuxi CloudABI",
             EiOsAbi::OpenVOS => "Stratus Technologies OpenVOS",
+            EiOsAbi::Standalone => "Standalone App",
+            EiOsAbi::Unknown(value) => return write!(f, "<unknown: {:x}>", value),
         };
 
         write!(f, "{}", repr)
@@ -401,120 +618,243 @@ impl<'a> parcel::Parser<'a, &'a [u8], Type> for TypeParser<BigEndian> {
 /// a u16.
 #[allow(clippy::clippy::upper_case_acronyms)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u16)]
 pub enum Machine {
-    None = 0x00,
-    M32 = 0x01,
-    SPARC = 0x02,
-    X386 = 0x03,
-    M68k = 0x04,
-    M88k = 0x05,
-    IntelMCU = 0x06,
-    Intel80860 = 0x07,
-    MIPS = 0x08,
-    S370 = 0x09,
-    MIPSRS3LE = 0x0A,
-    PARISC = 0x0E,
-    I960 = 0x13,
-    PPC = 0x14,
-    PPC64 = 0x15,
-    S390 = 0x16,
-    V800 = 0x24,
-    FR20 = 0x25,
-    RH32 = 0x26,
-    RCE = 0x27,
-    ARM = 0x28,
-    Alpha = 0x29,
-    SH = 0x2A,
-    SPARCV9 = 0x2B,
-    Tricore = 0x2C,
-    ARC = 0x2D,
-    H8300 = 0x2E,
-    H8_300H = 0x2F,
-    H8s = 0x30,
-    H8500 = 0x31,
-    IA64 = 0x32,
-    MIPSX = 0x33,
-    Coldfire = 0x34,
-    M68HC12 = 0x35,
-    MMA = 0x36,
-    PCP = 0x37,
-    NCPU = 0x38,
-    NDR1 = 0x39,
-    Starcore = 0x3A,
-    ME16 = 0x3B,
-    ST100 = 0x3C,
-    TinyJ = 0x3D,
-    X86_64 = 0x3E,
-    S320C600 = 0x8C,
-    AARCH64 = 0xB7,
-    RISCV = 0xF3,
-    BPF = 0xF7,
-    MCS6502 = 0xFE,
-    WDC65C817 = 0x101,
+    None,
+    M32,
+    SPARC,
+    X386,
+    M68k,
+    M88k,
+    IntelMCU,
+    Intel80860,
+    MIPS,
+    S370,
+    MIPSRS3LE,
+    PARISC,
+    I960,
+    PPC,
+    PPC64,
+    S390,
+    V800,
+    FR20,
+    RH32,
+    RCE,
+    ARM,
+    Alpha,
+    SH,
+    SPARCV9,
+    Tricore,
+    ARC,
+    H8300,
+    H8_300H,
+    H8s,
+    H8500,
+    IA64,
+    MIPSX,
+    Coldfire,
+    M68HC12,
+    MMA,
+    PCP,
+    NCPU,
+    NDR1,
+    Starcore,
+    ME16,
+    ST100,
+    TinyJ,
+    X86_64,
+    AVR,
+    Xtensa,
+    MSP430,
+    S320C600,
+    AARCH64,
+    AMDGPU,
+    RISCV,
+    BPF,
+    CSKY,
+    MCS6502,
+    WDC65C817,
+    LoongArch,
+    /// Other represents a machine code without a variant of its own,
+    /// carrying the original value.
+    Other(u16),
 }
 
 impl From<Machine> for u16 {
     fn from(src: Machine) -> Self {
-        src as u16
+        match src {
+            Machine::None => 0x00,
+            Machine::M32 => 0x01,
+            Machine::SPARC => 0x02,
+            Machine::X386 => 0x03,
+            Machine::M68k => 0x04,
+            Machine::M88k => 0x05,
+            Machine::IntelMCU => 0x06,
+            Machine::Intel80860 => 0x07,
+            Machine::MIPS => 0x08,
+            Machine::S370 => 0x09,
+            Machine::MIPSRS3LE => 0x0A,
+            Machine::PARISC => 0x0E,
+            Machine::I960 => 0x13,
+            Machine::PPC => 0x14,
+            Machine::PPC64 => 0x15,
+            Machine::S390 => 0x16,
+            Machine::V800 => 0x24,
+            Machine::FR20 => 0x25,
+            Machine::RH32 => 0x26,
+            Machine::RCE => 0x27,
+            Machine::ARM => 0x28,
+            Machine::Alpha => 0x29,
+            Machine::SH => 0x2A,
+            Machine::SPARCV9 => 0x2B,
+            Machine::Tricore => 0x2C,
+            Machine::ARC => 0x2D,
+            Machine::H8300 => 0x2E,
+            Machine::H8_300H => 0x2F,
+            Machine::H8s => 0x30,
+            Machine::H8500 => 0x31,
+            Machine::IA64 => 0x32,
+            Machine::MIPSX => 0x33,
+            Machine::Coldfire => 0x34,
+            Machine::M68HC12 => 0x35,
+            Machine::MMA => 0x36,
+            Machine::PCP => 0x37,
+            Machine::NCPU => 0x38,
+            Machine::NDR1 => 0x39,
+            Machine::Starcore => 0x3A,
+            Machine::ME16 => 0x3B,
+            Machine::ST100 => 0x3C,
+            Machine::TinyJ => 0x3D,
+            Machine::X86_64 => 0x3E,
+            Machine::AVR => 0x53,
+            Machine::Xtensa => 0x5E,
+            Machine::MSP430 => 0x69,
+            Machine::S320C600 => 0x8C,
+            Machine::AARCH64 => 0xB7,
+            Machine::AMDGPU => 0xE0,
+            Machine::RISCV => 0xF3,
+            Machine::BPF => 0xF7,
+            Machine::CSKY => 0xFC,
+            Machine::MCS6502 => 0xFE,
+            Machine::WDC65C817 => 0x101,
+            Machine::LoongArch => 0x102,
+            Machine::Other(value) => value,
+        }
     }
 }
 
-impl std::convert::TryFrom<u16> for Machine {
-    type Error = String;
-
-    fn try_from(value: u16) -> Result<Self, Self::Error> {
+impl From<u16> for Machine {
+    fn from(value: u16) -> Self {
         match value {
-            0x00 => Ok(Machine::None),
-            0x01 => Ok(Machine::M32),
-            0x02 => Ok(Machine::SPARC),
-            0x03 => Ok(Machine::X386),
-            0x04 => Ok(Machine::M68k),
-            0x05 => Ok(Machine::M88k),
-            0x06 => Ok(Machine::IntelMCU),
-            0x07 => Ok(Machine::Intel80860),
-            0x08 => Ok(Machine::MIPS),
-            0x09 => Ok(Machine::S370),
-            0x0A => Ok(Machine::MIPSRS3LE),
-            0x0E => Ok(Machine::PARISC),
-            0x13 => Ok(Machine::I960),
-            0x14 => Ok(Machine::PPC),
-            0x15 => Ok(Machine::PPC64),
-            0x16 => Ok(Machine::S390),
-            0x24 => Ok(Machine::V800),
-            0x25 => Ok(Machine::FR20),
-            0x26 => Ok(Machine::RH32),
-            0x27 => Ok(Machine::RCE),
-            0x28 => Ok(Machine::ARM),
-            0x29 => Ok(Machine::Alpha),
-            0x2A => Ok(Machine::SH),
-            0x2B => Ok(Machine::SPARCV9),
-            0x2C => Ok(Machine::Tricore),
-            0x2D => Ok(Machine::ARC),
-            0x2E => Ok(Machine::H8300),
-            0x2F => Ok(Machine::H8_300H),
-            0x30 => Ok(Machine::H8s),
-            0x31 => Ok(Machine::H8500),
-            0x32 => Ok(Machine::IA64),
-            0x33 => Ok(Machine::MIPSX),
-            0x34 => Ok(Machine::Coldfire),
-            0x35 => Ok(Machine::M68HC12),
-            0x36 => Ok(Machine::MMA),
-            0x37 => Ok(Machine::PCP),
-            0x38 => Ok(Machine::NCPU),
-            0x39 => Ok(Machine::NDR1),
-            0x3a => Ok(Machine::Starcore),
-            0x3B => Ok(Machine::ME16),
-            0x3C => Ok(Machine::ST100),
-            0x3D => Ok(Machine::TinyJ),
-            0x3e => Ok(Machine::X86_64),
-            0x8C => Ok(Machine::S320C600),
-            0xB9 => Ok(Machine::AARCH64),
-            0xFA => Ok(Machine::RISCV),
-            0xFB => Ok(Machine::BPF),
-            0xFE => Ok(Machine::MCS6502),
-            0x101 => Ok(Machine::WDC65C817),
-            _ => Err(format!("cannot convert {} to Machine variant", value)),
+            0x00 => Machine::None,
+            0x01 => Machine::M32,
+            0x02 => Machine::SPARC,
+            0x03 => Machine::X386,
+            0x04 => Machine::M68k,
+            0x05 => Machine::M88k,
+            0x06 => Machine::IntelMCU,
+            0x07 => Machine::Intel80860,
+            0x08 => Machine::MIPS,
+            0x09 => Machine::S370,
+            0x0A => Machine::MIPSRS3LE,
+            0x0E => Machine::PARISC,
+            0x13 => Machine::I960,
+            0x14 => Machine::PPC,
+            0x15 => Machine::PPC64,
+            0x16 => Machine::S390,
+            0x24 => Machine::V800,
+            0x25 => Machine::FR20,
+            0x26 => Machine::RH32,
+            0x27 => Machine::RCE,
+            0x28 => Machine::ARM,
+            0x29 => Machine::Alpha,
+            0x2A => Machine::SH,
+            0x2B => Machine::SPARCV9,
+            0x2C => Machine::Tricore,
+            0x2D => Machine::ARC,
+            0x2E => Machine::H8300,
+            0x2F => Machine::H8_300H,
+            0x30 => Machine::H8s,
+            0x31 => Machine::H8500,
+            0x32 => Machine::IA64,
+            0x33 => Machine::MIPSX,
+            0x34 => Machine::Coldfire,
+            0x35 => Machine::M68HC12,
+            0x36 => Machine::MMA,
+            0x37 => Machine::PCP,
+            0x38 => Machine::NCPU,
+            0x39 => Machine::NDR1,
+            0x3a => Machine::Starcore,
+            0x3B => Machine::ME16,
+            0x3C => Machine::ST100,
+            0x3D => Machine::TinyJ,
+            0x3e => Machine::X86_64,
+            0x53 => Machine::AVR,
+            0x5E => Machine::Xtensa,
+            0x69 => Machine::MSP430,
+            0x8C => Machine::S320C600,
+            0xB7 => Machine::AARCH64,
+            0xE0 => Machine::AMDGPU,
+            0xF3 => Machine::RISCV,
+            0xF7 => Machine::BPF,
+            0xFC => Machine::CSKY,
+            0xFE => Machine::MCS6502,
+            0x101 => Machine::WDC65C817,
+            0x102 => Machine::LoongArch,
+            _ => Machine::Other(value),
+        }
+    }
+}
+
+impl Machine {
+    /// EM_AMD64, the alternate name some toolchains use for EM_X86_64.
+    pub const AMD64: Machine = Machine::X86_64;
+
+    /// Returns the only file class the machine is defined for, or `None` for
+    /// architectures that are used with both classes or aren't width
+    /// specific. X86_64, AARCH64 and IA64 are dual-class, with ELF32 used by
+    /// the x32, ILP32 and HP-UX ABIs respectively.
+    pub fn natural_class(&self) -> Option<EiClass> {
+        match self {
+            Machine::PPC64 | Machine::SPARCV9 | Machine::Alpha | Machine::AMDGPU | Machine::BPF => {
+                Some(EiClass::SixtyFourBit)
+            }
+            Machine::M32
+            | Machine::SPARC
+            | Machine::X386
+            | Machine::M68k
+            | Machine::M88k
+            | Machine::IntelMCU
+            | Machine::Intel80860
+            | Machine::I960
+            | Machine::PPC
+            | Machine::ARM
+            | Machine::SH
+            | Machine::AVR
+            | Machine::MSP430
+            | Machine::Xtensa
+            | Machine::CSKY => Some(EiClass::ThirtyTwoBit),
+            _ => None,
+        }
+    }
+
+    /// Returns the width in bits of the machine's native addresses, as a
+    /// hint for formatting them. Small microcontroller targets report 16
+    /// even though their files are ELF32, while X86_64, AARCH64 and IA64
+    /// report 64 despite their ELF32 ABIs. Other machines used with both
+    /// classes report 32.
+    pub fn natural_address_bits(&self) -> u16 {
+        match self {
+            Machine::X86_64 | Machine::AARCH64 | Machine::IA64 => 64,
+            Machine::IntelMCU
+            | Machine::AVR
+            | Machine::MSP430
+            | Machine::M68HC12
+            | Machine::H8300
+            | Machine::MCS6502 => 16,
+            _ => match self.natural_class() {
+                Some(EiClass::SixtyFourBit) => 64,
+                _ => 32,
+            },
         }
     }
 }
@@ -541,7 +881,7 @@ impl std::fmt::Display for Machine {
             Machine::V800 => "Renesas V850 (using RH850 ABI)",
             Machine::FR20 => "Fujitsu FR20",
             Machine::RH32 => "TRW RH32",
-            Machine::RCE => "Motorola M*Core",
+            Machine::RCE => "MCORE",
             Machine::ARM => "ARM",
             Machine::Alpha => "Digital Alpha (old)",
             Machine::SH => "Renesas / SuperH SH",
@@ -556,7 +896,7 @@ impl std::fmt::Display for Machine {
             Machine::MIPSX => "Stanford MIPS-X",
             Machine::Coldfire => "Motorola Coldfire",
             Machine::M68HC12 => "Motorola MC68HC12 Microcontroller",
-            Machine::MMA => "Fujitsu Multimedia Accellerator",
+            Machine::MMA => "Fujitsu Multimedia Accelerator",
             Machine::PCP => "Siemens PCP",
             Machine::NCPU => "Sony nCPU embedded RISC processor",
             Machine::NDR1 => "Denso NDR1 microprocessor",
@@ -565,12 +905,19 @@ impl std::fmt::Display for Machine {
             Machine::ST100 => "STMicroelectronics ST100 processor",
             Machine::TinyJ => "Advanced Logic Corp. TinyJ embedded processor",
             Machine::X86_64 => "Advanced Micro Devices X86-64",
+            Machine::AVR => "Atmel AVR 8-bit microcontroller",
+            Machine::Xtensa => "Tensilica Xtensa Processor",
+            Machine::MSP430 => "Texas Instruments msp430 microcontroller",
             Machine::S320C600 => "Texas Instruments TMS320C6000 DSP family",
             Machine::AARCH64 => "AArch64",
+            Machine::AMDGPU => "AMD GPU",
             Machine::RISCV => "RISC-V",
-            Machine::BPF => "Berkeley Packet Filter",
+            Machine::BPF => "Linux BPF",
+            Machine::CSKY => "C-SKY",
             Machine::MCS6502 => "MOS Technology MCS 6502 processor",
             Machine::WDC65C817 => "WDC 65816/65C816",
+            Machine::LoongArch => "LoongArch",
+            Machine::Other(value) => return write!(f, "<unknown: {:x}>", value),
         };
 
         write!(f, "{}", repr)
@@ -599,58 +946,46 @@ where
 
 impl<'a> parcel::Parser<'a, &'a [u8], Machine> for MachineParser<LittleEndian> {
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], Machine> {
-        use std::convert::TryInto;
-        let preparse_input = input;
-
-        input
-            .iter()
-            .take(2)
-            .copied()
-            .collect::<Vec<u8>>()
-            .try_into()
-            .map(|mcode| std::convert::TryFrom::try_from(u16::from_le_bytes(mcode)))
-            .unwrap()
-            .map_or(Ok(MatchStatus::NoMatch(preparse_input)), |m| {
-                Ok(MatchStatus::Match((&preparse_input[2..], m)))
-            })
+        parse_machine(EiData::Little, input)
     }
 }
 
 impl<'a> parcel::Parser<'a, &'a [u8], Machine> for MachineParser<BigEndian> {
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], Machine> {
-        use std::convert::TryInto;
-        let preparse_input = input;
-
-        input
-            .iter()
-            .take(2)
-            .copied()
-            .collect::<Vec<u8>>()
-            .try_into()
-            .map(|mcode| std::convert::TryFrom::try_from(u16::from_be_bytes(mcode)))
-            .unwrap()
-            .map_or(Ok(MatchStatus::NoMatch(preparse_input)), |m| {
-                Ok(MatchStatus::Match((&preparse_input[2..], m)))
-            })
+        parse_machine(EiData::Big, input)
     }
 }
 
+/// Parses a 2-byte machine code, returning a NoMatch for short input. Codes
+/// without a variant of their own are retained as `Machine::Other`.
+fn parse_machine(endianness: EiData, input: &[u8]) -> parcel::ParseResult<'_, &[u8], Machine> {
+    match_u16(endianness).map(Machine::from).parse(input)
+}
+
 /// Version represent an ELF version. This should always be one.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
 pub enum Version {
-    One = 0x01,
+    One,
+    /// Other represents an unrecognized version accepted by a lenient parse,
+    /// carrying the original value.
+    Other(u32),
 }
 
 impl std::fmt::Display for Version {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", *self as u32)
+        match self {
+            Version::Other(value) => write!(f, "<unknown: {:x}>", value),
+            _ => write!(f, "{}", u32::from(*self)),
+        }
     }
 }
 
 impl From<Version> for u32 {
     fn from(src: Version) -> Self {
-        src as u32
+        match src {
+            Version::One => 0x01,
+            Version::Other(value) => value,
+        }
     }
 }
 
@@ -690,6 +1025,9 @@ impl<'a> parcel::Parser<'a, &'a [u8], Version> for VersionParser<BigEndian> {
     }
 }
 
+/// The four magic bytes, `\x7fELF`, that every ELF file begins with.
+pub const ELF_MAGIC: [u8; 4] = [0x7f, 0x45, 0x4c, 0x46];
+
 /// EiIdent defines the elf identification fields that define whether the
 /// address size, versions and abi of the file.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -699,49 +1037,177 @@ pub struct EiIdent {
     pub ei_version: EiVersion,
     pub ei_osabi: EiOsAbi,
     pub ei_abiversion: EiAbiVersion,
+    /// The reserved EI_PAD bytes following ei_abiversion. These are zero in
+    /// well-formed files, but are preserved as read since data may be
+    /// hidden in them.
+    pub pad: [u8; 7],
+}
+
+impl EiIdent {
+    /// Returns a builder for an identification, defaulting to a 64-bit,
+    /// little-endian SysV file of the current version.
+    pub fn builder() -> EiIdentBuilder {
+        EiIdentBuilder::default()
+    }
+
+    /// Compares the meaningful fields of two identifications, ignoring the
+    /// reserved EI_PAD bytes.
+    pub fn ident_eq_ignoring_padding(&self, other: &EiIdent) -> bool {
+        self.ei_class == other.ei_class
+            && self.ei_data == other.ei_data
+            && self.ei_version == other.ei_version
+            && self.ei_osabi == other.ei_osabi
+            && self.ei_abiversion == other.ei_abiversion
+    }
+
+    /// Returns the file's bitness and byte order, such as `(64, "little")`,
+    /// for one-line reporting.
+    pub fn summary(&self) -> (u8, &'static str) {
+        let bits = match self.ei_class {
+            EiClass::ThirtyTwoBit => 32,
+            EiClass::SixtyFourBit => 64,
+        };
+        let endianness = match self.ei_data {
+            EiData::Little => "little",
+            EiData::Big => "big",
+        };
+
+        (bits, endianness)
+    }
+
+    /// Serializes the identification to exactly the 16 bytes it occupies at
+    /// the start of a file, including the magic number and EI_PAD bytes.
+    pub fn serialize(&self) -> [u8; 16] {
+        let mut ident = [0u8; 16];
+        ident[..4].copy_from_slice(&ELF_MAGIC);
+        ident[4] = self.ei_class as u8;
+        ident[5] = self.ei_data as u8;
+        ident[6] = u8::from(self.ei_version);
+        ident[7] = u8::from(self.ei_osabi);
+        ident[8] = self.ei_abiversion as u8;
+        ident[9..].copy_from_slice(&self.pad);
+        ident
+    }
+}
+
+/// EiIdentBuilder assembles an EiIdent, leaving only the fields that differ
+/// between files to be set. The version is always `One` and the ABI version
+/// `Zero`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EiIdentBuilder {
+    ei_class: EiClass,
+    ei_data: EiData,
+    ei_osabi: EiOsAbi,
+}
+
+impl Default for EiIdentBuilder {
+    fn default() -> Self {
+        Self {
+            ei_class: EiClass::SixtyFourBit,
+            ei_data: EiData::Little,
+            ei_osabi: EiOsAbi::SysV,
+        }
+    }
+}
+
+impl EiIdentBuilder {
+    pub fn class(mut self, ei_class: EiClass) -> Self {
+        self.ei_class = ei_class;
+        self
+    }
+
+    pub fn data(mut self, ei_data: EiData) -> Self {
+        self.ei_data = ei_data;
+        self
+    }
+
+    pub fn osabi(mut self, ei_osabi: EiOsAbi) -> Self {
+        self.ei_osabi = ei_osabi;
+        self
+    }
+
+    pub fn build(self) -> EiIdent {
+        EiIdent {
+            ei_class: self.ei_class,
+            ei_data: self.ei_data,
+            ei_version: EiVersion::One,
+            ei_osabi: self.ei_osabi,
+            ei_abiversion: EiAbiVersion::Zero,
+            pad: [0; 7],
+        }
+    }
 }
 
 impl From<EiIdent> for Vec<u8> {
     fn from(src: EiIdent) -> Self {
-        vec![
-            src.ei_class as u8,
-            src.ei_class as u8,
-            src.ei_version as u8,
-            src.ei_osabi as u8,
-            src.ei_abiversion as u8,
-        ]
+        src.serialize().to_vec()
     }
 }
 
 /// EiIdentParser defines a parser for parsing a raw bitstream into an EiIdent.
+#[derive(Default)]
 pub struct EiIdentParser;
 
+impl EiIdentParser {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn with_options(options: ParseOptions) -> EiIdentOptionsParser {
+        EiIdentOptionsParser { options }
+    }
+}
+
 impl<'a> parcel::Parser<'a, &'a [u8], EiIdent> for EiIdentParser {
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], EiIdent> {
+        EiIdentParser::with_options(ParseOptions::default()).parse(input)
+    }
+}
+
+/// EiIdentOptionsParser parses an EiIdent under the passed ParseOptions, as
+/// returned by `EiIdentParser::with_options`.
+pub struct EiIdentOptionsParser {
+    options: ParseOptions,
+}
+
+impl<'a> parcel::Parser<'a, &'a [u8], EiIdent> for EiIdentOptionsParser {
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], EiIdent> {
+        use parcel::parsers::byte::any_byte;
+        let options = self.options;
+
         parcel::right(parcel::join(
-            expect_bytes(&[0x7f, 0x45, 0x4c, 0x46]),
+            expect_bytes(&ELF_MAGIC),
             parcel::join(
                 EiClassParser,
                 parcel::join(
                     EiDataParser,
                     parcel::join(
-                        EiVersionParser,
-                        parcel::join(EiOsAbiParser, EiAbiVersionParser),
+                        EiVersionParser
+                            .or(move || lenient(options, any_byte()).map(EiVersion::Unknown)),
+                        parcel::join(
+                            EiOsAbiParser
+                                .or(move || lenient(options, any_byte()).map(EiOsAbi::Unknown)),
+                            EiAbiVersionParser,
+                        ),
                     ),
                 ),
             )
-            // skip padding
             .and_then(|last| {
-                parcel::take_n(parcel::parsers::byte::any_byte(), 7).map(move |_| last)
+                parcel::take_n(parcel::parsers::byte::any_byte(), 7).map(move |pad| {
+                    let mut padding = [0; 7];
+                    padding.copy_from_slice(&pad);
+                    (last, padding)
+                })
             }),
         ))
         .map(
-            |(ei_class, (ei_data, (ei_version, (ei_osabi, ei_abiversion))))| EiIdent {
+            |((ei_class, (ei_data, (ei_version, (ei_osabi, ei_abiversion)))), pad)| EiIdent {
                 ei_class,
                 ei_data,
                 ei_version,
                 ei_osabi,
                 ei_abiversion,
+                pad,
             },
         )
         .parse(input)
@@ -768,6 +1234,96 @@ pub struct FileHeader<A> {
     pub shstrndx: u16,
 }
 
+impl<A> FileHeader<A> {
+    /// Checks that eh_size matches the size of the file header for the
+    /// passed class: 52 bytes for ELF32 and 64 bytes for ELF64.
+    pub fn validate_eh_size(&self, class: EiClass) -> Result<(), ValidationError> {
+        let expected = match class {
+            EiClass::ThirtyTwoBit => ElfAddr32::EH_SIZE,
+            EiClass::SixtyFourBit => ElfAddr64::EH_SIZE,
+        };
+
+        if self.eh_size == expected {
+            Ok(())
+        } else {
+            Err(ValidationError::EhSizeMismatch {
+                expected,
+                found: self.eh_size,
+            })
+        }
+    }
+
+    /// Checks that shstrndx names a section within the section header table,
+    /// is SHN_UNDEF for a file without a section name string table, or is
+    /// SHN_XINDEX to defer to the sh_link of the first section header.
+    pub fn validate_shstrndx(&self) -> Result<(), ValidationError> {
+        match self.shstrndx {
+            SHN_UNDEF | SHN_XINDEX => Ok(()),
+            idx if idx < self.shnum => Ok(()),
+            shstrndx => Err(ValidationError::ShstrndxOutOfRange {
+                shstrndx,
+                shnum: self.shnum,
+            }),
+        }
+    }
+
+    /// Decodes the architecture-specific e_flags into descriptions of each
+    /// set flag and field, such as `["PIC", "o32 ABI", "mips32r2"]`. Flags of
+    /// machines without a decoding are described by an empty list.
+    pub fn decode_flags(&self) -> Vec<&'static str> {
+        match self.machine {
+            Machine::MIPS => decode_mips_flags(self.flags),
+            _ => vec![],
+        }
+    }
+}
+
+const EF_MIPS_NOREORDER: u32 = 0x0000_0001;
+const EF_MIPS_PIC: u32 = 0x0000_0002;
+const EF_MIPS_CPIC: u32 = 0x0000_0004;
+const EF_MIPS_ABI2: u32 = 0x0000_0020;
+const EF_MIPS_NAN2008: u32 = 0x0000_0400;
+const EF_MIPS_ABI: u32 = 0x0000_f000;
+const EF_MIPS_ARCH: u32 = 0xf000_0000;
+
+fn decode_mips_flags(flags: u32) -> Vec<&'static str> {
+    let bits = [
+        (EF_MIPS_NOREORDER, "noreorder"),
+        (EF_MIPS_PIC, "PIC"),
+        (EF_MIPS_CPIC, "CPIC"),
+        (EF_MIPS_ABI2, "n32 ABI"),
+        (EF_MIPS_NAN2008, "nan2008"),
+    ];
+    let abi = match (flags & EF_MIPS_ABI) >> 12 {
+        1 => Some("o32 ABI"),
+        2 => Some("o64 ABI"),
+        3 => Some("eabi32 ABI"),
+        4 => Some("eabi64 ABI"),
+        _ => None,
+    };
+    let arch = match (flags & EF_MIPS_ARCH) >> 28 {
+        0x0 => Some("mips1"),
+        0x1 => Some("mips2"),
+        0x2 => Some("mips3"),
+        0x3 => Some("mips4"),
+        0x4 => Some("mips5"),
+        0x5 => Some("mips32"),
+        0x6 => Some("mips64"),
+        0x7 => Some("mips32r2"),
+        0x8 => Some("mips64r2"),
+        0x9 => Some("mips32r6"),
+        0xa => Some("mips64r6"),
+        _ => None,
+    };
+
+    bits.iter()
+        .filter(|&&(bit, _)| flags & bit != 0)
+        .map(|&(_, name)| name)
+        .chain(abi)
+        .chain(arch)
+        .collect()
+}
+
 impl Serialize<ElfAddr32, LittleEndian> for FileHeader<ElfAddr32> {
     fn serialize(&self) -> Vec<u8> {
         vec![
@@ -860,6 +1416,37 @@ impl Serialize<ElfAddr64, BigEndian> for FileHeader<ElfAddr64> {
     }
 }
 
+/// Implements `elf32_le`, `elf32_be`, `elf64_le` and `elf64_be` constructors
+/// on a parser generic over address width and data encoding, returning the
+/// parser for that class and encoding.
+macro_rules! impl_class_constructors {
+    ($parser:ident) => {
+        impl $parser<ElfAddr32, LittleEndian> {
+            pub fn elf32_le() -> Self {
+                Self::default()
+            }
+        }
+
+        impl $parser<ElfAddr32, BigEndian> {
+            pub fn elf32_be() -> Self {
+                Self::default()
+            }
+        }
+
+        impl $parser<ElfAddr64, LittleEndian> {
+            pub fn elf64_le() -> Self {
+                Self::default()
+            }
+        }
+
+        impl $parser<ElfAddr64, BigEndian> {
+            pub fn elf64_be() -> Self {
+                Self::default()
+            }
+        }
+    };
+}
+
 /// FileHeaderParser defines a parser for parsing a raw bitstream into a FileHeader.
 pub struct FileHeaderParser<A, E>
 where
@@ -868,44 +1455,39 @@ where
 {
     address_width: std::marker::PhantomData<A>,
     endianness: std::marker::PhantomData<E>,
+    options: ParseOptions,
 }
 
-impl<E> FileHeaderParser<ElfAddr32, E>
+impl<A, E> FileHeaderParser<A, E>
 where
+    A: AddressWidth,
     E: DataEncoding,
 {
-    #[allow(dead_code)]
     pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_options(options: ParseOptions) -> Self {
         Self {
             address_width: std::marker::PhantomData,
             endianness: std::marker::PhantomData,
+            options,
         }
     }
 }
 
-impl<E> FileHeaderParser<u64, E>
-where
-    E: DataEncoding,
-{
-    #[allow(dead_code)]
-    pub fn new() -> Self {
-        Self::default()
-    }
-}
-
 impl<A, E> Default for FileHeaderParser<A, E>
 where
     A: AddressWidth,
     E: DataEncoding,
 {
     fn default() -> Self {
-        Self {
-            address_width: std::marker::PhantomData,
-            endianness: std::marker::PhantomData,
-        }
+        Self::with_options(ParseOptions::default())
     }
 }
 
+impl_class_constructors!(FileHeaderParser);
+
 impl<'a, E> parcel::Parser<'a, &'a [u8], FileHeader<ElfAddr32>> for FileHeaderParser<ElfAddr32, E>
 where
     EiData: From<E>,
@@ -916,13 +1498,15 @@ where
 {
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], FileHeader<ElfAddr32>> {
         let encoding = EiData::from(E::default());
+        let options = self.options;
 
         parcel::join(
             TypeParser::<E>::new(),
             parcel::join(
                 MachineParser::<E>::new(),
                 parcel::join(
-                    VersionParser::<E>::new(),
+                    VersionParser::<E>::new()
+                        .or(move || lenient(options, match_u32(encoding)).map(Version::Other)),
                     parcel::join(
                         match_u32(encoding),
                         parcel::join(
@@ -1011,13 +1595,15 @@ where
 {
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], FileHeader<ElfAddr64>> {
         let encoding = EiData::from(E::default());
+        let options = self.options;
 
         parcel::join(
             TypeParser::<E>::new(),
             parcel::join(
                 MachineParser::<E>::new(),
                 parcel::join(
-                    VersionParser::<E>::new(),
+                    VersionParser::<E>::new()
+                        .or(move || lenient(options, match_u32(encoding)).map(Version::Other)),
                     parcel::join(
                         match_u64(encoding),
                         parcel::join(
@@ -1097,7 +1683,7 @@ where
 }
 
 /// ProgramHeaderType represents each type of program header.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u32)]
 pub enum ProgramHeaderType {
     Null = 0x00,
@@ -1123,9 +1709,10 @@ impl From<ProgramHeaderType> for u32 {
     }
 }
 
-impl std::fmt::Display for ProgramHeaderType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let repr = match self {
+impl ProgramHeaderType {
+    /// Returns the name the type is rendered with, without allocating.
+    pub fn type_str(&self) -> &'static str {
+        match self {
             ProgramHeaderType::Null => "Null",
             ProgramHeaderType::Load => "Load",
             ProgramHeaderType::Dynamic => "Dynamic",
@@ -1141,9 +1728,13 @@ impl std::fmt::Display for ProgramHeaderType {
             ProgramHeaderType::GnuEhFrame => "GNU_EH_FRAME",
             ProgramHeaderType::GnuStack => "GNU_STACK",
             ProgramHeaderType::GnuRelro => "GNU_RELRO",
-        };
+        }
+    }
+}
 
-        write!(f, "{}", repr)
+impl std::fmt::Display for ProgramHeaderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.type_str())
     }
 }
 
@@ -1205,63 +1796,282 @@ impl<'a> parcel::Parser<'a, &'a [u8], ProgramHeaderType> for ProgramHeaderTypePa
     }
 }
 
-/// Represents any kind of ProgramHeader, functioning as a way to link the 32
-/// and 64-bit ProgramHeader types.
-pub trait ProgramHeader {}
-
-/// Program header represents a Elf Program header for the 32-bit arrangement.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ProgramHeader32 {
-    pub r#type: ProgramHeaderType,
-    pub offset: u32,
-    pub vaddr: u32,
-    pub paddr: u32,
-    pub filesz: u32,
-    pub memsz: u32,
-    pub flags: u32,
-    pub align: u32,
+/// Permissions represents the PF_R, PF_W and PF_X bits of a program header's
+/// flags field.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Permissions {
+    pub read: bool,
+    pub write: bool,
+    pub execute: bool,
 }
 
-impl ProgramHeader for ProgramHeader32 {}
+impl Permissions {
+    const EXECUTE: u32 = 0x01;
+    const WRITE: u32 = 0x02;
+    const READ: u32 = 0x04;
+    const MASK: u32 = Self::READ | Self::WRITE | Self::EXECUTE;
 
-impl Serialize<ElfAddr32, LittleEndian> for ProgramHeader32 {
-    fn serialize(&self) -> Vec<u8> {
-        vec![
-            Into::<u32>::into(self.r#type).to_le_bytes().to_vec(),
-            self.offset.to_le_bytes().to_vec(),
-            self.vaddr.to_le_bytes().to_vec(),
-            self.paddr.to_le_bytes().to_vec(),
-            self.filesz.to_le_bytes().to_vec(),
-            self.flags.to_le_bytes().to_vec(),
-            self.align.to_le_bytes().to_vec(),
-        ]
-        .into_iter()
-        .flatten()
-        .collect()
+    /// Returns the passed flags with their permission bits replaced by those
+    /// of self, preserving any OS or processor-specific bits.
+    fn apply(self, flags: u32) -> u32 {
+        (flags & !Self::MASK) | u32::from(self)
     }
 }
 
-impl Serialize<ElfAddr32, BigEndian> for ProgramHeader32 {
-    fn serialize(&self) -> Vec<u8> {
-        vec![
-            Into::<u32>::into(self.r#type).to_be_bytes().to_vec(),
-            self.offset.to_be_bytes().to_vec(),
-            self.vaddr.to_be_bytes().to_vec(),
-            self.paddr.to_be_bytes().to_vec(),
-            self.filesz.to_be_bytes().to_vec(),
-            self.flags.to_be_bytes().to_vec(),
-            self.align.to_be_bytes().to_vec(),
-        ]
-        .into_iter()
-        .flatten()
-        .collect()
+impl std::fmt::Display for Permissions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}{}",
+            if self.read { 'R' } else { ' ' },
+            if self.write { 'W' } else { ' ' },
+            if self.execute { 'E' } else { ' ' }
+        )
     }
 }
 
-/// Program header represents a Elf Program header for the 64-bit arrangement.
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ProgramHeader64 {
-    pub r#type: ProgramHeaderType,
+impl From<Permissions> for u32 {
+    fn from(src: Permissions) -> Self {
+        let mut flags = 0;
+        if src.read {
+            flags |= Permissions::READ;
+        }
+        if src.write {
+            flags |= Permissions::WRITE;
+        }
+        if src.execute {
+            flags |= Permissions::EXECUTE;
+        }
+
+        flags
+    }
+}
+
+impl From<u32> for Permissions {
+    fn from(flags: u32) -> Self {
+        Self {
+            read: flags & Permissions::READ != 0,
+            write: flags & Permissions::WRITE != 0,
+            execute: flags & Permissions::EXECUTE != 0,
+        }
+    }
+}
+
+/// LoadSegment describes a PT_LOAD segment in the terms a loader needs: the
+/// file range to copy, the memory range to map it to and its permissions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadSegment {
+    pub file_offset: u64,
+    pub file_size: u64,
+    pub mem_addr: u64,
+    pub mem_size: u64,
+    pub flags: Permissions,
+}
+
+impl LoadSegment {
+    /// Returns the size of the zero-filled region, such as `.bss`, following
+    /// the file contents in memory.
+    pub fn zero_fill_size(&self) -> u64 {
+        self.mem_size.saturating_sub(self.file_size)
+    }
+}
+
+impl<P: ProgramHeader> From<&P> for LoadSegment {
+    fn from(ph: &P) -> Self {
+        Self {
+            file_offset: ph.p_offset(),
+            file_size: ph.p_filesz(),
+            mem_addr: ph.p_vaddr(),
+            mem_size: ph.p_memsz(),
+            flags: Permissions::from(ph.p_flags()),
+        }
+    }
+}
+
+/// Returns a LoadSegment for each PT_LOAD segment, in program header order.
+fn load_map<P: ProgramHeader>(program_headers: &[P]) -> Vec<LoadSegment> {
+    program_headers
+        .iter()
+        .filter(|ph| ph.p_type() == ProgramHeaderType::Load)
+        .map(LoadSegment::from)
+        .collect()
+}
+
+/// Returns, for each section, the index of the first PT_LOAD segment
+/// containing it, as in the section to segment mapping printed by binutils'
+/// `readelf -l`. Sections are matched by their file offset range, or for
+/// SHT_NOBITS sections by their address range within the segment's memory
+/// size. SHT_NULL sections and sections without an address, which aren't
+/// allocated, map to `None`.
+fn section_to_segment_map<P, S>(program_headers: &[P], section_headers: &[S]) -> Vec<Option<usize>>
+where
+    P: ProgramHeader,
+    S: SectionHeader,
+{
+    let contains = |ph: &P, sh: &S| match sh.sh_type() {
+        ShType::NoBits => {
+            sh.sh_addr() >= ph.p_vaddr()
+                && sh.sh_addr().saturating_add(sh.sh_size())
+                    <= ph.p_vaddr().saturating_add(ph.p_memsz())
+        }
+        _ => {
+            sh.sh_offset() >= ph.p_offset()
+                && sh.sh_offset().saturating_add(sh.sh_size())
+                    <= ph.p_offset().saturating_add(ph.p_filesz())
+        }
+    };
+
+    section_headers
+        .iter()
+        .map(|sh| {
+            if sh.sh_type() == ShType::Null || sh.sh_addr() == 0 {
+                return None;
+            }
+
+            program_headers
+                .iter()
+                .position(|ph| ph.p_type() == ProgramHeaderType::Load && contains(ph, sh))
+        })
+        .collect()
+}
+
+/// Returns the `[start, end)` virtual address ranges left unmapped between
+/// PT_LOAD segments, ordered by address. Segment ends are taken as
+/// `p_vaddr + p_memsz` without rounding to page boundaries.
+fn address_gaps<P: ProgramHeader>(program_headers: &[P]) -> Vec<(u64, u64)> {
+    let mut loads: Vec<(u64, u64)> = program_headers
+        .iter()
+        .filter(|ph| ph.p_type() == ProgramHeaderType::Load)
+        .map(|ph| (ph.p_vaddr(), ph.p_vaddr().saturating_add(ph.p_memsz())))
+        .collect();
+    loads.sort_unstable();
+
+    let mut gaps = vec![];
+    let mut mapped_end = match loads.first() {
+        Some(&(_, end)) => end,
+        None => return gaps,
+    };
+    for &(start, end) in loads.iter().skip(1) {
+        if start > mapped_end {
+            gaps.push((mapped_end, start));
+        }
+        mapped_end = std::cmp::max(mapped_end, end);
+    }
+
+    gaps
+}
+
+/// Represents any kind of ProgramHeader, functioning as a way to link the 32
+/// and 64-bit ProgramHeader types, exposing each field widened to its 64-bit
+/// representation.
+pub trait ProgramHeader {
+    fn p_type(&self) -> ProgramHeaderType;
+    fn p_flags(&self) -> u32;
+    fn p_offset(&self) -> u64;
+    fn p_vaddr(&self) -> u64;
+    fn p_paddr(&self) -> u64;
+    fn p_filesz(&self) -> u64;
+    fn p_memsz(&self) -> u64;
+    fn p_align(&self) -> u64;
+
+    /// Returns the slice of the passed image backing the segment's file
+    /// contents, or `None` if its range falls outside of the image.
+    fn data<'a>(&self, image: &'a [u8]) -> Option<&'a [u8]> {
+        use std::convert::TryFrom;
+
+        let start = usize::try_from(self.p_offset()).ok()?;
+        let end = start.checked_add(usize::try_from(self.p_filesz()).ok()?)?;
+        image.get(start..end)
+    }
+}
+
+/// Program header represents a Elf Program header for the 32-bit arrangement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProgramHeader32 {
+    pub r#type: ProgramHeaderType,
+    pub offset: u32,
+    pub vaddr: u32,
+    pub paddr: u32,
+    pub filesz: u32,
+    pub memsz: u32,
+    pub flags: u32,
+    pub align: u32,
+}
+
+impl ProgramHeader for ProgramHeader32 {
+    fn p_type(&self) -> ProgramHeaderType {
+        self.r#type
+    }
+
+    fn p_flags(&self) -> u32 {
+        self.flags
+    }
+
+    fn p_offset(&self) -> u64 {
+        self.offset.into()
+    }
+
+    fn p_vaddr(&self) -> u64 {
+        self.vaddr.into()
+    }
+
+    fn p_paddr(&self) -> u64 {
+        self.paddr.into()
+    }
+
+    fn p_filesz(&self) -> u64 {
+        self.filesz.into()
+    }
+
+    fn p_memsz(&self) -> u64 {
+        self.memsz.into()
+    }
+
+    fn p_align(&self) -> u64 {
+        self.align.into()
+    }
+}
+
+impl Serialize<ElfAddr32, LittleEndian> for ProgramHeader32 {
+    fn serialize(&self) -> Vec<u8> {
+        vec![
+            Into::<u32>::into(self.r#type).to_le_bytes().to_vec(),
+            self.offset.to_le_bytes().to_vec(),
+            self.vaddr.to_le_bytes().to_vec(),
+            self.paddr.to_le_bytes().to_vec(),
+            self.filesz.to_le_bytes().to_vec(),
+            self.memsz.to_le_bytes().to_vec(),
+            self.flags.to_le_bytes().to_vec(),
+            self.align.to_le_bytes().to_vec(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Serialize<ElfAddr32, BigEndian> for ProgramHeader32 {
+    fn serialize(&self) -> Vec<u8> {
+        vec![
+            Into::<u32>::into(self.r#type).to_be_bytes().to_vec(),
+            self.offset.to_be_bytes().to_vec(),
+            self.vaddr.to_be_bytes().to_vec(),
+            self.paddr.to_be_bytes().to_vec(),
+            self.filesz.to_be_bytes().to_vec(),
+            self.memsz.to_be_bytes().to_vec(),
+            self.flags.to_be_bytes().to_vec(),
+            self.align.to_be_bytes().to_vec(),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+/// Program header represents a Elf Program header for the 64-bit arrangement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ProgramHeader64 {
+    pub r#type: ProgramHeaderType,
     pub flags: u32,
     pub offset: u64,
     pub vaddr: u64,
@@ -1271,17 +2081,50 @@ pub struct ProgramHeader64 {
     pub align: u64,
 }
 
-impl ProgramHeader for ProgramHeader64 {}
+impl ProgramHeader for ProgramHeader64 {
+    fn p_type(&self) -> ProgramHeaderType {
+        self.r#type
+    }
+
+    fn p_flags(&self) -> u32 {
+        self.flags
+    }
+
+    fn p_offset(&self) -> u64 {
+        self.offset
+    }
+
+    fn p_vaddr(&self) -> u64 {
+        self.vaddr
+    }
+
+    fn p_paddr(&self) -> u64 {
+        self.paddr
+    }
+
+    fn p_filesz(&self) -> u64 {
+        self.filesz
+    }
+
+    fn p_memsz(&self) -> u64 {
+        self.memsz
+    }
+
+    fn p_align(&self) -> u64 {
+        self.align
+    }
+}
 
 impl Serialize<ElfAddr64, LittleEndian> for ProgramHeader64 {
     fn serialize(&self) -> Vec<u8> {
         vec![
             Into::<u32>::into(self.r#type).to_le_bytes().to_vec(),
+            self.flags.to_le_bytes().to_vec(),
             self.offset.to_le_bytes().to_vec(),
             self.vaddr.to_le_bytes().to_vec(),
             self.paddr.to_le_bytes().to_vec(),
             self.filesz.to_le_bytes().to_vec(),
-            self.flags.to_le_bytes().to_vec(),
+            self.memsz.to_le_bytes().to_vec(),
             self.align.to_le_bytes().to_vec(),
         ]
         .into_iter()
@@ -1294,11 +2137,12 @@ impl Serialize<ElfAddr64, BigEndian> for ProgramHeader64 {
     fn serialize(&self) -> Vec<u8> {
         vec![
             Into::<u32>::into(self.r#type).to_be_bytes().to_vec(),
+            self.flags.to_be_bytes().to_vec(),
             self.offset.to_be_bytes().to_vec(),
             self.vaddr.to_be_bytes().to_vec(),
             self.paddr.to_be_bytes().to_vec(),
             self.filesz.to_be_bytes().to_vec(),
-            self.flags.to_be_bytes().to_vec(),
+            self.memsz.to_be_bytes().to_vec(),
             self.align.to_be_bytes().to_vec(),
         ]
         .into_iter()
@@ -1341,6 +2185,8 @@ where
     }
 }
 
+impl_class_constructors!(ProgramHeaderParser);
+
 impl<'a, E> parcel::Parser<'a, &'a [u8], ProgramHeader32> for ProgramHeaderParser<ElfAddr32, E>
 where
     EiData: From<E>,
@@ -1423,7 +2269,7 @@ where
 
 /// ShType reprents all representable formats of the sh_type filed of a section
 /// header.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u32)]
 pub enum ShType {
     Null = 0x00,
@@ -1441,20 +2287,22 @@ pub enum ShType {
     InitArray = 0x0e,
     FiniArray = 0x0f,
     PreInitArray = 0x10,
-    GnuHash = 0x11,
-    Group = 0x12,
-    SymTabShndx = 0x13,
-    GnuVerDef = 0x14,
-    GnuVerNeed = 0x15,
-    GnuVerSym = 0x16,
+    Group = 0x11,
+    SymTabShndx = 0x12,
+    GnuHash = 0x6ffffff6,
+    GnuVerDef = 0x6ffffffd,
+    GnuVerNeed = 0x6ffffffe,
+    GnuVerSym = 0x6fffffff,
+    ArmAttributes = 0x70000003,
     Auxiliary = 0x7ffffffd,
     Used = 0x7ffffffe,
     Filter = 0x7fffffff,
 }
 
-impl std::fmt::Display for ShType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let repr = match self {
+impl ShType {
+    /// Returns the name the type is rendered with, without allocating.
+    pub fn type_str(&self) -> &'static str {
+        match self {
             ShType::Null => "NULL",
             ShType::ProgBits => "PROG_Bits",
             ShType::SymTab => "SYM_TAB",
@@ -1476,12 +2324,17 @@ impl std::fmt::Display for ShType {
             ShType::GnuVerDef => "VERDEF",
             ShType::GnuVerNeed => "VERNEED",
             ShType::GnuVerSym => "VERSYM",
+            ShType::ArmAttributes => "ARM_ATTRIBUTES",
             ShType::Auxiliary => "AUXILIARY",
             ShType::Used => "USED",
             ShType::Filter => "FILTER",
-        };
+        }
+    }
+}
 
-        write!(f, "{}", repr)
+impl std::fmt::Display for ShType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(self.type_str())
     }
 }
 
@@ -1546,9 +2399,10 @@ where
             expect_u32(encoding, ShType::GnuHash as u32).map(|_| ShType::GnuHash),
             expect_u32(encoding, ShType::Group as u32).map(|_| ShType::Group),
             expect_u32(encoding, ShType::SymTabShndx as u32).map(|_| ShType::SymTabShndx),
-            expect_u32(encoding, 0x14).map(|_| ShType::GnuVerDef),
+            expect_u32(encoding, ShType::GnuVerDef as u32).map(|_| ShType::GnuVerDef),
             expect_u32(encoding, ShType::GnuVerNeed as u32).map(|_| ShType::GnuVerNeed),
-            expect_u32(encoding, 0x16).map(|_| ShType::GnuVerSym),
+            expect_u32(encoding, ShType::GnuVerSym as u32).map(|_| ShType::GnuVerSym),
+            expect_u32(encoding, ShType::ArmAttributes as u32).map(|_| ShType::ArmAttributes),
             expect_u32(encoding, ShType::Auxiliary as u32).map(|_| ShType::Auxiliary),
             expect_u32(encoding, ShType::Used as u32).map(|_| ShType::Used),
             expect_u32(encoding, ShType::Filter as u32).map(|_| ShType::Filter),
@@ -1565,34 +2419,75 @@ where
 
 /// ShFlags32Bit reprents all representable formats of the sh_flags filed of a
 /// section header.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ShFlags32 {
-    Write = 0x01,
-    Other = 0x9999,
+    Write,
+    Compressed,
+    /// Other represents any other combination of flags, carrying the raw
+    /// sh_flags value.
+    Other(u32),
 }
 
 impl From<ShFlags32> for u32 {
     fn from(src: ShFlags32) -> Self {
-        src as u32
+        match src {
+            ShFlags32::Write => 0x01,
+            ShFlags32::Compressed => 0x800,
+            ShFlags32::Other(flags) => flags,
+        }
+    }
+}
+
+impl From<u32> for ShFlags32 {
+    fn from(flags: u32) -> Self {
+        match flags {
+            0x01 => ShFlags32::Write,
+            0x800 => ShFlags32::Compressed,
+            flags => ShFlags32::Other(flags),
+        }
     }
 }
 
 /// ShFlags64Bit reprents all representable formats of the sh_flags filed of a
 /// section header.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[repr(u64)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ShFlags64 {
-    Write = 0x01,
-    Other = 0x9999,
+    Write,
+    Compressed,
+    /// Other represents any other combination of flags, carrying the raw
+    /// sh_flags value.
+    Other(u64),
 }
 
 impl From<ShFlags64> for u64 {
     fn from(src: ShFlags64) -> Self {
-        src as u64
+        match src {
+            ShFlags64::Write => 0x01,
+            ShFlags64::Compressed => 0x800,
+            ShFlags64::Other(flags) => flags,
+        }
+    }
+}
+
+impl From<u64> for ShFlags64 {
+    fn from(flags: u64) -> Self {
+        match flags {
+            0x01 => ShFlags64::Write,
+            0x800 => ShFlags64::Compressed,
+            flags => ShFlags64::Other(flags),
+        }
     }
 }
 
+/// The section is writable at runtime.
+pub const SHF_WRITE: u64 = 0x01;
+/// The section occupies memory during execution.
+pub const SHF_ALLOC: u64 = 0x02;
+/// The section contains executable instructions.
+pub const SHF_EXECINSTR: u64 = 0x04;
+/// The section holds compressed data following a compression header.
+pub const SHF_COMPRESSED: u64 = 0x800;
+
 /// Provides a parser for ShFlags for a given address width and endianness.
 pub struct ShFlagsParser<A, E>
 where
@@ -1634,11 +2529,7 @@ where
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], ShFlags32> {
         let encoding = EiData::from(E::default());
 
-        parcel::one_of(vec![
-            expect_u32(encoding, ShFlags32::Write as u32).map(|_| ShFlags32::Write)
-        ])
-        .or(move || match_u32(encoding).map(|_| ShFlags32::Other))
-        .parse(input)
+        match_u32(encoding).map(ShFlags32::from).parse(input)
     }
 }
 
@@ -1650,20 +2541,129 @@ where
     fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], ShFlags64> {
         let encoding = EiData::from(E::default());
 
-        parcel::one_of(vec![
-            expect_u64(encoding, ShFlags64::Write as u64).map(|_| ShFlags64::Write)
-        ])
-        .or(move || match_u64(encoding).map(|_| ShFlags64::Other))
-        .parse(input)
+        match_u64(encoding).map(ShFlags64::from).parse(input)
     }
 }
 
 /// Provides a trait for identifying Section headers. Functionally this works
-/// to link the 32-bit and 64-bit SectionHeader types.
-pub trait SectionHeader {}
+/// to link the 32-bit and 64-bit SectionHeader types, exposing each field
+/// widened to its 64-bit representation.
+pub trait SectionHeader {
+    fn sh_name(&self) -> u32;
+    fn sh_type(&self) -> ShType;
+    fn sh_flags(&self) -> u64;
+    fn sh_addr(&self) -> u64;
+    fn sh_offset(&self) -> u64;
+    fn sh_size(&self) -> u64;
+    fn sh_link(&self) -> u32;
+    fn sh_info(&self) -> u32;
+    fn sh_addr_align(&self) -> u64;
+    fn sh_entsize(&self) -> u64;
+
+    /// Returns the slice of the passed image that backs the section, or
+    /// `None` if the section occupies no space in the file or its range falls
+    /// outside of the image.
+    fn data<'a>(&self, image: &'a [u8]) -> Option<&'a [u8]> {
+        use std::convert::TryFrom;
+
+        if self.sh_type() == ShType::NoBits {
+            return None;
+        }
+
+        let start = usize::try_from(self.sh_offset()).ok()?;
+        let end = start.checked_add(usize::try_from(self.sh_size()).ok()?)?;
+        image.get(start..end)
+    }
+
+    /// Describes what the sh_link field references for this section's type.
+    fn link_meaning(&self) -> LinkMeaning {
+        let link = self.sh_link();
+        match self.sh_type() {
+            ShType::SymTab | ShType::DynSym | ShType::Dynamic => LinkMeaning::StringTable(link),
+            ShType::GnuVerDef | ShType::GnuVerNeed => LinkMeaning::StringTable(link),
+            ShType::Rel | ShType::Rela | ShType::Hash | ShType::GnuHash => {
+                LinkMeaning::SymbolTable(link)
+            }
+            ShType::Group | ShType::SymTabShndx | ShType::GnuVerSym => {
+                LinkMeaning::SymbolTable(link)
+            }
+            _ if link == 0 => LinkMeaning::None,
+            _ => LinkMeaning::Unknown(link),
+        }
+    }
+
+    /// Describes what the sh_info field references for this section's type.
+    fn info_meaning(&self) -> LinkMeaning {
+        let info = self.sh_info();
+        match self.sh_type() {
+            ShType::SymTab | ShType::DynSym => LinkMeaning::FirstNonLocalSymbol(info),
+            ShType::Rel | ShType::Rela if info != 0 => LinkMeaning::TargetSection(info),
+            ShType::Group => LinkMeaning::SignatureSymbol(info),
+            ShType::GnuVerDef | ShType::GnuVerNeed => LinkMeaning::EntryCount(info),
+            _ if info == 0 => LinkMeaning::None,
+            _ => LinkMeaning::Unknown(info),
+        }
+    }
+
+    /// Returns the number of entries in a symbol table section, or 0 for
+    /// any other section or a symbol table without an sh_entsize.
+    fn symbol_count(&self) -> usize {
+        match self.sh_type() {
+            ShType::SymTab | ShType::DynSym if self.sh_entsize() != 0 => {
+                (self.sh_size() / self.sh_entsize()) as usize
+            }
+            _ => 0,
+        }
+    }
+
+    /// Returns the number of local symbols preceding the global symbols of a
+    /// symbol table section, as given by sh_info. An sh_info past the end of
+    /// the table, which `validate_symbol_info` reports, is clamped to the
+    /// symbol count.
+    fn local_symbol_count(&self) -> usize {
+        std::cmp::min(self.sh_info() as usize, self.symbol_count())
+    }
+
+    /// Checks that a symbol table's sh_info, the index of its first
+    /// non-local symbol, doesn't exceed its symbol count.
+    fn validate_symbol_info(&self) -> Result<(), ValidationError> {
+        match self.sh_type() {
+            ShType::SymTab | ShType::DynSym if self.sh_info() as usize > self.symbol_count() => {
+                Err(ValidationError::SymbolInfoOutOfRange {
+                    sh_info: self.sh_info(),
+                    symbol_count: self.symbol_count() as u64,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// LinkMeaning describes what a section header's sh_link or sh_info field
+/// refers to, which depends on the section's type. Section indices and
+/// symbol indices are carried alongside their interpretation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkMeaning {
+    /// The field is unused for this section type.
+    None,
+    /// The section index of the associated string table.
+    StringTable(u32),
+    /// The section index of the associated symbol table.
+    SymbolTable(u32),
+    /// The section index of the section relocations apply to.
+    TargetSection(u32),
+    /// One greater than the symbol table index of the last local symbol.
+    FirstNonLocalSymbol(u32),
+    /// The index of the symbol whose name is the section group's signature.
+    SignatureSymbol(u32),
+    /// The number of entries in a version definition or requirement section.
+    EntryCount(u32),
+    /// A non-zero value whose meaning isn't defined for this section type.
+    Unknown(u32),
+}
 
 /// Section header represents a Elf Program header.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SectionHeader32 {
     pub sh_name: u32,
     pub sh_type: ShType,
@@ -1677,7 +2677,47 @@ pub struct SectionHeader32 {
     pub sh_entsize: u32,
 }
 
-impl SectionHeader for SectionHeader32 {}
+impl SectionHeader for SectionHeader32 {
+    fn sh_name(&self) -> u32 {
+        self.sh_name
+    }
+
+    fn sh_type(&self) -> ShType {
+        self.sh_type
+    }
+
+    fn sh_flags(&self) -> u64 {
+        u32::from(self.sh_flags).into()
+    }
+
+    fn sh_addr(&self) -> u64 {
+        self.sh_addr.into()
+    }
+
+    fn sh_offset(&self) -> u64 {
+        self.sh_offset.into()
+    }
+
+    fn sh_size(&self) -> u64 {
+        self.sh_size.into()
+    }
+
+    fn sh_link(&self) -> u32 {
+        self.sh_link
+    }
+
+    fn sh_info(&self) -> u32 {
+        self.sh_info
+    }
+
+    fn sh_addr_align(&self) -> u64 {
+        self.sh_addr_align.into()
+    }
+
+    fn sh_entsize(&self) -> u64 {
+        self.sh_entsize.into()
+    }
+}
 
 impl Serialize<ElfAddr32, LittleEndian> for SectionHeader32 {
     fn serialize(&self) -> Vec<u8> {
@@ -1760,7 +2800,7 @@ impl Serialize<ElfAddr64, BigEndian> for SectionHeader64 {
 }
 
 /// Section header represents a Elf Program header.
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct SectionHeader64 {
     pub sh_name: u32,
     pub sh_type: ShType,
@@ -1774,7 +2814,47 @@ pub struct SectionHeader64 {
     pub sh_entsize: u64,
 }
 
-impl SectionHeader for SectionHeader64 {}
+impl SectionHeader for SectionHeader64 {
+    fn sh_name(&self) -> u32 {
+        self.sh_name
+    }
+
+    fn sh_type(&self) -> ShType {
+        self.sh_type
+    }
+
+    fn sh_flags(&self) -> u64 {
+        self.sh_flags.into()
+    }
+
+    fn sh_addr(&self) -> u64 {
+        self.sh_addr
+    }
+
+    fn sh_offset(&self) -> u64 {
+        self.sh_offset
+    }
+
+    fn sh_size(&self) -> u64 {
+        self.sh_size
+    }
+
+    fn sh_link(&self) -> u32 {
+        self.sh_link
+    }
+
+    fn sh_info(&self) -> u32 {
+        self.sh_info
+    }
+
+    fn sh_addr_align(&self) -> u64 {
+        self.sh_addr_align
+    }
+
+    fn sh_entsize(&self) -> u64 {
+        self.sh_entsize
+    }
+}
 
 /// Implements a parser for SectionHeaders of a given endianness and address width.
 pub struct SectionHeaderParser<A, E>
@@ -1809,6 +2889,8 @@ where
     }
 }
 
+impl_class_constructors!(SectionHeaderParser);
+
 impl<'a, E> parcel::Parser<'a, &'a [u8], SectionHeader32> for SectionHeaderParser<ElfAddr32, E>
 where
     EiData: From<E>,
@@ -1931,149 +3013,482 @@ where
     }
 }
 
-/// ElfHeader represents an ELF Header and functions to link the 32-bit and
-/// 64-bit ElfHeader types.
-pub trait ElfHeader {}
+/// CompressionType represents the ch_type field of a compression header,
+/// identifying the algorithm used for an SHF_COMPRESSED section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u32)]
+pub enum CompressionType {
+    Zlib = 0x01,
+    Zstd = 0x02,
+    Unknown = 0xffffffff,
+}
 
-/// ElfHeader32Bit captures the full ELF file header into a single struct along
-/// with the Identification information separated from the file header.
-#[derive(Debug, Clone, PartialEq)]
-pub struct ElfHeader32<E>
+impl From<CompressionType> for u32 {
+    fn from(src: CompressionType) -> Self {
+        src as u32
+    }
+}
+
+/// CompressionHeader represents the Elf32_Chdr/Elf64_Chdr found at the start
+/// of an SHF_COMPRESSED section, with each field widened to 64 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompressionHeader {
+    pub ch_type: CompressionType,
+    pub ch_size: u64,
+    pub ch_addralign: u64,
+}
+
+/// CompressionHeaderParser parses the compression header of a compressed
+/// section for a given address width and endianness.
+pub struct CompressionHeaderParser<A, E>
 where
-    E: DataEncoding + Default + 'static,
+    A: AddressWidth,
+    E: DataEncoding,
 {
+    address_width: std::marker::PhantomData<A>,
     endianness: std::marker::PhantomData<E>,
-    pub ei_ident: EiIdent,
-    pub file_header: FileHeader<ElfAddr32>,
-    pub program_headers: Vec<ProgramHeader32>,
-    pub section_headers: Vec<SectionHeader32>,
 }
 
-impl<E> ElfHeader32<E>
+impl<A, E> CompressionHeaderParser<A, E>
 where
-    E: DataEncoding + Default + 'static,
+    A: AddressWidth,
+    E: DataEncoding,
 {
-    pub fn new(
-        ei_ident: EiIdent,
-        file_header: FileHeader<ElfAddr32>,
-        program_headers: Vec<ProgramHeader32>,
-        section_headers: Vec<SectionHeader32>,
-    ) -> Self {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<A, E> Default for CompressionHeaderParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    fn default() -> Self {
         Self {
+            address_width: std::marker::PhantomData,
             endianness: std::marker::PhantomData,
-            ei_ident,
-            file_header,
-            program_headers,
-            section_headers,
         }
     }
 }
 
-impl ElfHeader for ElfHeader32<LittleEndian> {}
+fn compression_type<'a>(encoding: EiData) -> impl Parser<'a, &'a [u8], CompressionType> {
+    match_u32(encoding).map(|ch_type| match ch_type {
+        0x01 => CompressionType::Zlib,
+        0x02 => CompressionType::Zstd,
+        _ => CompressionType::Unknown,
+    })
+}
 
-impl<E> From<ElfHeader32<E>> for Vec<u8>
+impl<'a, E> parcel::Parser<'a, &'a [u8], CompressionHeader>
+    for CompressionHeaderParser<ElfAddr32, E>
 where
-    SectionHeader32: Serialize<ElfAddr32, E>,
-    ProgramHeader32: Serialize<ElfAddr32, E>,
-    FileHeader<ElfAddr32>: Serialize<ElfAddr32, E>,
+    EiData: From<E>,
     E: DataEncoding + Default + 'static,
 {
-    fn from(src: ElfHeader32<E>) -> Self {
-        let ident_bytes = Into::<Vec<u8>>::into(src.ei_ident);
-        let fh_bytes: Vec<u8> = Serialize::<ElfAddr32, E>::serialize(&src.file_header);
-        let ph_bytes: Vec<u8> = src
-            .program_headers
-            .iter()
-            .map(|ph| Serialize::<ElfAddr32, E>::serialize(ph))
-            .into_iter()
-            .flatten()
-            .collect();
-        let sh_bytes: Vec<u8> = src
-            .section_headers
-            .iter()
-            .map(|sh| Serialize::<ElfAddr32, E>::serialize(sh))
-            .into_iter()
-            .flatten()
-            .collect();
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], CompressionHeader> {
+        let encoding = EiData::from(E::default());
 
-        vec![ident_bytes, fh_bytes, ph_bytes, sh_bytes]
-            .into_iter()
-            .flatten()
-            .collect()
+        parcel::join(
+            compression_type(encoding),
+            parcel::join(match_u32(encoding), match_u32(encoding)),
+        )
+        .map(|(ch_type, (ch_size, ch_addralign))| CompressionHeader {
+            ch_type,
+            ch_size: u64::from(ch_size),
+            ch_addralign: u64::from(ch_addralign),
+        })
+        .parse(input)
     }
 }
 
-/// ElfHeader64Bit captures the full ELF file header into a single struct along
-/// with the Identification information separated from the file header.
-#[derive(Debug, Clone, PartialEq)]
-pub struct ElfHeader64<E>
+impl<'a, E> parcel::Parser<'a, &'a [u8], CompressionHeader>
+    for CompressionHeaderParser<ElfAddr64, E>
 where
-    E: DataEncoding,
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
 {
-    endianness: std::marker::PhantomData<E>,
-    pub ei_ident: EiIdent,
-    pub file_header: FileHeader<ElfAddr64>,
-    pub program_headers: Vec<ProgramHeader64>,
-    pub section_headers: Vec<SectionHeader64>,
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], CompressionHeader> {
+        let encoding = EiData::from(E::default());
+
+        parcel::join(
+            parcel::left(parcel::join(
+                compression_type(encoding),
+                match_u32(encoding),
+            )),
+            parcel::join(match_u64(encoding), match_u64(encoding)),
+        )
+        .map(|(ch_type, (ch_size, ch_addralign))| CompressionHeader {
+            ch_type,
+            ch_size,
+            ch_addralign,
+        })
+        .parse(input)
+    }
 }
 
-impl<E> ElfHeader64<E>
-where
-    E: DataEncoding,
-{
-    pub fn new(
-        ei_ident: EiIdent,
-        file_header: FileHeader<ElfAddr64>,
-        program_headers: Vec<ProgramHeader64>,
-        section_headers: Vec<SectionHeader64>,
-    ) -> Self {
-        Self {
-            endianness: std::marker::PhantomData,
-            ei_ident,
-            file_header,
-            program_headers,
-            section_headers,
+/// SymbolVisibility represents the visibility of a symbol, as encoded in the
+/// low two bits of st_other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SymbolVisibility {
+    Default = 0x00,
+    Internal = 0x01,
+    Hidden = 0x02,
+    Protected = 0x03,
+}
+
+impl std::fmt::Display for SymbolVisibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            SymbolVisibility::Default => "DEFAULT",
+            SymbolVisibility::Internal => "INTERNAL",
+            SymbolVisibility::Hidden => "HIDDEN",
+            SymbolVisibility::Protected => "PROTECTED",
+        };
+
+        write!(f, "{}", repr)
+    }
+}
+
+impl From<SymbolVisibility> for u8 {
+    fn from(src: SymbolVisibility) -> Self {
+        src as u8
+    }
+}
+
+/// SymbolBinding represents the binding of a symbol, as encoded in the high
+/// four bits of st_info.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SymbolBinding {
+    Local = 0x00,
+    Global = 0x01,
+    Weak = 0x02,
+    GnuUnique = 0x0a,
+}
+
+impl std::fmt::Display for SymbolBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            SymbolBinding::Local => "LOCAL",
+            SymbolBinding::Global => "GLOBAL",
+            SymbolBinding::Weak => "WEAK",
+            SymbolBinding::GnuUnique => "UNIQUE",
+        };
+
+        write!(f, "{}", repr)
+    }
+}
+
+impl From<SymbolBinding> for u8 {
+    fn from(src: SymbolBinding) -> Self {
+        src as u8
+    }
+}
+
+impl std::convert::TryFrom<u8> for SymbolBinding {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(SymbolBinding::Local),
+            0x01 => Ok(SymbolBinding::Global),
+            0x02 => Ok(SymbolBinding::Weak),
+            0x0a => Ok(SymbolBinding::GnuUnique),
+            _ => Err(format!("cannot convert {} to SymbolBinding variant", value)),
         }
     }
 }
 
-impl<E: DataEncoding> ElfHeader for ElfHeader64<E> {}
+/// SymbolType represents the type of a symbol, as encoded in the low four
+/// bits of st_info.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum SymbolType {
+    NoType = 0x00,
+    Object = 0x01,
+    Func = 0x02,
+    Section = 0x03,
+    File = 0x04,
+    Common = 0x05,
+    Tls = 0x06,
+    GnuIFunc = 0x0a,
+}
 
-impl<E> From<ElfHeader64<E>> for Vec<u8>
-where
-    SectionHeader64: Serialize<ElfAddr64, E>,
-    ProgramHeader64: Serialize<ElfAddr64, E>,
-    FileHeader<ElfAddr64>: Serialize<ElfAddr64, E>,
-    E: DataEncoding + Default + 'static,
-{
-    fn from(src: ElfHeader64<E>) -> Self {
-        let ident_bytes = Into::<Vec<u8>>::into(src.ei_ident);
-        let fh_bytes: Vec<u8> = Serialize::<ElfAddr64, E>::serialize(&src.file_header);
-        let ph_bytes: Vec<u8> = src
-            .program_headers
-            .iter()
-            .map(|ph| Serialize::<ElfAddr64, E>::serialize(ph))
-            .into_iter()
-            .flatten()
-            .collect();
-        let sh_bytes: Vec<u8> = src
-            .section_headers
-            .iter()
-            .map(|sh| Serialize::<ElfAddr64, E>::serialize(sh))
-            .into_iter()
-            .flatten()
-            .collect();
+impl std::fmt::Display for SymbolType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            SymbolType::NoType => "NOTYPE",
+            SymbolType::Object => "OBJECT",
+            SymbolType::Func => "FUNC",
+            SymbolType::Section => "SECTION",
+            SymbolType::File => "FILE",
+            SymbolType::Common => "COMMON",
+            SymbolType::Tls => "TLS",
+            SymbolType::GnuIFunc => "IFUNC",
+        };
 
-        vec![ident_bytes, fh_bytes, ph_bytes, sh_bytes]
-            .into_iter()
-            .flatten()
-            .collect()
+        write!(f, "{}", repr)
     }
 }
 
-/// ElfHeaderParser implements a parser for ElfHeader types for each variant
-/// of address width from a source of a given endianness.
-pub struct ElfHeaderParser<A, E>
+impl From<SymbolType> for u8 {
+    fn from(src: SymbolType) -> Self {
+        src as u8
+    }
+}
+
+impl std::convert::TryFrom<u8> for SymbolType {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(SymbolType::NoType),
+            0x01 => Ok(SymbolType::Object),
+            0x02 => Ok(SymbolType::Func),
+            0x03 => Ok(SymbolType::Section),
+            0x04 => Ok(SymbolType::File),
+            0x05 => Ok(SymbolType::Common),
+            0x06 => Ok(SymbolType::Tls),
+            0x0a => Ok(SymbolType::GnuIFunc),
+            _ => Err(format!("cannot convert {} to SymbolType variant", value)),
+        }
+    }
+}
+
+/// The section index of an undefined symbol.
+pub const SHN_UNDEF: u16 = 0x0000;
+/// The lowest section index reserved for special meanings.
+pub const SHN_LORESERVE: u16 = 0xff00;
+/// The section index of a symbol with an absolute value.
+pub const SHN_ABS: u16 = 0xfff1;
+/// The section index of a common symbol not yet allocated.
+pub const SHN_COMMON: u16 = 0xfff2;
+/// Marks an index too large for its 16-bit field, which is instead held
+/// elsewhere, such as in the sh_link of the first section header for
+/// shstrndx.
+pub const SHN_XINDEX: u16 = 0xffff;
+/// Marks a program header count too large for phnum, which is instead held
+/// in the sh_info of the first section header.
+pub const PN_XNUM: u16 = 0xffff;
+
+/// SpecialSectionIndex describes a st_shndx value that doesn't refer to a
+/// section header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpecialSectionIndex {
+    Undefined,
+    Absolute,
+    Common,
+    /// Any other index in the reserved range, such as SHN_XINDEX.
+    Reserved(u16),
+}
+
+/// Represents any kind of symbol table entry, functioning as a way to link the
+/// 32 and 64-bit Symbol types, exposing each field widened to its 64-bit
+/// representation.
+pub trait Symbol {
+    fn st_name(&self) -> u32;
+    fn st_value(&self) -> u64;
+    fn st_size(&self) -> u64;
+    fn st_info(&self) -> u8;
+    fn st_other(&self) -> u8;
+    fn st_shndx(&self) -> u16;
+
+    /// Returns the symbol's binding if it is a known SymbolBinding.
+    fn binding(&self) -> Option<SymbolBinding> {
+        std::convert::TryFrom::try_from(self.st_info() >> 4).ok()
+    }
+
+    /// Returns the symbol's type if it is a known SymbolType.
+    fn symbol_type(&self) -> Option<SymbolType> {
+        std::convert::TryFrom::try_from(self.st_info() & 0x0f).ok()
+    }
+
+    /// Returns the symbol's visibility, as encoded in the low two bits of
+    /// st_other.
+    fn visibility(&self) -> SymbolVisibility {
+        match self.st_other() & 0x03 {
+            0x00 => SymbolVisibility::Default,
+            0x01 => SymbolVisibility::Internal,
+            0x02 => SymbolVisibility::Hidden,
+            _ => SymbolVisibility::Protected,
+        }
+    }
+
+    /// Returns the special meaning of st_shndx, or `None` if it is the index
+    /// of a section header.
+    fn special_section_index(&self) -> Option<SpecialSectionIndex> {
+        match self.st_shndx() {
+            SHN_UNDEF => Some(SpecialSectionIndex::Undefined),
+            SHN_ABS => Some(SpecialSectionIndex::Absolute),
+            SHN_COMMON => Some(SpecialSectionIndex::Common),
+            shndx if shndx >= SHN_LORESERVE => Some(SpecialSectionIndex::Reserved(shndx)),
+            _ => None,
+        }
+    }
+}
+
+/// Symbol32 represents an entry in a 32-bit symbol table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol32 {
+    pub st_name: u32,
+    pub st_value: u32,
+    pub st_size: u32,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+}
+
+impl Symbol for Symbol32 {
+    fn st_name(&self) -> u32 {
+        self.st_name
+    }
+
+    fn st_value(&self) -> u64 {
+        self.st_value.into()
+    }
+
+    fn st_size(&self) -> u64 {
+        self.st_size.into()
+    }
+
+    fn st_info(&self) -> u8 {
+        self.st_info
+    }
+
+    fn st_other(&self) -> u8 {
+        self.st_other
+    }
+
+    fn st_shndx(&self) -> u16 {
+        self.st_shndx
+    }
+}
+
+/// Symbol64 represents an entry in a 64-bit symbol table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol64 {
+    pub st_name: u32,
+    pub st_info: u8,
+    pub st_other: u8,
+    pub st_shndx: u16,
+    pub st_value: u64,
+    pub st_size: u64,
+}
+
+impl Symbol for Symbol64 {
+    fn st_name(&self) -> u32 {
+        self.st_name
+    }
+
+    fn st_value(&self) -> u64 {
+        self.st_value
+    }
+
+    fn st_size(&self) -> u64 {
+        self.st_size
+    }
+
+    fn st_info(&self) -> u8 {
+        self.st_info
+    }
+
+    fn st_other(&self) -> u8 {
+        self.st_other
+    }
+
+    fn st_shndx(&self) -> u16 {
+        self.st_shndx
+    }
+}
+
+/// SymbolSource identifies the symbol table a symbol was read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolSource {
+    /// The full symbol table, `.symtab`.
+    SymTab,
+    /// The dynamic symbol table, `.dynsym`.
+    DynSym,
+}
+
+/// ResolvedSymbol pairs a symbol table entry, widened to its 64-bit
+/// representation, with its name as resolved against the linked string
+/// table. The name borrows from the image, see `into_owned` for a form that
+/// can outlive it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResolvedSymbol<'a> {
+    pub name: Option<&'a str>,
+    pub value: u64,
+    pub size: u64,
+    pub binding: Option<SymbolBinding>,
+    pub symbol_type: Option<SymbolType>,
+    pub visibility: SymbolVisibility,
+    pub shndx: u16,
+    pub source: SymbolSource,
+}
+
+impl<'a> ResolvedSymbol<'a> {
+    fn new<S: Symbol>(symbol: &S, strtab: Option<StrTab<'a>>, source: SymbolSource) -> Self {
+        Self {
+            name: strtab.and_then(|strtab| strtab.get(symbol.st_name() as usize)),
+            value: symbol.st_value(),
+            size: symbol.st_size(),
+            binding: symbol.binding(),
+            symbol_type: symbol.symbol_type(),
+            visibility: symbol.visibility(),
+            shndx: symbol.st_shndx(),
+            source,
+        }
+    }
+
+    /// Copies the symbol's name out of the image.
+    pub fn into_owned(self) -> OwnedResolvedSymbol {
+        OwnedResolvedSymbol::from(self)
+    }
+}
+
+/// OwnedResolvedSymbol is a ResolvedSymbol that owns its name and so
+/// doesn't borrow from the image it was resolved against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OwnedResolvedSymbol {
+    pub name: Option<String>,
+    pub value: u64,
+    pub size: u64,
+    pub binding: Option<SymbolBinding>,
+    pub symbol_type: Option<SymbolType>,
+    pub visibility: SymbolVisibility,
+    pub shndx: u16,
+    pub source: SymbolSource,
+}
+
+impl From<ResolvedSymbol<'_>> for OwnedResolvedSymbol {
+    fn from(src: ResolvedSymbol<'_>) -> Self {
+        Self {
+            name: src.name.map(str::to_owned),
+            value: src.value,
+            size: src.size,
+            binding: src.binding,
+            symbol_type: src.symbol_type,
+            visibility: src.visibility,
+            shndx: src.shndx,
+            source: src.source,
+        }
+    }
+}
+
+/// Drops each symbol sharing its name and value with an earlier one.
+fn unique_symbols(symbols: Vec<ResolvedSymbol<'_>>) -> Vec<ResolvedSymbol<'_>> {
+    let mut seen = std::collections::HashSet::new();
+    symbols
+        .into_iter()
+        .filter(|symbol| seen.insert((symbol.name, symbol.value)))
+        .collect()
+}
+
+/// SymbolParser implements a parser for symbol table entries of a given
+/// address width and endianness.
+pub struct SymbolParser<A, E>
 where
     A: AddressWidth,
     E: DataEncoding,
@@ -2082,7 +3497,7 @@ where
     endianness: std::marker::PhantomData<E>,
 }
 
-impl<A, E> ElfHeaderParser<A, E>
+impl<A, E> SymbolParser<A, E>
 where
     A: AddressWidth,
     E: DataEncoding,
@@ -2092,7 +3507,7 @@ where
     }
 }
 
-impl<A, E> Default for ElfHeaderParser<A, E>
+impl<A, E> Default for SymbolParser<A, E>
 where
     A: AddressWidth,
     E: DataEncoding,
@@ -2105,294 +3520,6596 @@ where
     }
 }
 
-impl<'a, E> parcel::Parser<'a, &'a [u8], ElfHeader32<E>> for ElfHeaderParser<ElfAddr32, E>
+impl<'a, E> parcel::Parser<'a, &'a [u8], Symbol32> for SymbolParser<ElfAddr32, E>
 where
+    EiData: From<E>,
     E: DataEncoding + Default + 'static,
-    FileHeaderParser<ElfAddr32, E>: Parser<'a, &'a [u8], FileHeader<ElfAddr32>>,
-    ProgramHeaderParser<ElfAddr32, E>: Parser<'a, &'a [u8], ProgramHeader32>,
-    SectionHeaderParser<ElfAddr32, E>: Parser<'a, &'a [u8], SectionHeader32>,
 {
-    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], ElfHeader32<E>> {
-        let ms = match EiIdentParser.parse(&input)? {
-            MatchStatus::Match((_, ei)) => FileHeaderParser::<ElfAddr32, E>::new()
-                .and_then(move |fh| {
-                    let phnum = fh.phnum as usize;
-                    ProgramHeaderParser::<ElfAddr32, E>::new()
-                        .take_n(phnum)
-                        .map(move |phs| (ei, fh, phs))
-                })
-                .parse(&input[0..]),
-            MatchStatus::NoMatch(rem) => Ok(MatchStatus::NoMatch(rem)),
-        }?;
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], Symbol32> {
+        use parcel::parsers::byte::any_byte;
+        let encoding = EiData::from(E::default());
 
-        match ms {
-            MatchStatus::Match((_, (ei, fh, phs))) => {
-                let shnum = fh.shnum as usize;
-                let sh_offset = fh.sh_offset as usize;
-                SectionHeaderParser::<ElfAddr32, E>::new()
-                    .take_n(shnum)
-                    .map(move |shs| (fh, phs.to_owned(), shs))
-                    .map(move |(fh, phs, shs)| ElfHeader32::new(ei, fh, phs, shs))
-                    .parse(&input[sh_offset..])
-            }
-            MatchStatus::NoMatch(rem) => Ok(MatchStatus::NoMatch(rem)),
-        }
+        parcel::join(
+            parcel::take_n(match_u32(encoding), 3),
+            parcel::join(any_byte(), parcel::join(any_byte(), match_u16(encoding))),
+        )
+        .map(|(u32_seq, (st_info, (st_other, st_shndx)))| Symbol32 {
+            st_name: u32_seq[0],
+            st_value: u32_seq[1],
+            st_size: u32_seq[2],
+            st_info,
+            st_other,
+            st_shndx,
+        })
+        .parse(input)
     }
 }
 
-impl<'a, E> parcel::Parser<'a, &'a [u8], ElfHeader64<E>> for ElfHeaderParser<ElfAddr64, E>
+impl<'a, E> parcel::Parser<'a, &'a [u8], Symbol64> for SymbolParser<ElfAddr64, E>
 where
+    EiData: From<E>,
     E: DataEncoding + Default + 'static,
-    FileHeaderParser<ElfAddr64, E>: Parser<'a, &'a [u8], FileHeader<ElfAddr64>>,
-    ProgramHeaderParser<ElfAddr64, E>: Parser<'a, &'a [u8], ProgramHeader64>,
-    SectionHeaderParser<ElfAddr64, E>: Parser<'a, &'a [u8], SectionHeader64>,
 {
-    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], ElfHeader64<E>> {
-        let ms = match EiIdentParser.parse(&input)? {
-            MatchStatus::Match((_, ei)) => FileHeaderParser::<ElfAddr64, E>::new()
-                .and_then(move |fh| {
-                    let phnum = fh.phnum as usize;
-                    ProgramHeaderParser::<ElfAddr64, E>::new()
-                        .take_n(phnum)
-                        .map(move |phs| (ei, fh, phs))
-                })
-                .parse(&input[0..]),
-            MatchStatus::NoMatch(rem) => Ok(MatchStatus::NoMatch(rem)),
-        }?;
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], Symbol64> {
+        use parcel::parsers::byte::any_byte;
+        let encoding = EiData::from(E::default());
 
-        match ms {
-            MatchStatus::Match((_, (ei, fh, phs))) => {
-                let shnum = fh.shnum as usize;
-                let sh_offset = fh.sh_offset as usize;
-                SectionHeaderParser::<ElfAddr64, E>::new()
-                    .take_n(shnum)
-                    .map(move |shs| (fh, phs.to_owned(), shs))
-                    .map(move |(fh, phs, shs)| ElfHeader64::new(ei, fh, phs, shs))
-                    .parse(&input[sh_offset..])
-            }
-            MatchStatus::NoMatch(rem) => Ok(MatchStatus::NoMatch(rem)),
-        }
+        parcel::join(
+            match_u32(encoding),
+            parcel::join(
+                any_byte(),
+                parcel::join(
+                    any_byte(),
+                    parcel::join(match_u16(encoding), parcel::take_n(match_u64(encoding), 2)),
+                ),
+            ),
+        )
+        .map(
+            |(st_name, (st_info, (st_other, (st_shndx, u64_seq))))| Symbol64 {
+                st_name,
+                st_info,
+                st_other,
+                st_shndx,
+                st_value: u64_seq[0],
+                st_size: u64_seq[1],
+            },
+        )
+        .parse(input)
     }
 }
 
-/// Matches a single provided static byte array, returning a match if the next
-/// bytes in the array match the expected byte array. Otherwise, a `NoMatch` is
-/// returned.
-fn expect_bytes<'a>(expected: &'static [u8]) -> impl Parser<'a, &'a [u8], Vec<u8>> {
-    move |input: &'a [u8]| {
-        let preparse_input = input;
-        let expected_len = expected.len();
-        let next: Vec<u8> = input.iter().take(expected_len).copied().collect();
-        if next == expected {
-            Ok(MatchStatus::Match((&input[expected_len..], next)))
-        } else {
-            Ok(MatchStatus::NoMatch(preparse_input))
-        }
-    }
+/// DynamicTag represents the known d_tag values of a dynamic section entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i64)]
+pub enum DynamicTag {
+    Null = 0x00,
+    Needed = 0x01,
+    PltRelSz = 0x02,
+    PltGot = 0x03,
+    Hash = 0x04,
+    StrTab = 0x05,
+    SymTab = 0x06,
+    Rela = 0x07,
+    RelaSz = 0x08,
+    RelaEnt = 0x09,
+    StrSz = 0x0a,
+    SymEnt = 0x0b,
+    Init = 0x0c,
+    Fini = 0x0d,
+    SoName = 0x0e,
+    RPath = 0x0f,
+    Symbolic = 0x10,
+    Rel = 0x11,
+    RelSz = 0x12,
+    RelEnt = 0x13,
+    PltRel = 0x14,
+    Debug = 0x15,
+    TextRel = 0x16,
+    JmpRel = 0x17,
+    BindNow = 0x18,
+    InitArray = 0x19,
+    FiniArray = 0x1a,
+    InitArraySz = 0x1b,
+    FiniArraySz = 0x1c,
+    RunPath = 0x1d,
+    Flags = 0x1e,
+    GnuHash = 0x6ffffef5,
+    VerSym = 0x6ffffff0,
+    Flags1 = 0x6ffffffb,
+    VerDef = 0x6ffffffc,
+    VerDefNum = 0x6ffffffd,
+    VerNeed = 0x6ffffffe,
+    VerNeedNum = 0x6fffffff,
 }
 
-/// Matches a single provided static u16, returning a match if the next
-/// two bytes in the array match the expected u16. Otherwise, a `NoMatch` is
-/// returned.
-fn expect_u16<'a>(endianness: EiData, expected: u16) -> impl Parser<'a, &'a [u8], u16> {
-    move |input: &'a [u8]| {
-        let preparse_input = input;
-        match match_u16(endianness).parse(input) {
-            Ok(MatchStatus::Match((rem, v))) if v == expected => {
-                Ok(MatchStatus::Match((rem, expected)))
-            }
-            _ => Ok(MatchStatus::NoMatch(preparse_input)),
-        }
+impl std::fmt::Display for DynamicTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            DynamicTag::Null => "NULL",
+            DynamicTag::Needed => "NEEDED",
+            DynamicTag::PltRelSz => "PLTRELSZ",
+            DynamicTag::PltGot => "PLTGOT",
+            DynamicTag::Hash => "HASH",
+            DynamicTag::StrTab => "STRTAB",
+            DynamicTag::SymTab => "SYMTAB",
+            DynamicTag::Rela => "RELA",
+            DynamicTag::RelaSz => "RELASZ",
+            DynamicTag::RelaEnt => "RELAENT",
+            DynamicTag::StrSz => "STRSZ",
+            DynamicTag::SymEnt => "SYMENT",
+            DynamicTag::Init => "INIT",
+            DynamicTag::Fini => "FINI",
+            DynamicTag::SoName => "SONAME",
+            DynamicTag::RPath => "RPATH",
+            DynamicTag::Symbolic => "SYMBOLIC",
+            DynamicTag::Rel => "REL",
+            DynamicTag::RelSz => "RELSZ",
+            DynamicTag::RelEnt => "RELENT",
+            DynamicTag::PltRel => "PLTREL",
+            DynamicTag::Debug => "DEBUG",
+            DynamicTag::TextRel => "TEXTREL",
+            DynamicTag::JmpRel => "JMPREL",
+            DynamicTag::BindNow => "BIND_NOW",
+            DynamicTag::InitArray => "INIT_ARRAY",
+            DynamicTag::FiniArray => "FINI_ARRAY",
+            DynamicTag::InitArraySz => "INIT_ARRAYSZ",
+            DynamicTag::FiniArraySz => "FINI_ARRAYSZ",
+            DynamicTag::RunPath => "RUNPATH",
+            DynamicTag::Flags => "FLAGS",
+            DynamicTag::GnuHash => "GNU_HASH",
+            DynamicTag::VerSym => "VERSYM",
+            DynamicTag::Flags1 => "FLAGS_1",
+            DynamicTag::VerDef => "VERDEF",
+            DynamicTag::VerDefNum => "VERDEFNUM",
+            DynamicTag::VerNeed => "VERNEED",
+            DynamicTag::VerNeedNum => "VERNEEDNUM",
+        };
+
+        write!(f, "{}", repr)
     }
 }
 
-/// Matches a single provided static u32, returning a match if the next
-/// four bytes in the array match the expected u32. Otherwise, a `NoMatch` is
-/// returned.
-fn expect_u32<'a>(endianness: EiData, expected: u32) -> impl Parser<'a, &'a [u8], u32> {
-    move |input: &'a [u8]| {
-        let preparse_input = input;
-        match match_u32(endianness).parse(input) {
-            Ok(MatchStatus::Match((rem, v))) if v == expected => {
-                Ok(MatchStatus::Match((rem, expected)))
-            }
-            _ => Ok(MatchStatus::NoMatch(preparse_input)),
-        }
+impl From<DynamicTag> for i64 {
+    fn from(src: DynamicTag) -> Self {
+        src as i64
     }
 }
 
-/// Matches a single provided static u64, returning a match if the next
-/// eight bytes in the array match the expected u64. Otherwise, a `NoMatch` is
-/// returned.
-fn expect_u64<'a>(endianness: EiData, expected: u64) -> impl Parser<'a, &'a [u8], u64> {
-    move |input: &'a [u8]| {
-        let preparse_input = input;
-        match match_u64(endianness).parse(input) {
-            Ok(MatchStatus::Match((rem, v))) if v == expected => {
-                Ok(MatchStatus::Match((rem, expected)))
-            }
-            _ => Ok(MatchStatus::NoMatch(preparse_input)),
+impl std::convert::TryFrom<i64> for DynamicTag {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(DynamicTag::Null),
+            0x01 => Ok(DynamicTag::Needed),
+            0x02 => Ok(DynamicTag::PltRelSz),
+            0x03 => Ok(DynamicTag::PltGot),
+            0x04 => Ok(DynamicTag::Hash),
+            0x05 => Ok(DynamicTag::StrTab),
+            0x06 => Ok(DynamicTag::SymTab),
+            0x07 => Ok(DynamicTag::Rela),
+            0x08 => Ok(DynamicTag::RelaSz),
+            0x09 => Ok(DynamicTag::RelaEnt),
+            0x0a => Ok(DynamicTag::StrSz),
+            0x0b => Ok(DynamicTag::SymEnt),
+            0x0c => Ok(DynamicTag::Init),
+            0x0d => Ok(DynamicTag::Fini),
+            0x0e => Ok(DynamicTag::SoName),
+            0x0f => Ok(DynamicTag::RPath),
+            0x10 => Ok(DynamicTag::Symbolic),
+            0x11 => Ok(DynamicTag::Rel),
+            0x12 => Ok(DynamicTag::RelSz),
+            0x13 => Ok(DynamicTag::RelEnt),
+            0x14 => Ok(DynamicTag::PltRel),
+            0x15 => Ok(DynamicTag::Debug),
+            0x16 => Ok(DynamicTag::TextRel),
+            0x17 => Ok(DynamicTag::JmpRel),
+            0x18 => Ok(DynamicTag::BindNow),
+            0x19 => Ok(DynamicTag::InitArray),
+            0x1a => Ok(DynamicTag::FiniArray),
+            0x1b => Ok(DynamicTag::InitArraySz),
+            0x1c => Ok(DynamicTag::FiniArraySz),
+            0x1d => Ok(DynamicTag::RunPath),
+            0x1e => Ok(DynamicTag::Flags),
+            0x6ffffef5 => Ok(DynamicTag::GnuHash),
+            0x6ffffff0 => Ok(DynamicTag::VerSym),
+            0x6ffffffb => Ok(DynamicTag::Flags1),
+            0x6ffffffc => Ok(DynamicTag::VerDef),
+            0x6ffffffd => Ok(DynamicTag::VerDefNum),
+            0x6ffffffe => Ok(DynamicTag::VerNeed),
+            0x6fffffff => Ok(DynamicTag::VerNeedNum),
+            _ => Err(format!("cannot convert {} to DynamicTag variant", value)),
         }
     }
 }
 
-/// Matches any given u16 by endianness returning a corresponding u16 value.
-fn match_u16<'a>(endianness: EiData) -> impl Parser<'a, &'a [u8], u16> {
-    use parcel::parsers::byte::any_byte;
-    use std::convert::TryInto;
+/// Represents any kind of dynamic section entry, functioning as a way to link
+/// the 32 and 64-bit DynamicEntry types.
+pub trait DynamicEntry {
+    fn d_tag(&self) -> i64;
+    fn d_val(&self) -> u64;
 
-    parcel::take_n(any_byte(), 2).map(move |b| {
-        b.try_into()
-            .map(|ep| match endianness {
-                EiData::Little => u16::from_le_bytes(ep),
-                EiData::Big => u16::from_be_bytes(ep),
-            })
-            .unwrap()
-    })
+    /// Returns the entry's tag if it is a known DynamicTag.
+    fn tag(&self) -> Option<DynamicTag> {
+        std::convert::TryFrom::try_from(self.d_tag()).ok()
+    }
 }
 
-/// Matches any given u32 by endianness returning a corresponding u32 value.
-fn match_u32<'a>(endianness: EiData) -> impl Parser<'a, &'a [u8], u32> {
-    use parcel::parsers::byte::any_byte;
-    use std::convert::TryInto;
-
-    parcel::take_n(any_byte(), 4).map(move |b| {
-        b.try_into()
-            .map(|ep| match endianness {
-                EiData::Little => u32::from_le_bytes(ep),
-                EiData::Big => u32::from_be_bytes(ep),
-            })
-            .unwrap()
-    })
+/// DynamicEntry32 represents an entry in a 32-bit dynamic section. d_val
+/// holds either the d_val or d_ptr member of the d_un union.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynamicEntry32 {
+    pub d_tag: i32,
+    pub d_val: u32,
 }
 
-/// Matches any given u64 by endianness returning a corresponding u64 value.
-fn match_u64<'a>(endianness: EiData) -> impl Parser<'a, &'a [u8], u64> {
-    use parcel::parsers::byte::any_byte;
-    use std::convert::TryInto;
+impl DynamicEntry for DynamicEntry32 {
+    fn d_tag(&self) -> i64 {
+        self.d_tag.into()
+    }
 
-    parcel::take_n(any_byte(), 8).map(move |b| {
-        b.try_into()
-            .map(|ep| match endianness {
-                EiData::Little => u64::from_le_bytes(ep),
-                EiData::Big => u64::from_be_bytes(ep),
-            })
-            .unwrap()
-    })
+    fn d_val(&self) -> u64 {
+        self.d_val.into()
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// DynamicEntry64 represents an entry in a 64-bit dynamic section. d_val
+/// holds either the d_val or d_ptr member of the d_un union.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynamicEntry64 {
+    pub d_tag: i64,
+    pub d_val: u64,
+}
 
-    macro_rules! generate_file_header {
-        () => {
-            vec![
-                // File Header
-                0x7f, 0x45, 0x4c, 0x46, // magic
-                0x01, // ei_class
-                0x01, // ei_data
-                0x01, // ei_version
-                0x00, // ei_osabi
-                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // padding
-                0x00, 0x00, // type
-                0x03, 0x00, // machine
-                0x01, 0x00, 0x00, 0x00, //version
-                0x05, 0x00, 0x00, 0x00, // entry
-                0x0A, 0x00, 0x00, 0x00, // phoff
-                0x0B, 0x00, 0x00, 0x00, // shoff
-                0x02, 0x00, 0x00, 0x00, // flags
-                0x00, 0x00, // eh_size
-                0x01, 0x00, // phentsize
-                0x01, 0x00, // phnum
-                0x01, 0x00, // shentsize
-                0x01, 0x00, // shnum
-                0x01, 0x00, // shstrndx
-            ]
-        };
+impl DynamicEntry for DynamicEntry64 {
+    fn d_tag(&self) -> i64 {
+        self.d_tag
     }
 
-    macro_rules! generate_program_header {
-        () => {
-            vec![
-                // Program Header
-                0x00, 0x00, 0x00, 0x00, // p_type
-                0x00, 0x00, 0x00, 0x00, // p_offset
-                0x00, 0x00, 0x00, 0x00, // p_vaddr
-                0x00, 0x00, 0x00, 0x00, // p_paddr
-                0x00, 0x00, 0x00, 0x00, // p_filesz
-                0x00, 0x00, 0x00, 0x00, // p_memsz
-                0x00, 0x00, 0x00, 0x00, // p_flags
-                0x00, 0x00, 0x00, 0x00, // p_align
-            ]
-        };
+    fn d_val(&self) -> u64 {
+        self.d_val
     }
+}
 
-    #[test]
-    fn parse_preamble_should_return_expected_results() {
-        let thirty_two_bit_input = [
-            0x7f, 0x45, 0x4c, 0x46, 0x01, 0x01, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00,
-        ];
-        let sixty_four_bit_input = [
-            0x7f, 0x45, 0x4c, 0x46, 0x02, 0x01, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
-            0x00, 0x00,
-        ];
-        let invalid_input = [0xff, 0xff, 0xff, 0xff, 0xff];
+/// DynamicEntryParser implements a parser for dynamic section entries of a
+/// given address width and endianness.
+pub struct DynamicEntryParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    address_width: std::marker::PhantomData<A>,
+    endianness: std::marker::PhantomData<E>,
+}
 
-        assert_eq!(
-            Ok(EiClass::ThirtyTwoBit),
-            EiIdentParser
-                .parse(&thirty_two_bit_input)
-                .map(|ms| ms.unwrap().ei_class)
-        );
-        assert_eq!(
-            Ok(EiClass::SixtyFourBit),
-            EiIdentParser
-                .parse(&sixty_four_bit_input)
-                .map(|ms| ms.unwrap().ei_class)
-        );
-        assert!(EiIdentParser
-            .parse(&invalid_input)
-            .and_then(|ms| match ms {
-                MatchStatus::Match(_) => Err("invalid input shouldn't match".to_string()),
-                MatchStatus::NoMatch(_) => Ok(()),
-            })
-            .is_ok());
+impl<A, E> DynamicEntryParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    pub fn new() -> Self {
+        Self::default()
     }
+}
 
-    #[test]
-    fn parse_known_good_file_header() {
-        let input: Vec<u8> = generate_file_header!();
-
-        assert_eq!(
-            FileHeader::<ElfAddr32> {
-                r#type: Type::None,
-                machine: Machine::X386,
-                version: Version::One,
-                entry_point: 5u32,
-                ph_offset: 10u32,
-                sh_offset: 11u32,
-                flags: 2,
-                eh_size: 0,
-                phent_size: 1,
-                phnum: 1,
-                shent_size: 1,
-                shnum: 1,
-                shstrndx: 1
-            },
-            FileHeaderParser::<ElfAddr32, LittleEndian>::new()
-                .parse(&input)
-                .unwrap()
-                .unwrap(),
-        )
+impl<A, E> Default for DynamicEntryParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    fn default() -> Self {
+        Self {
+            address_width: std::marker::PhantomData,
+            endianness: std::marker::PhantomData,
+        }
     }
+}
 
-    #[test]
-    fn parse_known_good_program_header() {
-        let input: Vec<u8> = generate_program_header!();
-
-        assert_eq!(
+impl<'a, E> parcel::Parser<'a, &'a [u8], DynamicEntry32> for DynamicEntryParser<ElfAddr32, E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], DynamicEntry32> {
+        let encoding = EiData::from(E::default());
+
+        parcel::join(match_i32(encoding), match_u32(encoding))
+            .map(|(d_tag, d_val)| DynamicEntry32 { d_tag, d_val })
+            .parse(input)
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], DynamicEntry64> for DynamicEntryParser<ElfAddr64, E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], DynamicEntry64> {
+        let encoding = EiData::from(E::default());
+
+        parcel::join(match_i64(encoding), match_u64(encoding))
+            .map(|(d_tag, d_val)| DynamicEntry64 { d_tag, d_val })
+            .parse(input)
+    }
+}
+
+/// DynamicIter yields the entries of a dynamic section up to, but not
+/// including, the terminating DT_NULL entry.
+#[derive(Debug, Clone)]
+pub struct DynamicIter<D> {
+    entries: std::vec::IntoIter<D>,
+}
+
+impl<D> DynamicIter<D> {
+    fn new(entries: Vec<D>) -> Self {
+        Self {
+            entries: entries.into_iter(),
+        }
+    }
+}
+
+impl<D: DynamicEntry> Iterator for DynamicIter<D> {
+    type Item = D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.entries.next() {
+            Some(entry) if entry.tag() != Some(DynamicTag::Null) => Some(entry),
+            _ => {
+                // discard anything following DT_NULL so the iterator stays
+                // exhausted.
+                self.entries = Vec::new().into_iter();
+                None
+            }
+        }
+    }
+}
+
+/// The note type of the ABI tag recorded by GNU toolchains.
+pub const NT_GNU_ABI_TAG: u32 = 1;
+/// The note type of the unique build ID recorded by GNU toolchains.
+pub const NT_GNU_BUILD_ID: u32 = 3;
+/// The note type of the auxiliary vector recorded in a core file.
+pub const NT_AUXV: u32 = 6;
+
+/// Note represents a single entry of a note segment or section.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Note<'a> {
+    /// The note's owner, such as `GNU`, without its terminating NUL.
+    pub name: &'a [u8],
+    pub n_type: u32,
+    pub desc: &'a [u8],
+}
+
+/// AuxType represents the commonly used a_type keys of an auxiliary vector
+/// entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u64)]
+pub enum AuxType {
+    Null = 0x00,
+    Phdr = 0x03,
+    Phent = 0x04,
+    Phnum = 0x05,
+    Pagesz = 0x06,
+    Base = 0x07,
+    Entry = 0x09,
+    Random = 0x19,
+}
+
+impl From<AuxType> for u64 {
+    fn from(src: AuxType) -> Self {
+        src as u64
+    }
+}
+
+impl std::convert::TryFrom<u64> for AuxType {
+    type Error = String;
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0x00 => Ok(AuxType::Null),
+            0x03 => Ok(AuxType::Phdr),
+            0x04 => Ok(AuxType::Phent),
+            0x05 => Ok(AuxType::Phnum),
+            0x06 => Ok(AuxType::Pagesz),
+            0x07 => Ok(AuxType::Base),
+            0x09 => Ok(AuxType::Entry),
+            0x19 => Ok(AuxType::Random),
+            _ => Err(format!("cannot convert {} to AuxType variant", value)),
+        }
+    }
+}
+
+/// AuxvEntry represents a key/value pair of a process's auxiliary vector.
+/// The fields of 32-bit entries are widened to 64 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuxvEntry {
+    pub a_type: u64,
+    pub a_val: u64,
+}
+
+impl AuxvEntry {
+    /// Returns the entry's key if it is a known AuxType.
+    pub fn aux_type(&self) -> Option<AuxType> {
+        std::convert::TryFrom::try_from(self.a_type).ok()
+    }
+}
+
+/// AuxvEntryParser implements a parser for the auxiliary vector entries held
+/// in an NT_AUXV note descriptor of a given address width and endianness.
+pub struct AuxvEntryParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    address_width: std::marker::PhantomData<A>,
+    endianness: std::marker::PhantomData<E>,
+}
+
+impl<A, E> AuxvEntryParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<A, E> Default for AuxvEntryParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    fn default() -> Self {
+        Self {
+            address_width: std::marker::PhantomData,
+            endianness: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], AuxvEntry> for AuxvEntryParser<ElfAddr32, E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], AuxvEntry> {
+        let encoding = EiData::from(E::default());
+
+        parcel::join(match_u32(encoding), match_u32(encoding))
+            .map(|(a_type, a_val)| AuxvEntry {
+                a_type: a_type.into(),
+                a_val: a_val.into(),
+            })
+            .parse(input)
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], AuxvEntry> for AuxvEntryParser<ElfAddr64, E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], AuxvEntry> {
+        let encoding = EiData::from(E::default());
+
+        parcel::join(match_u64(encoding), match_u64(encoding))
+            .map(|(a_type, a_val)| AuxvEntry { a_type, a_val })
+            .parse(input)
+    }
+}
+
+/// Parses each note in the contents of a note segment or section. Names and
+/// descriptors are padded to 4 bytes, and parsing stops at the first
+/// truncated entry.
+pub fn parse_notes(encoding: EiData, mut data: &[u8]) -> Vec<Note<'_>> {
+    let padded = |size: u32| (size as usize + 3) & !3;
+    let mut notes = vec![];
+
+    while let Some((rem, (namesz, (descsz, note_type)))) = matched(
+        parcel::join(
+            match_u32(encoding),
+            parcel::join(match_u32(encoding), match_u32(encoding)),
+        ),
+        data,
+    ) {
+        let desc_start = padded(namesz);
+        let (name, desc) = match (
+            rem.get(..namesz as usize),
+            rem.get(desc_start..desc_start + descsz as usize),
+        ) {
+            (Some(name), Some(desc)) => (name, desc),
+            _ => break,
+        };
+        notes.push(Note {
+            name: name.strip_suffix(&[0x00]).unwrap_or(name),
+            n_type: note_type,
+            desc,
+        });
+        data = rem.get(desc_start + padded(descsz)..).unwrap_or(&[]);
+    }
+
+    notes
+}
+
+/// Parses the notes of every SHT_NOTE section or, for files without note
+/// sections, every PT_NOTE segment, as the two typically describe the same
+/// data. Sections and segments larger than `max_note_size` are skipped.
+fn notes<'a, P, S>(
+    encoding: EiData,
+    program_headers: &[P],
+    section_headers: &[S],
+    max_note_size: u64,
+    image: &'a [u8],
+) -> Vec<Note<'a>>
+where
+    P: ProgramHeader,
+    S: SectionHeader,
+{
+    let sections: Vec<&[u8]> = section_headers
+        .iter()
+        .filter(|sh| sh.sh_type() == ShType::Note && sh.sh_size() <= max_note_size)
+        .filter_map(|sh| sh.data(image))
+        .collect();
+    let data = if sections.is_empty() {
+        program_headers
+            .iter()
+            .filter(|ph| ph.p_type() == ProgramHeaderType::Note && ph.p_filesz() <= max_note_size)
+            .filter_map(|ph| ph.data(image))
+            .collect()
+    } else {
+        sections
+    };
+
+    data.into_iter()
+        .flat_map(|data| parse_notes(encoding, data))
+        .collect()
+}
+
+/// Parses the auxiliary vector held in the first NT_AUXV note of a file's
+/// PT_NOTE segments, stopping at the terminating AT_NULL entry.
+fn auxv<'a, P, Q>(
+    encoding: EiData,
+    program_headers: &[P],
+    parser: Q,
+    image: &'a [u8],
+) -> Vec<AuxvEntry>
+where
+    P: ProgramHeader,
+    Q: Parser<'a, &'a [u8], AuxvEntry>,
+{
+    let mut input = match program_headers
+        .iter()
+        .filter(|ph| ph.p_type() == ProgramHeaderType::Note)
+        .filter_map(|ph| ph.data(image))
+        .flat_map(|data| parse_notes(encoding, data))
+        .find(|note| note.n_type == NT_AUXV)
+        .map(|note| note.desc)
+    {
+        Some(descriptor) => descriptor,
+        None => return vec![],
+    };
+
+    let mut entries = vec![];
+    while let Ok(MatchStatus::Match((rem, entry))) = parser.parse(input) {
+        if entry.aux_type() == Some(AuxType::Null) {
+            break;
+        }
+        entries.push(entry);
+        input = rem;
+    }
+
+    entries
+}
+
+/// Represents any kind of relocation entry with an explicit addend,
+/// functioning as a way to link the 32 and 64-bit Rela types.
+pub trait Relocation {
+    fn r_offset(&self) -> u64;
+    fn r_info(&self) -> u64;
+    fn r_addend(&self) -> i64;
+}
+
+/// Rela32 represents a 32-bit SHT_RELA relocation entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rela32 {
+    pub r_offset: u32,
+    pub r_info: u32,
+    pub r_addend: i32,
+}
+
+impl Relocation for Rela32 {
+    fn r_offset(&self) -> u64 {
+        self.r_offset.into()
+    }
+
+    fn r_info(&self) -> u64 {
+        self.r_info.into()
+    }
+
+    fn r_addend(&self) -> i64 {
+        self.r_addend.into()
+    }
+}
+
+/// Rela64 represents a 64-bit SHT_RELA relocation entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rela64 {
+    pub r_offset: u64,
+    pub r_info: u64,
+    pub r_addend: i64,
+}
+
+impl Relocation for Rela64 {
+    fn r_offset(&self) -> u64 {
+        self.r_offset
+    }
+
+    fn r_info(&self) -> u64 {
+        self.r_info
+    }
+
+    fn r_addend(&self) -> i64 {
+        self.r_addend
+    }
+}
+
+/// RelocationInfo holds the symbol index and relocation type decoded from an
+/// r_info field. MIPS64 entries carry up to three relocation types and a
+/// special symbol, these fields are zero for all other machines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RelocationInfo {
+    pub sym: u32,
+    pub r_type: u32,
+    pub r_type2: u8,
+    pub r_type3: u8,
+    pub r_ssym: u8,
+}
+
+impl RelocationInfo {
+    /// Decodes a 32-bit r_info, holding the symbol in the upper 24 bits and
+    /// the type in the low 8.
+    fn from_info32(r_info: u32) -> Self {
+        Self {
+            sym: r_info >> 8,
+            r_type: r_info & 0xff,
+            r_type2: 0,
+            r_type3: 0,
+            r_ssym: 0,
+        }
+    }
+
+    /// Decodes a 64-bit r_info, holding the symbol in the upper 32 bits and
+    /// the type in the lower 32.
+    fn from_info64(r_info: u64) -> Self {
+        Self {
+            sym: (r_info >> 32) as u32,
+            r_type: r_info as u32,
+            r_type2: 0,
+            r_type3: 0,
+            r_ssym: 0,
+        }
+    }
+
+    /// Decodes a MIPS64 r_info, which is a 32-bit symbol index followed by
+    /// the r_ssym, r_type3, r_type2 and r_type bytes rather than a single
+    /// 64-bit value. Read in the file's byte order, big-endian entries match
+    /// this layout directly while little-endian entries must be reordered.
+    fn from_mips64_info(encoding: EiData, r_info: u64) -> Self {
+        let r_info = match encoding {
+            EiData::Big => r_info,
+            EiData::Little => {
+                ((r_info & 0xffff_ffff) << 32)
+                    | ((r_info >> 56) & 0xff)
+                    | ((r_info >> 40) & 0xff00)
+                    | ((r_info >> 24) & 0x00ff_0000)
+                    | ((r_info >> 8) & 0xff00_0000)
+            }
+        };
+
+        Self {
+            sym: (r_info >> 32) as u32,
+            r_type: (r_info & 0xff) as u32,
+            r_type2: (r_info >> 8) as u8,
+            r_type3: (r_info >> 16) as u8,
+            r_ssym: (r_info >> 24) as u8,
+        }
+    }
+}
+
+/// PltEntry pairs the address of a PLT stub with the name of the symbol its
+/// `.rela.plt` relocation binds it to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PltEntry<'a> {
+    pub address: u64,
+    pub symbol: Option<&'a str>,
+}
+
+/// Enumerates the stubs of a PLT section, skipping `reserved` leading
+/// entries such as the lazy binding resolver of a classic `.plt`. Each stub
+/// is paired with the symbol of the `.rela.plt` relocation at the same
+/// index, or, without relocations, as many stubs as fit the section are
+/// returned without symbols. Stubs are only counted within the section's
+/// data in `image`. An sh_entsize of 0 is taken to be the 16-byte x86-64
+/// entry size. Enumeration stops at the first stub whose address overflows.
+fn plt_entries<'a, S>(
+    plt: &S,
+    reserved: u64,
+    symbols: Option<Vec<Option<&'a str>>>,
+    image: &[u8],
+) -> Vec<PltEntry<'a>>
+where
+    S: SectionHeader,
+{
+    let entsize = match plt.sh_entsize() {
+        0 => 16,
+        entsize => entsize,
+    };
+    let stubs = plt
+        .data(image)
+        .map_or(0, |data| data.len() as u64 / entsize)
+        .saturating_sub(reserved);
+    let count = match &symbols {
+        Some(symbols) => stubs.min(symbols.len() as u64),
+        None => stubs,
+    };
+
+    (0..count)
+        .map_while(|idx| {
+            let address = reserved
+                .checked_add(idx)?
+                .checked_mul(entsize)?
+                .checked_add(plt.sh_addr())?;
+            let symbol = symbols
+                .as_ref()
+                .and_then(|symbols| symbols.get(idx as usize).copied().flatten());
+            Some(PltEntry { address, symbol })
+        })
+        .collect()
+}
+
+/// RelaParser implements a parser for SHT_RELA relocation entries of a given
+/// address width and endianness.
+pub struct RelaParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    address_width: std::marker::PhantomData<A>,
+    endianness: std::marker::PhantomData<E>,
+}
+
+impl<A, E> RelaParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<A, E> Default for RelaParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    fn default() -> Self {
+        Self {
+            address_width: std::marker::PhantomData,
+            endianness: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], Rela32> for RelaParser<ElfAddr32, E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], Rela32> {
+        let encoding = EiData::from(E::default());
+
+        parcel::join(
+            match_u32(encoding),
+            parcel::join(match_u32(encoding), match_i32(encoding)),
+        )
+        .map(|(r_offset, (r_info, r_addend))| Rela32 {
+            r_offset,
+            r_info,
+            r_addend,
+        })
+        .parse(input)
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], Rela64> for RelaParser<ElfAddr64, E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], Rela64> {
+        let encoding = EiData::from(E::default());
+
+        parcel::join(
+            match_u64(encoding),
+            parcel::join(match_u64(encoding), match_i64(encoding)),
+        )
+        .map(|(r_offset, (r_info, r_addend))| Rela64 {
+            r_offset,
+            r_info,
+            r_addend,
+        })
+        .parse(input)
+    }
+}
+
+/// The object may reference $ORIGIN.
+pub const DF_ORIGIN: u64 = 0x01;
+/// Symbol resolution starts from the object itself.
+pub const DF_SYMBOLIC: u64 = 0x02;
+/// Relocations may modify a non-writable segment.
+pub const DF_TEXTREL: u64 = 0x04;
+/// All relocations must be processed before control is transferred to the
+/// program.
+pub const DF_BIND_NOW: u64 = 0x08;
+/// The object uses the static thread-local storage model.
+pub const DF_STATIC_TLS: u64 = 0x10;
+
+/// DF_1_NOW is the DT_FLAGS_1 equivalent of DF_BIND_NOW.
+pub const DF_1_NOW: u64 = 0x01;
+/// The object's symbols are made available to all subsequently loaded
+/// objects.
+pub const DF_1_GLOBAL: u64 = 0x02;
+/// The object and its dependencies are resolved as a group.
+pub const DF_1_GROUP: u64 = 0x04;
+/// The object can't be unloaded.
+pub const DF_1_NODELETE: u64 = 0x08;
+/// The object's filtees are loaded immediately.
+pub const DF_1_LOADFLTR: u64 = 0x10;
+/// The object is initialized before any other object.
+pub const DF_1_INITFIRST: u64 = 0x20;
+/// The object can't be loaded with dlopen.
+pub const DF_1_NOOPEN: u64 = 0x40;
+/// The object may reference $ORIGIN.
+pub const DF_1_ORIGIN: u64 = 0x80;
+/// Direct binding is enabled.
+pub const DF_1_DIRECT: u64 = 0x100;
+/// The object interposes on all objects but the executable.
+pub const DF_1_INTERPOSE: u64 = 0x400;
+/// The default library search path is ignored.
+pub const DF_1_NODEFLIB: u64 = 0x800;
+/// The object can't be dumped with dldump.
+pub const DF_1_NODUMP: u64 = 0x1000;
+/// The object is a position independent executable.
+pub const DF_1_PIE: u64 = 0x0800_0000;
+
+/// Writes the names of the set flags in `bits` separated by spaces, followed
+/// by any unnamed bits in hex.
+fn fmt_flag_names(
+    f: &mut std::fmt::Formatter<'_>,
+    bits: u64,
+    names: &[(u64, &str)],
+) -> std::fmt::Result {
+    let mut set: Vec<String> = names
+        .iter()
+        .filter(|(flag, _)| bits & flag != 0)
+        .map(|(_, name)| name.to_string())
+        .collect();
+    let unnamed = names.iter().fold(bits, |bits, (flag, _)| bits & !flag);
+    if unnamed != 0 {
+        set.push(format!("0x{:x}", unnamed));
+    }
+
+    write!(f, "{}", set.join(" "))
+}
+
+/// DfFlags holds the DF_* bits of a DT_FLAGS dynamic entry.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DfFlags(pub u64);
+
+impl DfFlags {
+    const NAMES: [(u64, &'static str); 5] = [
+        (DF_ORIGIN, "ORIGIN"),
+        (DF_SYMBOLIC, "SYMBOLIC"),
+        (DF_TEXTREL, "TEXTREL"),
+        (DF_BIND_NOW, "BIND_NOW"),
+        (DF_STATIC_TLS, "STATIC_TLS"),
+    ];
+
+    /// Returns true if every bit of `flags` is set.
+    pub fn contains(self, flags: u64) -> bool {
+        self.0 & flags == flags
+    }
+
+    pub fn origin(self) -> bool {
+        self.contains(DF_ORIGIN)
+    }
+
+    pub fn symbolic(self) -> bool {
+        self.contains(DF_SYMBOLIC)
+    }
+
+    pub fn textrel(self) -> bool {
+        self.contains(DF_TEXTREL)
+    }
+
+    pub fn bind_now(self) -> bool {
+        self.contains(DF_BIND_NOW)
+    }
+
+    pub fn static_tls(self) -> bool {
+        self.contains(DF_STATIC_TLS)
+    }
+}
+
+/// Formats the set flags as readelf does for a DT_FLAGS entry, such as
+/// `BIND_NOW STATIC_TLS`.
+impl std::fmt::Display for DfFlags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_flag_names(f, self.0, &Self::NAMES)
+    }
+}
+
+/// Df1Flags holds the DF_1_* bits of a DT_FLAGS_1 dynamic entry.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Df1Flags(pub u64);
+
+impl Df1Flags {
+    const NAMES: [(u64, &'static str); 13] = [
+        (DF_1_NOW, "NOW"),
+        (DF_1_GLOBAL, "GLOBAL"),
+        (DF_1_GROUP, "GROUP"),
+        (DF_1_NODELETE, "NODELETE"),
+        (DF_1_LOADFLTR, "LOADFLTR"),
+        (DF_1_INITFIRST, "INITFIRST"),
+        (DF_1_NOOPEN, "NOOPEN"),
+        (DF_1_ORIGIN, "ORIGIN"),
+        (DF_1_DIRECT, "DIRECT"),
+        (DF_1_INTERPOSE, "INTERPOSE"),
+        (DF_1_NODEFLIB, "NODEFLIB"),
+        (DF_1_NODUMP, "NODUMP"),
+        (DF_1_PIE, "PIE"),
+    ];
+
+    /// Returns true if every bit of `flags` is set.
+    pub fn contains(self, flags: u64) -> bool {
+        self.0 & flags == flags
+    }
+
+    pub fn now(self) -> bool {
+        self.contains(DF_1_NOW)
+    }
+
+    pub fn global(self) -> bool {
+        self.contains(DF_1_GLOBAL)
+    }
+
+    pub fn nodelete(self) -> bool {
+        self.contains(DF_1_NODELETE)
+    }
+
+    pub fn noopen(self) -> bool {
+        self.contains(DF_1_NOOPEN)
+    }
+
+    pub fn origin(self) -> bool {
+        self.contains(DF_1_ORIGIN)
+    }
+
+    pub fn pie(self) -> bool {
+        self.contains(DF_1_PIE)
+    }
+}
+
+/// Formats the set flags as readelf does for a DT_FLAGS_1 entry, such as
+/// `Flags: NOW PIE`.
+impl std::fmt::Display for Df1Flags {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Flags: ")?;
+        fmt_flag_names(f, self.0, &Self::NAMES)
+    }
+}
+
+/// Combines the DT_FLAGS and DT_FLAGS_1 entries preceding DT_NULL.
+fn dynamic_flags<D: DynamicEntry>(entries: &[D]) -> (DfFlags, Df1Flags) {
+    entries
+        .iter()
+        .take_while(|entry| entry.tag() != Some(DynamicTag::Null))
+        .fold(
+            (DfFlags::default(), Df1Flags::default()),
+            |(flags, flags_1), entry| match entry.tag() {
+                Some(DynamicTag::Flags) => (DfFlags(flags.0 | entry.d_val()), flags_1),
+                Some(DynamicTag::Flags1) => (flags, Df1Flags(flags_1.0 | entry.d_val())),
+                _ => (flags, flags_1),
+            },
+        )
+}
+
+/// RelroLevel describes how much of a file's relocated data is made
+/// read-only after relocation, as reported by tools like checksec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelroLevel {
+    /// The file has no PT_GNU_RELRO segment.
+    None,
+    /// A PT_GNU_RELRO segment is present, but lazy binding leaves the GOT
+    /// writable.
+    Partial,
+    /// A PT_GNU_RELRO segment is present and all symbols are bound at load
+    /// time.
+    Full,
+}
+
+impl std::fmt::Display for RelroLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let repr = match self {
+            RelroLevel::None => "No RELRO",
+            RelroLevel::Partial => "Partial RELRO",
+            RelroLevel::Full => "Full RELRO",
+        };
+
+        write!(f, "{}", repr)
+    }
+}
+
+fn relro_level<P: ProgramHeader, D: DynamicEntry>(
+    program_headers: &[P],
+    entries: &[D],
+) -> RelroLevel {
+    if !program_headers
+        .iter()
+        .any(|ph| ph.p_type() == ProgramHeaderType::GnuRelro)
+    {
+        return RelroLevel::None;
+    }
+
+    let (flags, flags_1) = dynamic_flags(entries);
+    let bind_now = flags.bind_now()
+        || flags_1.now()
+        || entries
+            .iter()
+            .take_while(|entry| entry.tag() != Some(DynamicTag::Null))
+            .any(|entry| entry.tag() == Some(DynamicTag::BindNow));
+
+    if bind_now {
+        RelroLevel::Full
+    } else {
+        RelroLevel::Partial
+    }
+}
+
+/// Returns the file data, from the passed virtual address to the end of its
+/// containing section.
+fn section_data_at_vaddr<'a, S: SectionHeader>(
+    section_headers: &[S],
+    vaddr: u64,
+    image: &'a [u8],
+) -> Option<&'a [u8]> {
+    use std::convert::TryFrom;
+
+    section_headers
+        .iter()
+        .filter(|sh| sh.sh_addr() != 0)
+        .find(|sh| vaddr >= sh.sh_addr() && vaddr - sh.sh_addr() < sh.sh_size())
+        .and_then(|sh| {
+            let offset = usize::try_from(vaddr - sh.sh_addr()).ok()?;
+            sh.data(image)?.get(offset..)
+        })
+}
+
+/// Returns the file data, from the passed virtual address to the end of the
+/// file contents of its containing PT_LOAD segment.
+fn segment_data_at_vaddr<'a, P: ProgramHeader>(
+    program_headers: &[P],
+    vaddr: u64,
+    image: &'a [u8],
+) -> Option<&'a [u8]> {
+    use std::convert::TryFrom;
+
+    program_headers
+        .iter()
+        .filter(|ph| ph.p_type() == ProgramHeaderType::Load)
+        .find(|ph| vaddr >= ph.p_vaddr() && vaddr - ph.p_vaddr() < ph.p_filesz())
+        .and_then(|ph| {
+            let offset = usize::try_from(vaddr - ph.p_vaddr()).ok()?;
+            ph.data(image)?.get(offset..)
+        })
+}
+
+/// Returns the dynamic string table referenced by a DT_STRTAB entry. The
+/// address is resolved against the section headers, falling back to the
+/// PT_LOAD segments for files stripped of their section headers.
+fn dynamic_strtab<'a, P, S, D>(
+    program_headers: &[P],
+    section_headers: &[S],
+    entries: &[D],
+    image: &'a [u8],
+) -> Option<StrTab<'a>>
+where
+    P: ProgramHeader,
+    S: SectionHeader,
+    D: DynamicEntry,
+{
+    entries
+        .iter()
+        .take_while(|entry| entry.tag() != Some(DynamicTag::Null))
+        .find(|entry| entry.tag() == Some(DynamicTag::StrTab))
+        .and_then(|entry| {
+            section_data_at_vaddr(section_headers, entry.d_val(), image)
+                .or_else(|| segment_data_at_vaddr(program_headers, entry.d_val(), image))
+        })
+        .map(StrTab::new)
+}
+
+/// Resolves the string values of each dynamic entry with the passed tag
+/// against the dynamic string table.
+fn dynamic_strings<P, S, D>(
+    program_headers: &[P],
+    section_headers: &[S],
+    entries: &[D],
+    tag: DynamicTag,
+    image: &[u8],
+) -> Vec<String>
+where
+    P: ProgramHeader,
+    S: SectionHeader,
+    D: DynamicEntry,
+{
+    let strtab = match dynamic_strtab(program_headers, section_headers, entries, image) {
+        Some(strtab) => strtab,
+        None => return vec![],
+    };
+
+    entries
+        .iter()
+        .take_while(|entry| entry.tag() != Some(DynamicTag::Null))
+        .filter(|entry| entry.tag() == Some(tag))
+        .filter_map(|entry| strtab.get(entry.d_val() as usize))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Parses each fixed-size entry of a table section, such as a symbol table,
+/// returning an empty vector if the section's data isn't fully available.
+fn parse_table<'a, S, P, T>(section: &S, entry_size: usize, parser: P, image: &'a [u8]) -> Vec<T>
+where
+    S: SectionHeader,
+    P: Parser<'a, &'a [u8], T>,
+{
+    parse_entries(section.data(image), entry_size, parser)
+}
+
+/// Parses each fixed-size entry of a table segment, such as PT_DYNAMIC,
+/// returning an empty vector if the segment's data isn't fully available.
+fn parse_segment_table<'a, S, P, T>(
+    segment: &S,
+    entry_size: usize,
+    parser: P,
+    image: &'a [u8],
+) -> Vec<T>
+where
+    S: ProgramHeader,
+    P: Parser<'a, &'a [u8], T>,
+{
+    parse_entries(segment.data(image), entry_size, parser)
+}
+
+fn parse_entries<'a, P, T>(data: Option<&'a [u8]>, entry_size: usize, parser: P) -> Vec<T>
+where
+    P: Parser<'a, &'a [u8], T>,
+{
+    match data {
+        Some(data) => match take_exact(parser, data.len() / entry_size).parse(data) {
+            Ok(MatchStatus::Match((_, entries))) => entries,
+            _ => vec![],
+        },
+        None => vec![],
+    }
+}
+
+/// Returns the bytes of `image` past the file's declared extent: the further
+/// of `min_size` and the end of the file data of every segment.
+fn trailing_data<'a, P>(min_size: u64, program_headers: &[P], image: &'a [u8]) -> &'a [u8]
+where
+    P: ProgramHeader,
+{
+    use std::convert::TryFrom;
+
+    let extent = program_headers
+        .iter()
+        .map(|ph| ph.p_offset().saturating_add(ph.p_filesz()))
+        .fold(min_size, std::cmp::max);
+    usize::try_from(extent)
+        .ok()
+        .and_then(|end| image.get(end..))
+        .unwrap_or(&[])
+}
+
+/// Computes the GNU hash of a symbol name, as used by `.gnu.hash` sections.
+/// This is the DJB hash, `h = h * 33 + c`, seeded with 5381.
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381u32, |h, &c| {
+        h.wrapping_shl(5).wrapping_add(h).wrapping_add(u32::from(c))
+    })
+}
+
+/// Tests the bloom filter of a `.gnu.hash` section for `name`, returning
+/// `None` if the section is too short to hold the filter it declares.
+fn gnu_hash_bloom_test<A>(encoding: EiData, data: &[u8], name: &str) -> Option<bool>
+where
+    A: AddressWidth + Into<u64>,
+{
+    let (rem, header) = match take_exact(match_u32(encoding), 4).parse(data) {
+        Ok(MatchStatus::Match(m)) => m,
+        _ => return None,
+    };
+    let (bloom_size, bloom_shift) = (header[2], header[3]);
+    if bloom_size == 0 {
+        return None;
+    }
+
+    let word_bits = (std::mem::size_of::<A>() * 8) as u32;
+    let h1 = gnu_hash(name.as_bytes());
+    let h2 = h1.wrapping_shr(bloom_shift);
+    let word_idx = ((h1 / word_bits) % bloom_size) as usize;
+    let offset = word_idx * std::mem::size_of::<A>();
+
+    let word: u64 = match match_addr::<A>(encoding).parse(rem.get(offset..)?) {
+        Ok(MatchStatus::Match((_, word))) => word.into(),
+        _ => return None,
+    };
+    let mask = (1u64 << (h1 % word_bits)) | (1u64 << (h2 % word_bits));
+
+    Some(word & mask == mask)
+}
+
+/// Computes the SysV hash of a symbol name, as used by `.hash` sections. Each
+/// byte is shifted into the hash, with the top four bits folded back in and
+/// cleared.
+pub fn elf_hash(name: &[u8]) -> u32 {
+    name.iter().fold(0u32, |h, &c| {
+        let h = h.wrapping_shl(4).wrapping_add(u32::from(c));
+        let high = h & 0xf000_0000;
+        (h ^ (high >> 24)) & !high
+    })
+}
+
+/// Walks the hash chain of a `.gnu.hash` section for `name`, returning the
+/// index of the first symbol for which `is_named` holds. `None` is returned
+/// if the section is malformed, while `Some(None)` means `name` is absent.
+fn gnu_hash_lookup<A, F>(
+    encoding: EiData,
+    data: &[u8],
+    name: &str,
+    is_named: F,
+) -> Option<Option<usize>>
+where
+    A: AddressWidth + Into<u64>,
+    F: Fn(usize) -> bool,
+{
+    if !gnu_hash_bloom_test::<A>(encoding, data, name)? {
+        return Some(None);
+    }
+
+    let (rem, header) = match take_exact(match_u32(encoding), 4).parse(data) {
+        Ok(MatchStatus::Match(m)) => m,
+        _ => return None,
+    };
+    let (nbuckets, symoffset, bloom_size) =
+        (header[0] as usize, header[1] as usize, header[2] as usize);
+    let words = rem.get(bloom_size * std::mem::size_of::<A>()..)?;
+    let words = match take_exact(match_u32(encoding), words.len() / 4).parse(words) {
+        Ok(MatchStatus::Match((_, words))) => words,
+        _ => return None,
+    };
+    if nbuckets == 0 || words.len() < nbuckets {
+        return None;
+    }
+    let (buckets, chains) = words.split_at(nbuckets);
+
+    let hash = gnu_hash(name.as_bytes());
+    let mut idx = buckets[hash as usize % nbuckets] as usize;
+    if idx < symoffset {
+        return Some(None);
+    }
+
+    // the low bit of each chain value marks the end of the chain.
+    loop {
+        let chain_hash = *chains.get(idx - symoffset)?;
+        if chain_hash | 1 == hash | 1 && is_named(idx) {
+            return Some(Some(idx));
+        }
+        if chain_hash & 1 != 0 {
+            return Some(None);
+        }
+        idx += 1;
+    }
+}
+
+/// Walks the hash chain of a SysV `.hash` section for `name`, returning the
+/// index of the first symbol for which `is_named` holds. `None` is returned
+/// if the section is malformed, while `Some(None)` means `name` is absent.
+fn sysv_hash_lookup<F>(
+    encoding: EiData,
+    data: &[u8],
+    name: &str,
+    is_named: F,
+) -> Option<Option<usize>>
+where
+    F: Fn(usize) -> bool,
+{
+    let words = match take_exact(match_u32(encoding), data.len() / 4).parse(data) {
+        Ok(MatchStatus::Match((_, words))) => words,
+        _ => return None,
+    };
+    let (nbucket, nchain) = match words.as_slice() {
+        [nbucket, nchain, ..] if *nbucket != 0 => (*nbucket as usize, *nchain as usize),
+        _ => return None,
+    };
+    let chains_start = 2usize.checked_add(nbucket)?;
+    let buckets = words.get(2..chains_start)?;
+    let chains = words.get(chains_start..chains_start.checked_add(nchain)?)?;
+
+    let mut idx = buckets[elf_hash(name.as_bytes()) as usize % nbucket] as usize;
+    // a chain can visit each symbol at most once, bounding malformed cycles.
+    for _ in 0..nchain {
+        if idx == 0 {
+            return Some(None);
+        }
+        if is_named(idx) {
+            return Some(Some(idx));
+        }
+        idx = *chains.get(idx)? as usize;
+    }
+
+    None
+}
+
+/// Finds the index of a symbol by name in a dynamic symbol table, preferring
+/// a `.gnu.hash` table, then a `.hash` table and finally falling back to a
+/// linear scan when neither is present or well-formed.
+fn lookup_dynamic_symbol<A, S>(
+    encoding: EiData,
+    gnu_hash: Option<&[u8]>,
+    sysv_hash: Option<&[u8]>,
+    symbols: &[S],
+    strtab: StrTab,
+    name: &str,
+) -> Option<usize>
+where
+    A: AddressWidth + Into<u64>,
+    S: Symbol,
+{
+    let is_named = |idx: usize| {
+        symbols
+            .get(idx)
+            .and_then(|symbol| strtab.get(symbol.st_name() as usize))
+            == Some(name)
+    };
+
+    gnu_hash
+        .and_then(|data| gnu_hash_lookup::<A, _>(encoding, data, name, is_named))
+        .or_else(|| sysv_hash.and_then(|data| sysv_hash_lookup(encoding, data, name, is_named)))
+        .unwrap_or_else(|| (1..symbols.len()).find(|&idx| is_named(idx)))
+}
+
+/// The version index of a local symbol.
+const VER_NDX_LOCAL: u16 = 0;
+/// The version index of an unversioned global symbol.
+const VER_NDX_GLOBAL: u16 = 1;
+/// Masks the hidden bit out of a `.gnu.version` entry.
+const VERSYM_VERSION: u16 = 0x7fff;
+
+/// Collects the name of each version index defined by a `.gnu.version_d`
+/// section or required by a `.gnu.version_r` section, each paired with the
+/// string table named by its sh_link.
+fn version_names<'a>(
+    encoding: EiData,
+    verdef: Option<(&[u8], StrTab<'a>)>,
+    verneed: Option<(&[u8], StrTab<'a>)>,
+) -> std::collections::BTreeMap<u16, &'a str> {
+    let u16_at = |data: &[u8], offset: usize| {
+        matched(match_u16(encoding), data.get(offset..)?).map(|(_, v)| v)
+    };
+    let u32_at = |data: &[u8], offset: usize| {
+        matched(match_u32(encoding), data.get(offset..)?).map(|(_, v)| v as usize)
+    };
+    let mut names = std::collections::BTreeMap::new();
+
+    // each Verdef names its version in the first of its Verdaux entries. The
+    // walks are bounded by the section size to guard against next offsets
+    // that loop.
+    if let Some((data, strtab)) = verdef {
+        let mut offset = 0;
+        for _ in 0..data.len() / 20 {
+            let entry = (|| {
+                let ndx = u16_at(data, offset + 4)?;
+                let aux = offset + u32_at(data, offset + 12)?;
+                let name = strtab.get(u32_at(data, aux)?)?;
+                Some((ndx, name, u32_at(data, offset + 16)?))
+            })();
+            let (ndx, name, next) = match entry {
+                Some(entry) => entry,
+                None => break,
+            };
+
+            names.insert(ndx, name);
+            if next == 0 {
+                break;
+            }
+            offset += next;
+        }
+    }
+
+    // each Verneed lists the versions required from one file as Vernaux
+    // entries.
+    if let Some((data, strtab)) = verneed {
+        let mut offset = 0;
+        let mut remaining = data.len() / 16;
+        while remaining > 0 {
+            let (count, aux, next) = match (
+                u16_at(data, offset + 2),
+                u32_at(data, offset + 8),
+                u32_at(data, offset + 12),
+            ) {
+                (Some(count), Some(aux), Some(next)) => (count, offset + aux, next),
+                _ => break,
+            };
+
+            let mut aux_offset = aux;
+            for _ in 0..count {
+                remaining = remaining.saturating_sub(1);
+                let vernaux = (|| {
+                    let other = u16_at(data, aux_offset + 6)?;
+                    let name = strtab.get(u32_at(data, aux_offset + 8)?)?;
+                    Some((other, name, u32_at(data, aux_offset + 12)?))
+                })();
+                match vernaux {
+                    Some((other, name, aux_next)) => {
+                        names.insert(other, name);
+                        if aux_next == 0 {
+                            break;
+                        }
+                        aux_offset += aux_next;
+                    }
+                    None => break,
+                }
+            }
+
+            remaining = remaining.saturating_sub(1);
+            if next == 0 {
+                break;
+            }
+            offset += next;
+        }
+    }
+
+    names
+}
+
+/// Pairs each `.dynsym` symbol name with the name of the version selected by
+/// the corresponding `.gnu.version` entry. Local and unversioned global
+/// symbols, and symbols without a resolvable version, have no version name.
+fn symbol_versions<S: SectionHeader>(
+    encoding: EiData,
+    section_headers: &[S],
+    symbol_names: Vec<Option<&str>>,
+    image: &[u8],
+) -> Vec<(String, Option<String>)> {
+    let section = |r#type| {
+        section_headers
+            .iter()
+            .find(|sh| sh.sh_type() == r#type)
+            .and_then(|sh| {
+                let strtab = section_headers
+                    .get(sh.sh_link() as usize)
+                    .and_then(|strtab| strtab.data(image))
+                    .map(StrTab::new)?;
+                Some((sh.data(image)?, strtab))
+            })
+    };
+    let versions = version_names(
+        encoding,
+        section(ShType::GnuVerDef),
+        section(ShType::GnuVerNeed),
+    );
+    let versym = section_headers
+        .iter()
+        .find(|sh| sh.sh_type() == ShType::GnuVerSym)
+        .and_then(|sh| sh.data(image))
+        .unwrap_or(&[]);
+
+    symbol_names
+        .into_iter()
+        .enumerate()
+        .map(|(idx, name)| {
+            let version = matched(match_u16(encoding), versym.get(idx * 2..).unwrap_or(&[]))
+                .map(|(_, versym)| versym & VERSYM_VERSION)
+                .filter(|&ndx| ndx != VER_NDX_LOCAL && ndx != VER_NDX_GLOBAL)
+                .and_then(|ndx| versions.get(&ndx))
+                .map(|version| version.to_string());
+
+            (name.unwrap_or("").to_string(), version)
+        })
+        .collect()
+}
+
+/// Parses a legacy `.ctors`/`.dtors` array of addresses, dropping an
+/// optional leading all-ones sentinel and stopping at the terminating `0`.
+fn parse_constructor_array<A>(encoding: EiData, data: &[u8]) -> Vec<u64>
+where
+    A: AddressWidth + Into<u64> + PartialEq + Copy,
+{
+    let width = std::mem::size_of::<A>();
+    let entries = match A::from_bytes(encoding, &vec![0xff; width])
+        .map(|sentinel| expect_addr(encoding, sentinel).parse(data))
+    {
+        Some(Ok(MatchStatus::Match((rem, _)))) => rem,
+        _ => data,
+    };
+
+    match take_exact(match_addr::<A>(encoding), entries.len() / width).parse(entries) {
+        Ok(MatchStatus::Match((_, addrs))) => addrs
+            .into_iter()
+            .map(Into::into)
+            .take_while(|&addr| addr != 0)
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// StrTab wraps the contents of a string table section, like `.shstrtab` or
+/// `.stabstr`, providing lookups of NUL-terminated strings by offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StrTab<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> StrTab<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+
+    /// Returns the string starting at the passed offset, or `None` if the
+    /// offset is out of bounds, the string is unterminated or it is not valid
+    /// UTF-8.
+    pub fn get(&self, offset: usize) -> Option<&'a str> {
+        match c_string().parse(self.data.get(offset..)?) {
+            Ok(MatchStatus::Match((_, string))) => Some(string),
+            _ => None,
+        }
+    }
+}
+
+/// Returns the section header string table for a set of section headers as
+/// referenced by a file header's shstrndx field, or `None` if shstrndx is
+/// out of range. An shstrndx of SHN_XINDEX is resolved through the sh_link
+/// of the first section header.
+fn shstrtab<'a, S: SectionHeader>(
+    section_headers: &[S],
+    shstrndx: u16,
+    image: &'a [u8],
+) -> Option<StrTab<'a>> {
+    let idx = match shstrndx {
+        SHN_XINDEX => section_headers.first()?.sh_link() as usize,
+        shstrndx => shstrndx as usize,
+    };
+
+    section_headers
+        .get(idx)
+        .and_then(|sh| sh.data(image))
+        .map(StrTab::new)
+}
+
+/// Returns the end of a section's file range, or `None` if the section
+/// occupies no space in the file or its end overflows.
+fn section_file_end<S: SectionHeader>(section: &S) -> Option<u64> {
+    if section.sh_type() == ShType::NoBits || section.sh_size() == 0 {
+        None
+    } else {
+        section.sh_offset().checked_add(section.sh_size())
+    }
+}
+
+/// Returns the furthest file extent implied by a file header and its section
+/// headers, saturating to `u64::MAX` on overflow.
+fn expected_min_size<A, S>(file_header: &FileHeader<A>, section_headers: &[S]) -> u64
+where
+    A: Into<u64> + Copy,
+    S: SectionHeader,
+{
+    let table_end = |offset: A, num: u16, entsize: u16| {
+        offset
+            .into()
+            .saturating_add(u64::from(num) * u64::from(entsize))
+    };
+
+    let ph_end = table_end(
+        file_header.ph_offset,
+        file_header.phnum,
+        file_header.phent_size,
+    );
+    let sh_end = table_end(
+        file_header.sh_offset,
+        file_header.shnum,
+        file_header.shent_size,
+    );
+
+    section_headers
+        .iter()
+        .filter(|sh| sh.sh_type() != ShType::NoBits)
+        .map(|sh| sh.sh_offset().saturating_add(sh.sh_size()))
+        .fold(std::cmp::max(ph_end, sh_end), std::cmp::max)
+}
+
+/// Validates that the reserved section header at index 0, if present, is
+/// zeroed. Its sh_size, sh_link and sh_info may hold the section count,
+/// shstrndx and program header count respectively when the file header
+/// defers to them via a shnum of 0, SHN_XINDEX or PN_XNUM. As `ShFlags` can't
+/// represent an empty set, sh_flags isn't checked.
+fn validate_null_section<A, S>(
+    file_header: &FileHeader<A>,
+    section_headers: &[S],
+) -> Result<(), ValidationError>
+where
+    S: SectionHeader,
+{
+    let null = match section_headers.first() {
+        Some(null) => null,
+        None => return Ok(()),
+    };
+
+    let fields = [
+        ("sh_name", u64::from(null.sh_name()), false),
+        ("sh_type", u64::from(null.sh_type() as u32), false),
+        ("sh_addr", null.sh_addr(), false),
+        ("sh_offset", null.sh_offset(), false),
+        ("sh_size", null.sh_size(), file_header.shnum == 0),
+        (
+            "sh_link",
+            u64::from(null.sh_link()),
+            file_header.shstrndx == SHN_XINDEX,
+        ),
+        (
+            "sh_info",
+            u64::from(null.sh_info()),
+            file_header.phnum == PN_XNUM,
+        ),
+        ("sh_addralign", null.sh_addr_align(), false),
+        ("sh_entsize", null.sh_entsize(), false),
+    ];
+
+    match fields
+        .iter()
+        .find(|(_, value, extended)| *value != 0 && !extended)
+    {
+        Some((field, _, _)) => Err(ValidationError::NullSectionNotEmpty { field }),
+        None => Ok(()),
+    }
+}
+
+/// Validates a PT_PHDR segment against the program header table it
+/// describes.
+fn validate_phdr_segment<A, P>(
+    file_header: &FileHeader<A>,
+    program_headers: &[P],
+) -> Result<(), ValidationError>
+where
+    A: Into<u64> + Copy,
+    P: ProgramHeader,
+{
+    let phdr = match program_headers
+        .iter()
+        .find(|ph| ph.p_type() == ProgramHeaderType::PhDr)
+    {
+        Some(phdr) => phdr,
+        None => return Ok(()),
+    };
+
+    let offset = file_header.ph_offset.into();
+    let size = u64::from(file_header.phnum) * u64::from(file_header.phent_size);
+    if phdr.p_offset() != offset {
+        return Err(ValidationError::PhdrOffsetMismatch {
+            expected: offset,
+            found: phdr.p_offset(),
+        });
+    }
+    if phdr.p_filesz() != size {
+        return Err(ValidationError::PhdrSizeMismatch {
+            expected: size,
+            found: phdr.p_filesz(),
+        });
+    }
+
+    let load = program_headers
+        .iter()
+        .filter(|ph| ph.p_type() == ProgramHeaderType::Load)
+        .find(|ph| {
+            offset >= ph.p_offset()
+                && offset.saturating_add(size) <= ph.p_offset().saturating_add(ph.p_filesz())
+        })
+        .ok_or(ValidationError::PhdrNotLoaded)?;
+
+    let vaddr = load.p_vaddr().wrapping_add(offset - load.p_offset());
+    if phdr.p_vaddr() != vaddr {
+        return Err(ValidationError::PhdrAddressMismatch {
+            expected: vaddr,
+            found: phdr.p_vaddr(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Checks the program and section headers against the expectations of a
+/// file's type.
+fn structural_warnings<P, S>(
+    r#type: Type,
+    program_headers: &[P],
+    section_headers: &[S],
+) -> Vec<String>
+where
+    P: ProgramHeader,
+    S: SectionHeader,
+{
+    let segments = |p_type: ProgramHeaderType| {
+        program_headers
+            .iter()
+            .filter(|ph| ph.p_type() == p_type)
+            .count()
+    };
+    let mut warnings = vec![];
+
+    match r#type {
+        Type::Exec | Type::Dyn => {
+            if program_headers.is_empty() {
+                warnings.push(format!("{} file has no program headers", r#type));
+            } else if segments(ProgramHeaderType::Load) == 0 {
+                warnings.push(format!("{} file has no PT_LOAD segments", r#type));
+            }
+        }
+        Type::Rel => {
+            if section_headers.is_empty() {
+                warnings.push(format!("{} file has no sections", r#type));
+            }
+            if !program_headers.is_empty() {
+                warnings.push(format!(
+                    "{} file has {} program headers",
+                    r#type,
+                    program_headers.len()
+                ));
+            }
+        }
+        Type::Core if segments(ProgramHeaderType::Note) == 0 => {
+            warnings.push(format!("{} file has no PT_NOTE segments", r#type));
+        }
+        _ => (),
+    }
+
+    warnings
+}
+
+/// Returns each pair of section indices whose file ranges overlap.
+fn overlapping_sections<S: SectionHeader>(section_headers: &[S]) -> Vec<(usize, usize)> {
+    let ranges: Vec<(usize, u64, u64)> = section_headers
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, sh)| section_file_end(sh).map(|end| (idx, sh.sh_offset(), end)))
+        .collect();
+
+    ranges
+        .iter()
+        .enumerate()
+        .flat_map(|(pos, &(a_idx, a_start, a_end))| {
+            ranges[pos + 1..]
+                .iter()
+                .filter(move |&&(_, b_start, b_end)| a_start < b_end && b_start < a_end)
+                .map(move |&(b_idx, _, _)| (a_idx, b_idx))
+        })
+        .collect()
+}
+
+/// Returns true if `vaddr` falls within the memory image of a PT_LOAD
+/// segment.
+fn is_loaded_vaddr<P: ProgramHeader>(program_headers: &[P], vaddr: u64) -> bool {
+    program_headers
+        .iter()
+        .filter(|ph| ph.p_type() == ProgramHeaderType::Load)
+        .any(|ph| vaddr >= ph.p_vaddr() && vaddr - ph.p_vaddr() < ph.p_memsz())
+}
+
+/// Returns the index of each section whose file range intersects
+/// `[start, start + len)`.
+fn sections_in_range<S: SectionHeader>(section_headers: &[S], start: u64, len: u64) -> Vec<usize> {
+    let end = start.saturating_add(len);
+
+    section_headers
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, sh)| section_file_end(sh).map(|sh_end| (idx, sh.sh_offset(), sh_end)))
+        .filter(|&(_, sh_start, sh_end)| sh_start < end && start < sh_end)
+        .map(|(idx, _, _)| idx)
+        .collect()
+}
+
+/// Resolves the name of every section against the section header string
+/// table.
+fn section_names<'a, S: SectionHeader>(
+    section_headers: &[S],
+    shstrndx: u16,
+    image: &'a [u8],
+) -> Vec<Option<&'a str>> {
+    let strtab = shstrtab(section_headers, shstrndx, image);
+    section_headers
+        .iter()
+        .map(|sh| strtab.and_then(|strtab| strtab.get(sh.sh_name() as usize)))
+        .collect()
+}
+
+/// Returns the first section header matching the passed name.
+fn section_by_name<'s, S: SectionHeader>(
+    section_headers: &'s [S],
+    shstrndx: u16,
+    name: &str,
+    image: &[u8],
+) -> Option<&'s S> {
+    let strtab = shstrtab(section_headers, shstrndx, image)?;
+    section_headers
+        .iter()
+        .find(|sh| strtab.get(sh.sh_name() as usize) == Some(name))
+}
+
+/// ElfHeader represents an ELF Header and functions to link the 32-bit and
+/// 64-bit ElfHeader types.
+pub trait ElfHeader {}
+
+/// ElfHeader32Bit captures the full ELF file header into a single struct along
+/// with the Identification information separated from the file header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElfHeader32<E>
+where
+    E: DataEncoding + Default + 'static,
+{
+    endianness: std::marker::PhantomData<E>,
+    pub ei_ident: EiIdent,
+    pub file_header: FileHeader<ElfAddr32>,
+    pub program_headers: Vec<ProgramHeader32>,
+    pub section_headers: Vec<SectionHeader32>,
+    limits: ParseLimits,
+}
+
+impl<E> ElfHeader32<E>
+where
+    E: DataEncoding + Default + 'static,
+{
+    pub fn new(
+        ei_ident: EiIdent,
+        file_header: FileHeader<ElfAddr32>,
+        program_headers: Vec<ProgramHeader32>,
+        section_headers: Vec<SectionHeader32>,
+    ) -> Self {
+        Self {
+            endianness: std::marker::PhantomData,
+            ei_ident,
+            file_header,
+            program_headers,
+            section_headers,
+            limits: ParseLimits::default(),
+        }
+    }
+
+    /// Returns the program headers as a slice.
+    pub fn program_headers(&self) -> &[ProgramHeader32] {
+        &self.program_headers
+    }
+
+    /// Returns the section headers as a slice.
+    pub fn section_headers(&self) -> &[SectionHeader32] {
+        &self.section_headers
+    }
+
+    /// Returns the section headers following the reserved SHT_NULL entry at
+    /// index 0. Indices into the returned slice are one less than the
+    /// section's index.
+    pub fn sections(&self) -> &[SectionHeader32] {
+        self.section_headers.get(1..).unwrap_or(&[])
+    }
+
+    /// Checks that the reserved section header at index 0 is zeroed, aside
+    /// from any fields extending the file header's counts.
+    pub fn validate_null_section(&self) -> Result<(), ValidationError> {
+        validate_null_section(&self.file_header, &self.section_headers)
+    }
+
+    /// Compares the decoded fields of two headers regardless of the data
+    /// encoding they were parsed with, allowing a little-endian header to be
+    /// compared against a big-endian one. EI_DATA is ignored as it only
+    /// records that encoding.
+    pub fn eq_fields<O>(&self, other: &ElfHeader32<O>) -> bool
+    where
+        O: DataEncoding + Default + 'static,
+    {
+        let ei_ident = EiIdent {
+            ei_data: other.ei_ident.ei_data,
+            ..self.ei_ident
+        };
+
+        ei_ident == other.ei_ident
+            && self.file_header == other.file_header
+            && self.program_headers == other.program_headers
+            && self.section_headers == other.section_headers
+    }
+
+    /// Replaces the read, write and execute bits of the program header at
+    /// `idx`, such as clearing PF_X on a GNU_STACK segment. This only modifies
+    /// the parsed representation, the header must be re-serialized to persist
+    /// the change.
+    pub fn set_segment_permissions(
+        &mut self,
+        idx: usize,
+        perms: Permissions,
+    ) -> Result<(), String> {
+        let phnum = self.program_headers.len();
+        self.program_headers
+            .get_mut(idx)
+            .map(|ph| ph.flags = perms.apply(ph.flags))
+            .ok_or_else(|| {
+                format!(
+                    "program header index {} out of bounds for {} headers",
+                    idx, phnum
+                )
+            })
+    }
+
+    /// Returns each pair of section indices whose
+    /// `[sh_offset, sh_offset + sh_size)` file ranges overlap. NOBITS and
+    /// zero-sized sections are ignored as they occupy no space in the file.
+    pub fn validate_section_layout(&self) -> Vec<(usize, usize)> {
+        overlapping_sections(&self.section_headers)
+    }
+
+    /// Returns the index of each section whose file range intersects
+    /// `[start, start + len)`, attributing an arbitrary span of the file to
+    /// the sections it falls in. NOBITS sections are skipped.
+    pub fn sections_in_range(&self, start: u64, len: u64) -> Vec<usize> {
+        sections_in_range(&self.section_headers, start, len)
+    }
+
+    /// Returns the minimum file size implied by the headers: the furthest
+    /// extent of the program header table, the section header table and the
+    /// file data of every section. An extent that overflows saturates to
+    /// `u64::MAX`.
+    pub fn expected_min_size(&self) -> u64 {
+        expected_min_size(&self.file_header, &self.section_headers)
+    }
+
+    /// Returns the bytes of `image` past both `expected_min_size` and the
+    /// file data of every segment, such as an appended signature, or an
+    /// empty slice when there are none.
+    pub fn trailing_data<'a>(&self, image: &'a [u8]) -> &'a [u8] {
+        trailing_data(self.expected_min_size(), &self.program_headers, image)
+    }
+
+    /// Returns false if the file's class contradicts the only class its
+    /// machine is defined for, such as an ELF32 file for PPC64, which
+    /// suggests corruption. Bi-width architectures always match.
+    pub fn class_matches_machine(&self) -> bool {
+        match self.file_header.machine.natural_class() {
+            Some(class) => class == self.ei_ident.ei_class,
+            None => true,
+        }
+    }
+
+    /// Converts the header back into a builder for editing. Counts and table
+    /// offsets are recomputed when the builder is rebuilt.
+    pub fn into_builder(self) -> ElfHeaderBuilder<ElfAddr32, E> {
+        ElfHeaderBuilder {
+            endianness: std::marker::PhantomData,
+            ei_ident: self.ei_ident,
+            file_header: self.file_header,
+            program_headers: self.program_headers,
+            section_headers: self.section_headers,
+        }
+    }
+
+    /// Returns the identification and file header bytes at the start of
+    /// `image`, truncated if the image is shorter than a full header.
+    pub fn file_header_bytes<'a>(&self, image: &'a [u8]) -> &'a [u8] {
+        let len = std::cmp::min(usize::from(ElfAddr32::EH_SIZE), image.len());
+        &image[..len]
+    }
+
+    /// Cross-checks a PT_PHDR segment, if present, against the location and
+    /// size of the program header table given by the file header and the
+    /// PT_LOAD segment mapping it.
+    pub fn validate_phdr_segment(&self) -> Result<(), ValidationError> {
+        validate_phdr_segment(&self.file_header, &self.program_headers)
+    }
+
+    /// Returns false if a PT_PHDR segment is present whose offset or filesz
+    /// disagrees with the program header table described by the file header.
+    pub fn phdr_is_consistent(&self) -> bool {
+        !matches!(
+            self.validate_phdr_segment(),
+            Err(ValidationError::PhdrOffsetMismatch { .. })
+                | Err(ValidationError::PhdrSizeMismatch { .. })
+        )
+    }
+
+    /// Translates an address in a running process, where the file was
+    /// loaded at `load_base`, back to a file virtual address. Returns `None`
+    /// if the address lies outside of every PT_LOAD segment.
+    pub fn runtime_to_file_vaddr(&self, runtime_addr: u64, load_base: u64) -> Option<u64> {
+        runtime_addr
+            .checked_sub(load_base)
+            .filter(|&vaddr| is_loaded_vaddr(&self.program_headers, vaddr))
+    }
+
+    /// Translates a file virtual address to its address in a running process
+    /// where the file was loaded at `load_base`. Returns `None` if the
+    /// address lies outside of every PT_LOAD segment.
+    pub fn file_to_runtime_vaddr(&self, vaddr: u64, load_base: u64) -> Option<u64> {
+        Some(vaddr)
+            .filter(|&vaddr| is_loaded_vaddr(&self.program_headers, vaddr))
+            .and_then(|vaddr| vaddr.checked_add(load_base))
+    }
+
+    /// Returns a LoadSegment for each PT_LOAD segment, in program header
+    /// order, describing the file and memory ranges a loader maps.
+    pub fn load_map(&self) -> Vec<LoadSegment> {
+        load_map(&self.program_headers)
+    }
+
+    /// Returns, for each section index, the index of the PT_LOAD program
+    /// header containing the section, or `None` if it isn't loaded.
+    pub fn section_to_segment_map(&self) -> Vec<Option<usize>> {
+        section_to_segment_map(&self.program_headers, &self.section_headers)
+    }
+
+    /// Returns the `[start, end)` virtual address ranges between PT_LOAD
+    /// segments that the loader leaves unmapped.
+    pub fn address_gaps(&self) -> Vec<(u64, u64)> {
+        address_gaps(&self.program_headers)
+    }
+
+    /// Returns the TLS initialization image, the `.tdata` contents spanning
+    /// the PT_TLS segment's p_filesz. The segment's remaining
+    /// `p_memsz - p_filesz` bytes are the zero-initialized `.tbss`. `None` is
+    /// returned if the file has no PT_TLS segment or its range falls outside
+    /// of `image`.
+    pub fn tls_template<'a>(&self, image: &'a [u8]) -> Option<&'a [u8]> {
+        self.program_headers
+            .iter()
+            .find(|ph| ph.r#type == ProgramHeaderType::Tls)?
+            .data(image)
+    }
+
+    /// Decodes the symbol index and relocation type of a relocation entry.
+    pub fn relocation_info(&self, rela: &Rela32) -> RelocationInfo {
+        RelocationInfo::from_info32(rela.r_info)
+    }
+
+    /// Returns a description of each way the file's headers deviate from the
+    /// structure its type implies, such as an executable without a PT_LOAD
+    /// segment or a core file without notes.
+    pub fn structural_warnings(&self) -> Vec<String> {
+        structural_warnings(
+            self.file_header.r#type,
+            &self.program_headers,
+            &self.section_headers,
+        )
+    }
+
+    /// Returns the section header string table referenced by the file
+    /// header's shstrndx field.
+    pub fn shstrtab<'a>(&self, image: &'a [u8]) -> Option<StrTab<'a>> {
+        shstrtab(&self.section_headers, self.file_header.shstrndx, image)
+    }
+
+    /// Resolves the name of a section against the section header string
+    /// table.
+    pub fn section_name<'a>(&self, section: &SectionHeader32, image: &'a [u8]) -> Option<&'a str> {
+        self.shstrtab(image)
+            .and_then(|strtab| strtab.get(section.sh_name as usize))
+    }
+
+    /// Returns the first section header with a name matching `name`.
+    pub fn section_by_name(&self, name: &str, image: &[u8]) -> Option<&SectionHeader32> {
+        section_by_name(
+            &self.section_headers,
+            self.file_header.shstrndx,
+            name,
+            image,
+        )
+    }
+
+    /// Returns the section a symbol is defined relative to. `None` is
+    /// returned for undefined, absolute, common and other special indices,
+    /// which `Symbol::special_section_index` distinguishes, as well as for
+    /// out of range indices.
+    pub fn symbol_section(&self, symbol: &Symbol32) -> Option<&SectionHeader32> {
+        match symbol.special_section_index() {
+            Some(_) => None,
+            None => self.section_headers.get(usize::from(symbol.st_shndx)),
+        }
+    }
+
+    /// Returns the address a symbol refers to. In relocatable files st_value
+    /// is an offset into the symbol's section, so the section's sh_addr is
+    /// added to it. As sh_addr is typically zero before linking, the result
+    /// generally remains section-relative. `None` is returned for undefined
+    /// and common symbols, or an out of range section index, in a relocatable
+    /// file. For all other file types st_value is returned as is.
+    pub fn resolved_address(&self, symbol: &Symbol32) -> Option<u64> {
+        if self.file_header.r#type != Type::Rel {
+            return Some(symbol.st_value());
+        }
+
+        match symbol.special_section_index() {
+            Some(SpecialSectionIndex::Absolute) => Some(symbol.st_value()),
+            Some(_) => None,
+            None => self
+                .symbol_section(symbol)
+                .and_then(|sh| sh.sh_addr().checked_add(symbol.st_value())),
+        }
+    }
+
+    /// Returns true if the file carries no `.symtab` symbol table, leaving at
+    /// most the dynamic symbols in `.dynsym`.
+    pub fn is_stripped(&self) -> bool {
+        !self
+            .section_headers
+            .iter()
+            .any(|sh| sh.sh_type == ShType::SymTab)
+    }
+}
+
+impl<E> ElfHeader32<E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    /// Parses each entry of the first dynamic section, including the
+    /// terminating DT_NULL entry and any entries following it. Files without
+    /// a dynamic section, such as those stripped of their section headers,
+    /// fall back to the PT_DYNAMIC segment. An empty vector is returned if
+    /// neither is present.
+    pub fn dynamic(&self, image: &[u8]) -> Vec<DynamicEntry32> {
+        match self
+            .section_headers
+            .iter()
+            .find(|sh| sh.sh_type == ShType::Dynamic)
+        {
+            Some(sh) => parse_table(sh, 8, DynamicEntryParser::<ElfAddr32, E>::new(), image),
+            None => self.dynamic_from_segment(image),
+        }
+    }
+
+    /// Parses each entry of the PT_DYNAMIC segment, located through the
+    /// program headers rather than the section headers. An empty vector is
+    /// returned if the file has no PT_DYNAMIC segment.
+    pub fn dynamic_from_segment(&self, file: &[u8]) -> Vec<DynamicEntry32> {
+        self.program_headers
+            .iter()
+            .find(|ph| ph.r#type == ProgramHeaderType::Dynamic)
+            .map(|ph| parse_segment_table(ph, 8, DynamicEntryParser::<ElfAddr32, E>::new(), file))
+            .unwrap_or_default()
+    }
+
+    /// Iterates over the entries of the first dynamic section, stopping at
+    /// the terminating DT_NULL entry. Nothing is yielded if the file has no
+    /// dynamic section.
+    pub fn dynamic_entries(&self, image: &[u8]) -> DynamicIter<DynamicEntry32> {
+        DynamicIter::new(self.dynamic(image))
+    }
+
+    /// Returns the auxiliary vector recorded in the NT_AUXV note of a core
+    /// file, up to the terminating AT_NULL entry. Nothing is returned if the
+    /// file has no such note.
+    pub fn auxv(&self, image: &[u8]) -> Vec<AuxvEntry> {
+        auxv(
+            EiData::from(E::default()),
+            &self.program_headers,
+            AuxvEntryParser::<ElfAddr32, E>::new(),
+            image,
+        )
+    }
+
+    /// Returns the notes of every SHT_NOTE section, falling back to the
+    /// PT_NOTE segments for files without note sections. Sections and
+    /// segments larger than the header's `max_note_size` are skipped.
+    pub fn notes<'a>(&self, image: &'a [u8]) -> Vec<Note<'a>> {
+        notes(
+            EiData::from(E::default()),
+            &self.program_headers,
+            &self.section_headers,
+            self.limits.max_note_size,
+            image,
+        )
+    }
+
+    /// Reports whether relocated data is protected by a PT_GNU_RELRO segment,
+    /// and whether immediate binding extends that protection to the GOT.
+    pub fn relro_level(&self, image: &[u8]) -> RelroLevel {
+        relro_level(&self.program_headers, &self.dynamic(image))
+    }
+
+    /// Decodes the DT_FLAGS and DT_FLAGS_1 entries of the dynamic section,
+    /// both of which are empty when absent.
+    pub fn dynamic_flags(&self, image: &[u8]) -> (DfFlags, Df1Flags) {
+        dynamic_flags(&self.dynamic(image))
+    }
+
+    /// Returns the name of each shared library listed in a DT_NEEDED entry,
+    /// in the order they appear in the dynamic section.
+    pub fn needed_libraries(&self, image: &[u8]) -> Vec<String> {
+        dynamic_strings(
+            &self.program_headers,
+            &self.section_headers,
+            &self.dynamic(image),
+            DynamicTag::Needed,
+            image,
+        )
+    }
+
+    /// Returns the shared object name recorded in a DT_SONAME entry.
+    pub fn soname(&self, image: &[u8]) -> Option<String> {
+        dynamic_strings(
+            &self.program_headers,
+            &self.section_headers,
+            &self.dynamic(image),
+            DynamicTag::SoName,
+            image,
+        )
+        .into_iter()
+        .next()
+    }
+
+    /// Returns the library search paths of the colon-separated DT_RUNPATH
+    /// entry. As with the dynamic loader, DT_RPATH is only consulted when no
+    /// DT_RUNPATH is present.
+    pub fn runpath(&self, image: &[u8]) -> Vec<String> {
+        let entries = self.dynamic(image);
+        let runpath = dynamic_strings(
+            &self.program_headers,
+            &self.section_headers,
+            &entries,
+            DynamicTag::RunPath,
+            image,
+        );
+        let paths = if runpath.is_empty() {
+            dynamic_strings(
+                &self.program_headers,
+                &self.section_headers,
+                &entries,
+                DynamicTag::RPath,
+                image,
+            )
+        } else {
+            runpath
+        };
+
+        paths
+            .iter()
+            .flat_map(|path| path.split(':'))
+            .filter(|path| !path.is_empty())
+            .map(|path| path.to_string())
+            .collect()
+    }
+
+    /// Returns true if `__stack_chk_fail` appears in `.symtab` or `.dynsym`,
+    /// indicating the file was built with stack protection.
+    pub fn has_stack_protector(&self, image: &[u8]) -> bool {
+        self.any_symbol_named(image, |name| name == "__stack_chk_fail")
+    }
+
+    /// Returns true if a FORTIFY_SOURCE checked function, such as
+    /// `__memcpy_chk`, appears in `.symtab` or `.dynsym`.
+    pub fn uses_fortify(&self, image: &[u8]) -> bool {
+        self.any_symbol_named(image, |name| {
+            name.starts_with("__") && name.ends_with("_chk")
+        })
+    }
+
+    /// Tests each symbol name with any `@VERSION` or `@@VERSION` suffix
+    /// stripped, as linkers record for versioned references.
+    fn any_symbol_named<F>(&self, image: &[u8], predicate: F) -> bool
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.section_headers
+            .iter()
+            .filter(|sh| matches!(sh.sh_type, ShType::SymTab | ShType::DynSym))
+            .flat_map(|sh| self.resolved_symbols(sh, image))
+            .filter_map(|symbol| symbol.name)
+            .map(|name| name.find('@').map_or(name, |at| &name[..at]))
+            .any(predicate)
+    }
+
+    /// Parses the flags and member section indices of a SHT_GROUP section.
+    pub fn section_group(&self, section: &SectionHeader32, image: &[u8]) -> Option<SectionGroup> {
+        match SectionGroupParser::<E>::new().parse(section.data(image)?) {
+            Ok(MatchStatus::Match((_, group))) => Some(group),
+            _ => None,
+        }
+    }
+
+    /// Parses each entry of a SHT_RELA section.
+    pub fn relocations(&self, section: &SectionHeader32, image: &[u8]) -> Vec<Rela32> {
+        parse_table(section, 12, RelaParser::<ElfAddr32, E>::new(), image)
+    }
+
+    /// Parses each entry of the passed symbol table section, such as
+    /// `.symtab` or `.dynsym`. Tables holding more than the `max_symbols`
+    /// the header was parsed with yield no symbols.
+    pub fn symbols(&self, section: &SectionHeader32, image: &[u8]) -> Vec<Symbol32> {
+        if section.sh_size() / 16 > self.limits.max_symbols as u64 {
+            return vec![];
+        }
+        parse_table(section, 16, SymbolParser::<ElfAddr32, E>::new(), image)
+    }
+
+    /// Returns the full sh_flags bitmask of the section header at `idx`, such
+    /// as `SHF_ALLOC | SHF_EXECINSTR`, read from the section header table in
+    /// `image`.
+    pub fn section_flags(&self, idx: usize, image: &[u8]) -> Option<u64> {
+        if idx >= self.section_headers.len() {
+            return None;
+        }
+        let table_offset: usize =
+            std::convert::TryFrom::try_from(self.file_header.sh_offset).ok()?;
+        let offset = table_offset
+            .checked_add(idx.checked_mul(usize::from(self.file_header.shent_size))?)?
+            .checked_add(8)?;
+
+        match match_u32(EiData::from(E::default()))
+            .map(u64::from)
+            .parse(image.get(offset..)?)
+        {
+            Ok(MatchStatus::Match((_, flags))) => Some(flags),
+            _ => None,
+        }
+    }
+
+    /// Parses the passed symbol table section, split into its local symbols
+    /// and the global and weak symbols following them.
+    pub fn split_symbols(
+        &self,
+        section: &SectionHeader32,
+        image: &[u8],
+    ) -> (Vec<Symbol32>, Vec<Symbol32>) {
+        let mut locals = self.symbols(section, image);
+        let globals = locals.split_off(std::cmp::min(section.local_symbol_count(), locals.len()));
+        (locals, globals)
+    }
+
+    /// Parses each entry of the passed symbol table section, resolving its
+    /// name against the string table named by the section's sh_link.
+    pub fn resolved_symbols<'a>(
+        &self,
+        section: &SectionHeader32,
+        image: &'a [u8],
+    ) -> Vec<ResolvedSymbol<'a>> {
+        let strtab = self
+            .section_headers
+            .get(section.sh_link as usize)
+            .and_then(|sh| sh.data(image))
+            .map(StrTab::new);
+        let source = match section.sh_type {
+            ShType::DynSym => SymbolSource::DynSym,
+            _ => SymbolSource::SymTab,
+        };
+
+        self.symbols(section, image)
+            .iter()
+            .map(|symbol| ResolvedSymbol::new(symbol, strtab, source))
+            .collect()
+    }
+
+    /// Resolves the symbols of every `.symtab` and `.dynsym` table, in
+    /// section order, skipping the null symbol leading each table. Each
+    /// symbol's `source` records the table it was read from.
+    pub fn all_symbols<'a>(&self, image: &'a [u8]) -> Vec<ResolvedSymbol<'a>> {
+        self.section_headers
+            .iter()
+            .filter(|sh| matches!(sh.sh_type, ShType::SymTab | ShType::DynSym))
+            .flat_map(|sh| self.resolved_symbols(sh, image).into_iter().skip(1))
+            .collect()
+    }
+
+    /// Resolves symbols as with `all_symbols`, keeping only the first of
+    /// any symbols sharing a name and value, such as an exported function
+    /// listed in both tables.
+    pub fn all_unique_symbols<'a>(&self, image: &'a [u8]) -> Vec<ResolvedSymbol<'a>> {
+        unique_symbols(self.all_symbols(image))
+    }
+
+    /// Pairs the name of each `.dynsym` symbol with its version, such as
+    /// `("printf", Some("GLIBC_2.2.5"))`, as resolved through `.gnu.version`
+    /// and the version definition and requirement sections. Local and
+    /// unversioned global symbols have no version.
+    pub fn symbol_versions(&self, image: &[u8]) -> Vec<(String, Option<String>)> {
+        let names = self
+            .section_headers
+            .iter()
+            .find(|sh| sh.sh_type == ShType::DynSym)
+            .map(|dynsym| {
+                self.resolved_symbols(dynsym, image)
+                    .into_iter()
+                    .map(|symbol| symbol.name)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        symbol_versions(
+            EiData::from(E::default()),
+            &self.section_headers,
+            names,
+            image,
+        )
+    }
+
+    /// Returns the address of each PLT stub paired with its target symbol.
+    /// With IBT enabled the stubs are taken from `.plt.sec`, otherwise from
+    /// `.plt` following its reserved first entry. Symbols are correlated by
+    /// index with the `.rela.plt` relocations and the symbol table they link
+    /// to. SHT_REL relocations aren't parsed, so the `.rel.plt` of i386 and
+    /// other REL-based targets leaves the stubs without symbols.
+    pub fn plt_entries<'a>(&self, image: &'a [u8]) -> Vec<PltEntry<'a>> {
+        let (plt, reserved) = match self.section_by_name(".plt.sec", image) {
+            Some(plt_sec) => (plt_sec, 0),
+            None => match self.section_by_name(".plt", image) {
+                Some(plt) => (plt, 1),
+                None => return vec![],
+            },
+        };
+        let symbols = self.section_by_name(".rela.plt", image).map(|rela_plt| {
+            let names: Vec<Option<&str>> = self
+                .section_headers
+                .get(rela_plt.sh_link as usize)
+                .map(|dynsym| {
+                    self.resolved_symbols(dynsym, image)
+                        .into_iter()
+                        .map(|symbol| symbol.name)
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            self.relocations(rela_plt, image)
+                .iter()
+                .map(|rela| {
+                    names
+                        .get(self.relocation_info(rela).sym as usize)
+                        .copied()
+                        .flatten()
+                })
+                .collect()
+        });
+
+        plt_entries(plt, reserved, symbols, image)
+    }
+
+    fn report<'h>(
+        &'h self,
+        image: &'h [u8],
+    ) -> Report<'h, ElfAddr32, ProgramHeader32, SectionHeader32, DynamicEntry32, Symbol32> {
+        let symbol_tables = self
+            .section_headers
+            .iter()
+            .enumerate()
+            .filter(|(_, sh)| matches!(sh.sh_type, ShType::SymTab | ShType::DynSym))
+            .map(|(idx, sh)| (idx, self.symbols(sh, image)))
+            .collect();
+
+        Report {
+            image,
+            ei_ident: &self.ei_ident,
+            file_header: &self.file_header,
+            program_headers: &self.program_headers,
+            section_headers: &self.section_headers,
+            dynamic: self.dynamic(image),
+            symbol_tables,
+        }
+    }
+
+    /// Returns each entry of the `.stab` section paired with its name as
+    /// resolved against `.stabstr`. An empty vector is returned if the file
+    /// carries no stab debugging information.
+    pub fn stabs<'a>(&self, image: &'a [u8]) -> Vec<(Stab, Option<&'a str>)> {
+        let stab = self
+            .section_by_name(".stab", image)
+            .and_then(|sh| sh.data(image));
+        let stabstr = self
+            .section_by_name(".stabstr", image)
+            .and_then(|sh| sh.data(image))
+            .map(StrTab::new);
+
+        match (stab, stabstr) {
+            (Some(stab), Some(stabstr)) => resolve_stabs::<E>(stab, stabstr),
+            _ => vec![],
+        }
+    }
+
+    /// Parses the header of the first line number program in the
+    /// `.debug_line` section.
+    pub fn debug_line_header(&self, image: &[u8]) -> Option<DebugLineHeader> {
+        let data = self
+            .section_by_name(".debug_line", image)
+            .and_then(|sh| sh.data(image))?;
+
+        match DebugLineHeaderParser::<E>::new().parse(data) {
+            Ok(MatchStatus::Match((_, header))) => Some(header),
+            _ => None,
+        }
+    }
+
+    /// Returns the constructor addresses listed in the legacy `.ctors`
+    /// section, in the order they are run. The section is stored in reverse
+    /// order between a leading `-1` and trailing `0` sentinel, both of which
+    /// are dropped.
+    pub fn ctors(&self, image: &[u8]) -> Vec<u64> {
+        let mut ctors = self.legacy_constructor_array(".ctors", image);
+        ctors.reverse();
+        ctors
+    }
+
+    /// Returns the destructor addresses listed in the legacy `.dtors`
+    /// section, in the order they are run, with the leading `-1` and trailing
+    /// `0` sentinels dropped.
+    pub fn dtors(&self, image: &[u8]) -> Vec<u64> {
+        self.legacy_constructor_array(".dtors", image)
+    }
+
+    fn legacy_constructor_array(&self, name: &str, image: &[u8]) -> Vec<u64> {
+        let encoding = EiData::from(E::default());
+
+        match self.section_by_name(name, image) {
+            Some(sh) => sh
+                .data(image)
+                .map(|data| parse_constructor_array::<ElfAddr32>(encoding, data))
+                .unwrap_or_default(),
+            None => vec![],
+        }
+    }
+
+    /// Parses the file-scoped `aeabi` build attributes of the
+    /// SHT_ARM_ATTRIBUTES section, keyed by tag.
+    pub fn arm_attributes(
+        &self,
+        image: &[u8],
+    ) -> Option<std::collections::BTreeMap<u64, ArmAttributeValue>> {
+        self.section_headers
+            .iter()
+            .find(|sh| sh.sh_type == ShType::ArmAttributes)
+            .and_then(|sh| sh.data(image))
+            .and_then(|data| parse_arm_attributes(EiData::from(E::default()), data))
+    }
+
+    /// Queries the `.gnu.hash` bloom filter for `name`. A `false` result
+    /// means the symbol is definitely not exported, while `true` means the
+    /// hash chains must be checked. Files without a well-formed `.gnu.hash`
+    /// section always return `true`.
+    pub fn gnu_hash_maybe_contains(&self, name: &str, image: &[u8]) -> bool {
+        self.section_by_name(".gnu.hash", image)
+            .and_then(|sh| sh.data(image))
+            .and_then(|data| {
+                gnu_hash_bloom_test::<ElfAddr32>(EiData::from(E::default()), data, name)
+            })
+            .unwrap_or(true)
+    }
+
+    /// Looks up a `.dynsym` symbol by name, using the `.gnu.hash` table when
+    /// present, otherwise the `.hash` table, and otherwise scanning the
+    /// symbol table linearly.
+    pub fn lookup_dynamic_symbol(&self, image: &[u8], name: &str) -> Option<Symbol32> {
+        let dynsym = self
+            .section_headers
+            .iter()
+            .find(|sh| sh.sh_type == ShType::DynSym)?;
+        let strtab = self
+            .section_headers
+            .get(dynsym.sh_link as usize)
+            .and_then(|sh| sh.data(image))
+            .map(StrTab::new)?;
+        let hash_table = |r#type| {
+            self.section_headers
+                .iter()
+                .find(|sh| sh.sh_type == r#type)
+                .and_then(|sh| sh.data(image))
+        };
+
+        let symbols = self.symbols(dynsym, image);
+        lookup_dynamic_symbol::<ElfAddr32, _>(
+            EiData::from(E::default()),
+            hash_table(ShType::GnuHash),
+            hash_table(ShType::Hash),
+            &symbols,
+            strtab,
+            name,
+        )
+        .and_then(|idx| symbols.get(idx).copied())
+    }
+
+    /// Returns the contents of a section, transparently inflating sections
+    /// flagged SHF_COMPRESSED. Use `SectionHeader::data` for the raw on-disk
+    /// bytes, including the compression header. Returns `None` if the data
+    /// lies outside the image, cannot be decompressed or would inflate past
+    /// the header's `max_decompressed_size`.
+    pub fn section_data_decompressed<'a>(
+        &self,
+        section: &SectionHeader32,
+        image: &'a [u8],
+    ) -> Option<std::borrow::Cow<'a, [u8]>> {
+        let data = section.data(image)?;
+        if section.sh_flags() & SHF_COMPRESSED == 0 {
+            return Some(std::borrow::Cow::Borrowed(data));
+        }
+
+        match CompressionHeaderParser::<ElfAddr32, E>::new().parse(data) {
+            Ok(MatchStatus::Match((payload, header)))
+                if header.ch_size <= self.limits.max_decompressed_size =>
+            {
+                decompress_section(&header, payload).map(std::borrow::Cow::Owned)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the debug file name and CRC32 recorded in the
+    /// `.gnu_debuglink` section, if present and well formed.
+    pub fn debuglink(&self, image: &[u8]) -> Option<(String, u32)> {
+        self.section_by_name(".gnu_debuglink", image)
+            .and_then(|sh| sh.data(image))
+            .and_then(|data| parse_debuglink(EiData::from(E::default()), data))
+    }
+}
+
+impl ElfHeader for ElfHeader32<LittleEndian> {}
+
+/// Borrows the program header table as a slice.
+impl<E> AsRef<[ProgramHeader32]> for ElfHeader32<E>
+where
+    E: DataEncoding + Default + 'static,
+{
+    fn as_ref(&self) -> &[ProgramHeader32] {
+        self.program_headers()
+    }
+}
+
+/// Iterating over a borrowed ElfHeader32 yields each of its section headers in
+/// table order. Program headers remain accessible via the program_headers
+/// field.
+impl<'a, E> IntoIterator for &'a ElfHeader32<E>
+where
+    E: DataEncoding + Default + 'static,
+{
+    type Item = &'a SectionHeader32;
+    type IntoIter = std::slice::Iter<'a, SectionHeader32>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.section_headers.iter()
+    }
+}
+
+impl<E> From<ElfHeader32<E>> for Vec<u8>
+where
+    SectionHeader32: Serialize<ElfAddr32, E>,
+    ProgramHeader32: Serialize<ElfAddr32, E>,
+    FileHeader<ElfAddr32>: Serialize<ElfAddr32, E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn from(src: ElfHeader32<E>) -> Self {
+        let ident_bytes = Into::<Vec<u8>>::into(src.ei_ident);
+        let fh_bytes: Vec<u8> = Serialize::<ElfAddr32, E>::serialize(&src.file_header);
+        let ph_bytes: Vec<u8> = src
+            .program_headers
+            .iter()
+            .map(|ph| Serialize::<ElfAddr32, E>::serialize(ph))
+            .into_iter()
+            .flatten()
+            .collect();
+        let sh_bytes: Vec<u8> = src
+            .section_headers
+            .iter()
+            .map(|sh| Serialize::<ElfAddr32, E>::serialize(sh))
+            .into_iter()
+            .flatten()
+            .collect();
+
+        vec![ident_bytes, fh_bytes, ph_bytes, sh_bytes]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+/// ElfHeader64Bit captures the full ELF file header into a single struct along
+/// with the Identification information separated from the file header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElfHeader64<E>
+where
+    E: DataEncoding,
+{
+    endianness: std::marker::PhantomData<E>,
+    pub ei_ident: EiIdent,
+    pub file_header: FileHeader<ElfAddr64>,
+    pub program_headers: Vec<ProgramHeader64>,
+    pub section_headers: Vec<SectionHeader64>,
+    limits: ParseLimits,
+}
+
+impl<E> ElfHeader64<E>
+where
+    E: DataEncoding,
+{
+    pub fn new(
+        ei_ident: EiIdent,
+        file_header: FileHeader<ElfAddr64>,
+        program_headers: Vec<ProgramHeader64>,
+        section_headers: Vec<SectionHeader64>,
+    ) -> Self {
+        Self {
+            endianness: std::marker::PhantomData,
+            ei_ident,
+            file_header,
+            program_headers,
+            section_headers,
+            limits: ParseLimits::default(),
+        }
+    }
+
+    /// Returns the program headers as a slice.
+    pub fn program_headers(&self) -> &[ProgramHeader64] {
+        &self.program_headers
+    }
+
+    /// Returns the section headers as a slice.
+    pub fn section_headers(&self) -> &[SectionHeader64] {
+        &self.section_headers
+    }
+
+    /// Returns the section headers following the reserved SHT_NULL entry at
+    /// index 0. Indices into the returned slice are one less than the
+    /// section's index.
+    pub fn sections(&self) -> &[SectionHeader64] {
+        self.section_headers.get(1..).unwrap_or(&[])
+    }
+
+    /// Checks that the reserved section header at index 0 is zeroed, aside
+    /// from any fields extending the file header's counts.
+    pub fn validate_null_section(&self) -> Result<(), ValidationError> {
+        validate_null_section(&self.file_header, &self.section_headers)
+    }
+
+    /// Compares the decoded fields of two headers regardless of the data
+    /// encoding they were parsed with, allowing a little-endian header to be
+    /// compared against a big-endian one. EI_DATA is ignored as it only
+    /// records that encoding.
+    pub fn eq_fields<O>(&self, other: &ElfHeader64<O>) -> bool
+    where
+        O: DataEncoding + Default + 'static,
+    {
+        let ei_ident = EiIdent {
+            ei_data: other.ei_ident.ei_data,
+            ..self.ei_ident
+        };
+
+        ei_ident == other.ei_ident
+            && self.file_header == other.file_header
+            && self.program_headers == other.program_headers
+            && self.section_headers == other.section_headers
+    }
+
+    /// Replaces the read, write and execute bits of the program header at
+    /// `idx`, such as clearing PF_X on a GNU_STACK segment. This only modifies
+    /// the parsed representation, the header must be re-serialized to persist
+    /// the change.
+    pub fn set_segment_permissions(
+        &mut self,
+        idx: usize,
+        perms: Permissions,
+    ) -> Result<(), String> {
+        let phnum = self.program_headers.len();
+        self.program_headers
+            .get_mut(idx)
+            .map(|ph| ph.flags = perms.apply(ph.flags))
+            .ok_or_else(|| {
+                format!(
+                    "program header index {} out of bounds for {} headers",
+                    idx, phnum
+                )
+            })
+    }
+
+    /// Returns each pair of section indices whose
+    /// `[sh_offset, sh_offset + sh_size)` file ranges overlap. NOBITS and
+    /// zero-sized sections are ignored as they occupy no space in the file.
+    pub fn validate_section_layout(&self) -> Vec<(usize, usize)> {
+        overlapping_sections(&self.section_headers)
+    }
+
+    /// Returns the index of each section whose file range intersects
+    /// `[start, start + len)`, attributing an arbitrary span of the file to
+    /// the sections it falls in. NOBITS sections are skipped.
+    pub fn sections_in_range(&self, start: u64, len: u64) -> Vec<usize> {
+        sections_in_range(&self.section_headers, start, len)
+    }
+
+    /// Returns the minimum file size implied by the headers: the furthest
+    /// extent of the program header table, the section header table and the
+    /// file data of every section. An extent that overflows saturates to
+    /// `u64::MAX`.
+    pub fn expected_min_size(&self) -> u64 {
+        expected_min_size(&self.file_header, &self.section_headers)
+    }
+
+    /// Returns the bytes of `image` past both `expected_min_size` and the
+    /// file data of every segment, such as an appended signature, or an
+    /// empty slice when there are none.
+    pub fn trailing_data<'a>(&self, image: &'a [u8]) -> &'a [u8] {
+        trailing_data(self.expected_min_size(), &self.program_headers, image)
+    }
+
+    /// Returns false if the file's class contradicts the only class its
+    /// machine is defined for, such as an ELF32 file for PPC64, which
+    /// suggests corruption. Bi-width architectures always match.
+    pub fn class_matches_machine(&self) -> bool {
+        match self.file_header.machine.natural_class() {
+            Some(class) => class == self.ei_ident.ei_class,
+            None => true,
+        }
+    }
+
+    /// Converts the header back into a builder for editing. Counts and table
+    /// offsets are recomputed when the builder is rebuilt.
+    pub fn into_builder(self) -> ElfHeaderBuilder<ElfAddr64, E> {
+        ElfHeaderBuilder {
+            endianness: std::marker::PhantomData,
+            ei_ident: self.ei_ident,
+            file_header: self.file_header,
+            program_headers: self.program_headers,
+            section_headers: self.section_headers,
+        }
+    }
+
+    /// Returns the identification and file header bytes at the start of
+    /// `image`, truncated if the image is shorter than a full header.
+    pub fn file_header_bytes<'a>(&self, image: &'a [u8]) -> &'a [u8] {
+        let len = std::cmp::min(usize::from(ElfAddr64::EH_SIZE), image.len());
+        &image[..len]
+    }
+
+    /// Cross-checks a PT_PHDR segment, if present, against the location and
+    /// size of the program header table given by the file header and the
+    /// PT_LOAD segment mapping it.
+    pub fn validate_phdr_segment(&self) -> Result<(), ValidationError> {
+        validate_phdr_segment(&self.file_header, &self.program_headers)
+    }
+
+    /// Returns false if a PT_PHDR segment is present whose offset or filesz
+    /// disagrees with the program header table described by the file header.
+    pub fn phdr_is_consistent(&self) -> bool {
+        !matches!(
+            self.validate_phdr_segment(),
+            Err(ValidationError::PhdrOffsetMismatch { .. })
+                | Err(ValidationError::PhdrSizeMismatch { .. })
+        )
+    }
+
+    /// Translates an address in a running process, where the file was
+    /// loaded at `load_base`, back to a file virtual address. Returns `None`
+    /// if the address lies outside of every PT_LOAD segment.
+    pub fn runtime_to_file_vaddr(&self, runtime_addr: u64, load_base: u64) -> Option<u64> {
+        runtime_addr
+            .checked_sub(load_base)
+            .filter(|&vaddr| is_loaded_vaddr(&self.program_headers, vaddr))
+    }
+
+    /// Translates a file virtual address to its address in a running process
+    /// where the file was loaded at `load_base`. Returns `None` if the
+    /// address lies outside of every PT_LOAD segment.
+    pub fn file_to_runtime_vaddr(&self, vaddr: u64, load_base: u64) -> Option<u64> {
+        Some(vaddr)
+            .filter(|&vaddr| is_loaded_vaddr(&self.program_headers, vaddr))
+            .and_then(|vaddr| vaddr.checked_add(load_base))
+    }
+
+    /// Returns a LoadSegment for each PT_LOAD segment, in program header
+    /// order, describing the file and memory ranges a loader maps.
+    pub fn load_map(&self) -> Vec<LoadSegment> {
+        load_map(&self.program_headers)
+    }
+
+    /// Returns, for each section index, the index of the PT_LOAD program
+    /// header containing the section, or `None` if it isn't loaded.
+    pub fn section_to_segment_map(&self) -> Vec<Option<usize>> {
+        section_to_segment_map(&self.program_headers, &self.section_headers)
+    }
+
+    /// Returns the `[start, end)` virtual address ranges between PT_LOAD
+    /// segments that the loader leaves unmapped.
+    pub fn address_gaps(&self) -> Vec<(u64, u64)> {
+        address_gaps(&self.program_headers)
+    }
+
+    /// Returns the TLS initialization image, the `.tdata` contents spanning
+    /// the PT_TLS segment's p_filesz. The segment's remaining
+    /// `p_memsz - p_filesz` bytes are the zero-initialized `.tbss`. `None` is
+    /// returned if the file has no PT_TLS segment or its range falls outside
+    /// of `image`.
+    pub fn tls_template<'a>(&self, image: &'a [u8]) -> Option<&'a [u8]> {
+        self.program_headers
+            .iter()
+            .find(|ph| ph.r#type == ProgramHeaderType::Tls)?
+            .data(image)
+    }
+
+    /// Decodes the symbol index and relocation type of a relocation entry,
+    /// accounting for the nonstandard r_info layout used by MIPS64.
+    pub fn relocation_info(&self, rela: &Rela64) -> RelocationInfo {
+        match self.file_header.machine {
+            Machine::MIPS => RelocationInfo::from_mips64_info(self.ei_ident.ei_data, rela.r_info),
+            _ => RelocationInfo::from_info64(rela.r_info),
+        }
+    }
+
+    /// Returns a description of each way the file's headers deviate from the
+    /// structure its type implies, such as an executable without a PT_LOAD
+    /// segment or a core file without notes.
+    pub fn structural_warnings(&self) -> Vec<String> {
+        structural_warnings(
+            self.file_header.r#type,
+            &self.program_headers,
+            &self.section_headers,
+        )
+    }
+
+    /// Returns the section header string table referenced by the file
+    /// header's shstrndx field.
+    pub fn shstrtab<'a>(&self, image: &'a [u8]) -> Option<StrTab<'a>> {
+        shstrtab(&self.section_headers, self.file_header.shstrndx, image)
+    }
+
+    /// Resolves the name of a section against the section header string
+    /// table.
+    pub fn section_name<'a>(&self, section: &SectionHeader64, image: &'a [u8]) -> Option<&'a str> {
+        self.shstrtab(image)
+            .and_then(|strtab| strtab.get(section.sh_name as usize))
+    }
+
+    /// Returns the first section header with a name matching `name`.
+    pub fn section_by_name(&self, name: &str, image: &[u8]) -> Option<&SectionHeader64> {
+        section_by_name(
+            &self.section_headers,
+            self.file_header.shstrndx,
+            name,
+            image,
+        )
+    }
+
+    /// Returns the section a symbol is defined relative to. `None` is
+    /// returned for undefined, absolute, common and other special indices,
+    /// which `Symbol::special_section_index` distinguishes, as well as for
+    /// out of range indices.
+    pub fn symbol_section(&self, symbol: &Symbol64) -> Option<&SectionHeader64> {
+        match symbol.special_section_index() {
+            Some(_) => None,
+            None => self.section_headers.get(usize::from(symbol.st_shndx)),
+        }
+    }
+
+    /// Returns the address a symbol refers to. In relocatable files st_value
+    /// is an offset into the symbol's section, so the section's sh_addr is
+    /// added to it. As sh_addr is typically zero before linking, the result
+    /// generally remains section-relative. `None` is returned for undefined
+    /// and common symbols, or an out of range section index, in a relocatable
+    /// file. For all other file types st_value is returned as is.
+    pub fn resolved_address(&self, symbol: &Symbol64) -> Option<u64> {
+        if self.file_header.r#type != Type::Rel {
+            return Some(symbol.st_value());
+        }
+
+        match symbol.special_section_index() {
+            Some(SpecialSectionIndex::Absolute) => Some(symbol.st_value()),
+            Some(_) => None,
+            None => self
+                .symbol_section(symbol)
+                .and_then(|sh| sh.sh_addr().checked_add(symbol.st_value())),
+        }
+    }
+
+    /// Returns true if the file carries no `.symtab` symbol table, leaving at
+    /// most the dynamic symbols in `.dynsym`.
+    pub fn is_stripped(&self) -> bool {
+        !self
+            .section_headers
+            .iter()
+            .any(|sh| sh.sh_type == ShType::SymTab)
+    }
+}
+
+impl<E> ElfHeader64<E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    /// Parses each entry of the first dynamic section, including the
+    /// terminating DT_NULL entry and any entries following it. Files without
+    /// a dynamic section, such as those stripped of their section headers,
+    /// fall back to the PT_DYNAMIC segment. An empty vector is returned if
+    /// neither is present.
+    pub fn dynamic(&self, image: &[u8]) -> Vec<DynamicEntry64> {
+        match self
+            .section_headers
+            .iter()
+            .find(|sh| sh.sh_type == ShType::Dynamic)
+        {
+            Some(sh) => parse_table(sh, 16, DynamicEntryParser::<ElfAddr64, E>::new(), image),
+            None => self.dynamic_from_segment(image),
+        }
+    }
+
+    /// Parses each entry of the PT_DYNAMIC segment, located through the
+    /// program headers rather than the section headers. An empty vector is
+    /// returned if the file has no PT_DYNAMIC segment.
+    pub fn dynamic_from_segment(&self, file: &[u8]) -> Vec<DynamicEntry64> {
+        self.program_headers
+            .iter()
+            .find(|ph| ph.r#type == ProgramHeaderType::Dynamic)
+            .map(|ph| parse_segment_table(ph, 16, DynamicEntryParser::<ElfAddr64, E>::new(), file))
+            .unwrap_or_default()
+    }
+
+    /// Iterates over the entries of the first dynamic section, stopping at
+    /// the terminating DT_NULL entry. Nothing is yielded if the file has no
+    /// dynamic section.
+    pub fn dynamic_entries(&self, image: &[u8]) -> DynamicIter<DynamicEntry64> {
+        DynamicIter::new(self.dynamic(image))
+    }
+
+    /// Returns the auxiliary vector recorded in the NT_AUXV note of a core
+    /// file, up to the terminating AT_NULL entry. Nothing is returned if the
+    /// file has no such note.
+    pub fn auxv(&self, image: &[u8]) -> Vec<AuxvEntry> {
+        auxv(
+            EiData::from(E::default()),
+            &self.program_headers,
+            AuxvEntryParser::<ElfAddr64, E>::new(),
+            image,
+        )
+    }
+
+    /// Returns the notes of every SHT_NOTE section, falling back to the
+    /// PT_NOTE segments for files without note sections. Sections and
+    /// segments larger than the header's `max_note_size` are skipped.
+    pub fn notes<'a>(&self, image: &'a [u8]) -> Vec<Note<'a>> {
+        notes(
+            EiData::from(E::default()),
+            &self.program_headers,
+            &self.section_headers,
+            self.limits.max_note_size,
+            image,
+        )
+    }
+
+    /// Reports whether relocated data is protected by a PT_GNU_RELRO segment,
+    /// and whether immediate binding extends that protection to the GOT.
+    pub fn relro_level(&self, image: &[u8]) -> RelroLevel {
+        relro_level(&self.program_headers, &self.dynamic(image))
+    }
+
+    /// Decodes the DT_FLAGS and DT_FLAGS_1 entries of the dynamic section,
+    /// both of which are empty when absent.
+    pub fn dynamic_flags(&self, image: &[u8]) -> (DfFlags, Df1Flags) {
+        dynamic_flags(&self.dynamic(image))
+    }
+
+    /// Returns the name of each shared library listed in a DT_NEEDED entry,
+    /// in the order they appear in the dynamic section.
+    pub fn needed_libraries(&self, image: &[u8]) -> Vec<String> {
+        dynamic_strings(
+            &self.program_headers,
+            &self.section_headers,
+            &self.dynamic(image),
+            DynamicTag::Needed,
+            image,
+        )
+    }
+
+    /// Returns the shared object name recorded in a DT_SONAME entry.
+    pub fn soname(&self, image: &[u8]) -> Option<String> {
+        dynamic_strings(
+            &self.program_headers,
+            &self.section_headers,
+            &self.dynamic(image),
+            DynamicTag::SoName,
+            image,
+        )
+        .into_iter()
+        .next()
+    }
+
+    /// Returns the library search paths of the colon-separated DT_RUNPATH
+    /// entry. As with the dynamic loader, DT_RPATH is only consulted when no
+    /// DT_RUNPATH is present.
+    pub fn runpath(&self, image: &[u8]) -> Vec<String> {
+        let entries = self.dynamic(image);
+        let runpath = dynamic_strings(
+            &self.program_headers,
+            &self.section_headers,
+            &entries,
+            DynamicTag::RunPath,
+            image,
+        );
+        let paths = if runpath.is_empty() {
+            dynamic_strings(
+                &self.program_headers,
+                &self.section_headers,
+                &entries,
+                DynamicTag::RPath,
+                image,
+            )
+        } else {
+            runpath
+        };
+
+        paths
+            .iter()
+            .flat_map(|path| path.split(':'))
+            .filter(|path| !path.is_empty())
+            .map(|path| path.to_string())
+            .collect()
+    }
+
+    /// Returns true if `__stack_chk_fail` appears in `.symtab` or `.dynsym`,
+    /// indicating the file was built with stack protection.
+    pub fn has_stack_protector(&self, image: &[u8]) -> bool {
+        self.any_symbol_named(image, |name| name == "__stack_chk_fail")
+    }
+
+    /// Returns true if a FORTIFY_SOURCE checked function, such as
+    /// `__memcpy_chk`, appears in `.symtab` or `.dynsym`.
+    pub fn uses_fortify(&self, image: &[u8]) -> bool {
+        self.any_symbol_named(image, |name| {
+            name.starts_with("__") && name.ends_with("_chk")
+        })
+    }
+
+    /// Tests each symbol name with any `@VERSION` or `@@VERSION` suffix
+    /// stripped, as linkers record for versioned references.
+    fn any_symbol_named<F>(&self, image: &[u8], predicate: F) -> bool
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.section_headers
+            .iter()
+            .filter(|sh| matches!(sh.sh_type, ShType::SymTab | ShType::DynSym))
+            .flat_map(|sh| self.resolved_symbols(sh, image))
+            .filter_map(|symbol| symbol.name)
+            .map(|name| name.find('@').map_or(name, |at| &name[..at]))
+            .any(predicate)
+    }
+
+    /// Parses the flags and member section indices of a SHT_GROUP section.
+    pub fn section_group(&self, section: &SectionHeader64, image: &[u8]) -> Option<SectionGroup> {
+        match SectionGroupParser::<E>::new().parse(section.data(image)?) {
+            Ok(MatchStatus::Match((_, group))) => Some(group),
+            _ => None,
+        }
+    }
+
+    /// Parses each entry of a SHT_RELA section.
+    pub fn relocations(&self, section: &SectionHeader64, image: &[u8]) -> Vec<Rela64> {
+        parse_table(section, 24, RelaParser::<ElfAddr64, E>::new(), image)
+    }
+
+    /// Parses each entry of the passed symbol table section, such as
+    /// `.symtab` or `.dynsym`. Tables holding more than the `max_symbols`
+    /// the header was parsed with yield no symbols.
+    pub fn symbols(&self, section: &SectionHeader64, image: &[u8]) -> Vec<Symbol64> {
+        if section.sh_size() / 24 > self.limits.max_symbols as u64 {
+            return vec![];
+        }
+        parse_table(section, 24, SymbolParser::<ElfAddr64, E>::new(), image)
+    }
+
+    /// Returns the full sh_flags bitmask of the section header at `idx`, such
+    /// as `SHF_ALLOC | SHF_EXECINSTR`, read from the section header table in
+    /// `image`.
+    pub fn section_flags(&self, idx: usize, image: &[u8]) -> Option<u64> {
+        if idx >= self.section_headers.len() {
+            return None;
+        }
+        let table_offset: usize =
+            std::convert::TryFrom::try_from(self.file_header.sh_offset).ok()?;
+        let offset = table_offset
+            .checked_add(idx.checked_mul(usize::from(self.file_header.shent_size))?)?
+            .checked_add(8)?;
+
+        match match_u64(EiData::from(E::default())).parse(image.get(offset..)?) {
+            Ok(MatchStatus::Match((_, flags))) => Some(flags),
+            _ => None,
+        }
+    }
+
+    /// Parses the passed symbol table section, split into its local symbols
+    /// and the global and weak symbols following them.
+    pub fn split_symbols(
+        &self,
+        section: &SectionHeader64,
+        image: &[u8],
+    ) -> (Vec<Symbol64>, Vec<Symbol64>) {
+        let mut locals = self.symbols(section, image);
+        let globals = locals.split_off(std::cmp::min(section.local_symbol_count(), locals.len()));
+        (locals, globals)
+    }
+
+    /// Parses each entry of the passed symbol table section, resolving its
+    /// name against the string table named by the section's sh_link.
+    pub fn resolved_symbols<'a>(
+        &self,
+        section: &SectionHeader64,
+        image: &'a [u8],
+    ) -> Vec<ResolvedSymbol<'a>> {
+        let strtab = self
+            .section_headers
+            .get(section.sh_link as usize)
+            .and_then(|sh| sh.data(image))
+            .map(StrTab::new);
+        let source = match section.sh_type {
+            ShType::DynSym => SymbolSource::DynSym,
+            _ => SymbolSource::SymTab,
+        };
+
+        self.symbols(section, image)
+            .iter()
+            .map(|symbol| ResolvedSymbol::new(symbol, strtab, source))
+            .collect()
+    }
+
+    /// Resolves the symbols of every `.symtab` and `.dynsym` table, in
+    /// section order, skipping the null symbol leading each table. Each
+    /// symbol's `source` records the table it was read from.
+    pub fn all_symbols<'a>(&self, image: &'a [u8]) -> Vec<ResolvedSymbol<'a>> {
+        self.section_headers
+            .iter()
+            .filter(|sh| matches!(sh.sh_type, ShType::SymTab | ShType::DynSym))
+            .flat_map(|sh| self.resolved_symbols(sh, image).into_iter().skip(1))
+            .collect()
+    }
+
+    /// Resolves symbols as with `all_symbols`, keeping only the first of
+    /// any symbols sharing a name and value, such as an exported function
+    /// listed in both tables.
+    pub fn all_unique_symbols<'a>(&self, image: &'a [u8]) -> Vec<ResolvedSymbol<'a>> {
+        unique_symbols(self.all_symbols(image))
+    }
+
+    /// Pairs the name of each `.dynsym` symbol with its version, such as
+    /// `("printf", Some("GLIBC_2.2.5"))`, as resolved through `.gnu.version`
+    /// and the version definition and requirement sections. Local and
+    /// unversioned global symbols have no version.
+    pub fn symbol_versions(&self, image: &[u8]) -> Vec<(String, Option<String>)> {
+        let names = self
+            .section_headers
+            .iter()
+            .find(|sh| sh.sh_type == ShType::DynSym)
+            .map(|dynsym| {
+                self.resolved_symbols(dynsym, image)
+                    .into_iter()
+                    .map(|symbol| symbol.name)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        symbol_versions(
+            EiData::from(E::default()),
+            &self.section_headers,
+            names,
+            image,
+        )
+    }
+
+    /// Returns the address of each PLT stub paired with its target symbol.
+    /// With IBT enabled the stubs are taken from `.plt.sec`, otherwise from
+    /// `.plt` following its reserved first entry. Symbols are correlated by
+    /// index with the `.rela.plt` relocations and the symbol table they link
+    /// to. SHT_REL relocations aren't parsed, so the `.rel.plt` of i386 and
+    /// other REL-based targets leaves the stubs without symbols.
+    pub fn plt_entries<'a>(&self, image: &'a [u8]) -> Vec<PltEntry<'a>> {
+        let (plt, reserved) = match self.section_by_name(".plt.sec", image) {
+            Some(plt_sec) => (plt_sec, 0),
+            None => match self.section_by_name(".plt", image) {
+                Some(plt) => (plt, 1),
+                None => return vec![],
+            },
+        };
+        let symbols = self.section_by_name(".rela.plt", image).map(|rela_plt| {
+            let names: Vec<Option<&str>> = self
+                .section_headers
+                .get(rela_plt.sh_link as usize)
+                .map(|dynsym| {
+                    self.resolved_symbols(dynsym, image)
+                        .into_iter()
+                        .map(|symbol| symbol.name)
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            self.relocations(rela_plt, image)
+                .iter()
+                .map(|rela| {
+                    names
+                        .get(self.relocation_info(rela).sym as usize)
+                        .copied()
+                        .flatten()
+                })
+                .collect()
+        });
+
+        plt_entries(plt, reserved, symbols, image)
+    }
+
+    fn report<'h>(
+        &'h self,
+        image: &'h [u8],
+    ) -> Report<'h, ElfAddr64, ProgramHeader64, SectionHeader64, DynamicEntry64, Symbol64> {
+        let symbol_tables = self
+            .section_headers
+            .iter()
+            .enumerate()
+            .filter(|(_, sh)| matches!(sh.sh_type, ShType::SymTab | ShType::DynSym))
+            .map(|(idx, sh)| (idx, self.symbols(sh, image)))
+            .collect();
+
+        Report {
+            image,
+            ei_ident: &self.ei_ident,
+            file_header: &self.file_header,
+            program_headers: &self.program_headers,
+            section_headers: &self.section_headers,
+            dynamic: self.dynamic(image),
+            symbol_tables,
+        }
+    }
+
+    /// Returns each entry of the `.stab` section paired with its name as
+    /// resolved against `.stabstr`. An empty vector is returned if the file
+    /// carries no stab debugging information.
+    pub fn stabs<'a>(&self, image: &'a [u8]) -> Vec<(Stab, Option<&'a str>)> {
+        let stab = self
+            .section_by_name(".stab", image)
+            .and_then(|sh| sh.data(image));
+        let stabstr = self
+            .section_by_name(".stabstr", image)
+            .and_then(|sh| sh.data(image))
+            .map(StrTab::new);
+
+        match (stab, stabstr) {
+            (Some(stab), Some(stabstr)) => resolve_stabs::<E>(stab, stabstr),
+            _ => vec![],
+        }
+    }
+
+    /// Parses the header of the first line number program in the
+    /// `.debug_line` section.
+    pub fn debug_line_header(&self, image: &[u8]) -> Option<DebugLineHeader> {
+        let data = self
+            .section_by_name(".debug_line", image)
+            .and_then(|sh| sh.data(image))?;
+
+        match DebugLineHeaderParser::<E>::new().parse(data) {
+            Ok(MatchStatus::Match((_, header))) => Some(header),
+            _ => None,
+        }
+    }
+
+    /// Returns the constructor addresses listed in the legacy `.ctors`
+    /// section, in the order they are run. The section is stored in reverse
+    /// order between a leading `-1` and trailing `0` sentinel, both of which
+    /// are dropped.
+    pub fn ctors(&self, image: &[u8]) -> Vec<u64> {
+        let mut ctors = self.legacy_constructor_array(".ctors", image);
+        ctors.reverse();
+        ctors
+    }
+
+    /// Returns the destructor addresses listed in the legacy `.dtors`
+    /// section, in the order they are run, with the leading `-1` and trailing
+    /// `0` sentinels dropped.
+    pub fn dtors(&self, image: &[u8]) -> Vec<u64> {
+        self.legacy_constructor_array(".dtors", image)
+    }
+
+    fn legacy_constructor_array(&self, name: &str, image: &[u8]) -> Vec<u64> {
+        let encoding = EiData::from(E::default());
+
+        match self.section_by_name(name, image) {
+            Some(sh) => sh
+                .data(image)
+                .map(|data| parse_constructor_array::<ElfAddr64>(encoding, data))
+                .unwrap_or_default(),
+            None => vec![],
+        }
+    }
+
+    /// Parses the file-scoped `aeabi` build attributes of the
+    /// SHT_ARM_ATTRIBUTES section, keyed by tag.
+    pub fn arm_attributes(
+        &self,
+        image: &[u8],
+    ) -> Option<std::collections::BTreeMap<u64, ArmAttributeValue>> {
+        self.section_headers
+            .iter()
+            .find(|sh| sh.sh_type == ShType::ArmAttributes)
+            .and_then(|sh| sh.data(image))
+            .and_then(|data| parse_arm_attributes(EiData::from(E::default()), data))
+    }
+
+    /// Queries the `.gnu.hash` bloom filter for `name`. A `false` result
+    /// means the symbol is definitely not exported, while `true` means the
+    /// hash chains must be checked. Files without a well-formed `.gnu.hash`
+    /// section always return `true`.
+    pub fn gnu_hash_maybe_contains(&self, name: &str, image: &[u8]) -> bool {
+        self.section_by_name(".gnu.hash", image)
+            .and_then(|sh| sh.data(image))
+            .and_then(|data| {
+                gnu_hash_bloom_test::<ElfAddr64>(EiData::from(E::default()), data, name)
+            })
+            .unwrap_or(true)
+    }
+
+    /// Looks up a `.dynsym` symbol by name, using the `.gnu.hash` table when
+    /// present, otherwise the `.hash` table, and otherwise scanning the
+    /// symbol table linearly.
+    pub fn lookup_dynamic_symbol(&self, image: &[u8], name: &str) -> Option<Symbol64> {
+        let dynsym = self
+            .section_headers
+            .iter()
+            .find(|sh| sh.sh_type == ShType::DynSym)?;
+        let strtab = self
+            .section_headers
+            .get(dynsym.sh_link as usize)
+            .and_then(|sh| sh.data(image))
+            .map(StrTab::new)?;
+        let hash_table = |r#type| {
+            self.section_headers
+                .iter()
+                .find(|sh| sh.sh_type == r#type)
+                .and_then(|sh| sh.data(image))
+        };
+
+        let symbols = self.symbols(dynsym, image);
+        lookup_dynamic_symbol::<ElfAddr64, _>(
+            EiData::from(E::default()),
+            hash_table(ShType::GnuHash),
+            hash_table(ShType::Hash),
+            &symbols,
+            strtab,
+            name,
+        )
+        .and_then(|idx| symbols.get(idx).copied())
+    }
+
+    /// Returns the contents of a section, transparently inflating sections
+    /// flagged SHF_COMPRESSED. Use `SectionHeader::data` for the raw on-disk
+    /// bytes, including the compression header. Returns `None` if the data
+    /// lies outside the image, cannot be decompressed or would inflate past
+    /// the header's `max_decompressed_size`.
+    pub fn section_data_decompressed<'a>(
+        &self,
+        section: &SectionHeader64,
+        image: &'a [u8],
+    ) -> Option<std::borrow::Cow<'a, [u8]>> {
+        let data = section.data(image)?;
+        if section.sh_flags() & SHF_COMPRESSED == 0 {
+            return Some(std::borrow::Cow::Borrowed(data));
+        }
+
+        match CompressionHeaderParser::<ElfAddr64, E>::new().parse(data) {
+            Ok(MatchStatus::Match((payload, header)))
+                if header.ch_size <= self.limits.max_decompressed_size =>
+            {
+                decompress_section(&header, payload).map(std::borrow::Cow::Owned)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the debug file name and CRC32 recorded in the
+    /// `.gnu_debuglink` section, if present and well formed.
+    pub fn debuglink(&self, image: &[u8]) -> Option<(String, u32)> {
+        self.section_by_name(".gnu_debuglink", image)
+            .and_then(|sh| sh.data(image))
+            .and_then(|data| parse_debuglink(EiData::from(E::default()), data))
+    }
+}
+
+impl<E: DataEncoding> ElfHeader for ElfHeader64<E> {}
+
+/// Borrows the program header table as a slice.
+impl<E> AsRef<[ProgramHeader64]> for ElfHeader64<E>
+where
+    E: DataEncoding,
+{
+    fn as_ref(&self) -> &[ProgramHeader64] {
+        self.program_headers()
+    }
+}
+
+/// Iterating over a borrowed ElfHeader64 yields each of its section headers in
+/// table order. Program headers remain accessible via the program_headers
+/// field.
+impl<'a, E> IntoIterator for &'a ElfHeader64<E>
+where
+    E: DataEncoding,
+{
+    type Item = &'a SectionHeader64;
+    type IntoIter = std::slice::Iter<'a, SectionHeader64>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.section_headers.iter()
+    }
+}
+
+impl<E> From<ElfHeader64<E>> for Vec<u8>
+where
+    SectionHeader64: Serialize<ElfAddr64, E>,
+    ProgramHeader64: Serialize<ElfAddr64, E>,
+    FileHeader<ElfAddr64>: Serialize<ElfAddr64, E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn from(src: ElfHeader64<E>) -> Self {
+        let ident_bytes = Into::<Vec<u8>>::into(src.ei_ident);
+        let fh_bytes: Vec<u8> = Serialize::<ElfAddr64, E>::serialize(&src.file_header);
+        let ph_bytes: Vec<u8> = src
+            .program_headers
+            .iter()
+            .map(|ph| Serialize::<ElfAddr64, E>::serialize(ph))
+            .into_iter()
+            .flatten()
+            .collect();
+        let sh_bytes: Vec<u8> = src
+            .section_headers
+            .iter()
+            .map(|sh| Serialize::<ElfAddr64, E>::serialize(sh))
+            .into_iter()
+            .flatten()
+            .collect();
+
+        vec![ident_bytes, fh_bytes, ph_bytes, sh_bytes]
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+}
+
+/// ElfHeaderParser implements a parser for ElfHeader types for each variant
+/// of address width from a source of a given endianness.
+pub struct ElfHeaderParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    address_width: std::marker::PhantomData<A>,
+    endianness: std::marker::PhantomData<E>,
+    options: ParseOptions,
+    limits: ParseLimits,
+}
+
+impl<A, E> ElfHeaderParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self {
+            address_width: std::marker::PhantomData,
+            endianness: std::marker::PhantomData,
+            options,
+            limits: ParseLimits::default(),
+        }
+    }
+
+    /// Replaces the default `ParseLimits`. Headers declaring more program or
+    /// section headers than `limits` allow fail with a
+    /// `FileErr::LimitExceeded` error before the section header table is
+    /// parsed, and the parsed
+    /// header applies `limits` to the tables read through it.
+    pub fn with_limits(self, limits: ParseLimits) -> Self {
+        Self { limits, ..self }
+    }
+}
+
+impl<A, E> Default for ElfHeaderParser<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    fn default() -> Self {
+        Self::with_options(ParseOptions::default())
+    }
+}
+
+impl_class_constructors!(ElfHeaderParser);
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], ElfHeader32<E>> for ElfHeaderParser<ElfAddr32, E>
+where
+    E: DataEncoding + Default + 'static,
+    FileHeaderParser<ElfAddr32, E>: Parser<'a, &'a [u8], FileHeader<ElfAddr32>>,
+    ProgramHeaderParser<ElfAddr32, E>: Parser<'a, &'a [u8], ProgramHeader32>,
+    SectionHeaderParser<ElfAddr32, E>: Parser<'a, &'a [u8], SectionHeader32>,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], ElfHeader32<E>> {
+        let ms = match EiIdentParser::with_options(self.options).parse(&input)? {
+            MatchStatus::Match((_, ei)) => {
+                FileHeaderParser::<ElfAddr32, E>::with_options(self.options)
+                    .and_then(move |fh| {
+                        let phnum = fh.phnum as usize;
+                        take_exact(ProgramHeaderParser::<ElfAddr32, E>::new(), phnum)
+                            .map(move |phs| (ei, fh, phs))
+                    })
+                    .parse(&input[0..])
+            }
+            MatchStatus::NoMatch(rem) => Ok(MatchStatus::NoMatch(rem)),
+        }?;
+
+        let limits = self.limits;
+        match ms {
+            MatchStatus::Match((_, (_, fh, _)))
+                if usize::from(fh.phnum) > limits.max_segments
+                    || usize::from(fh.shnum) > limits.max_sections =>
+            {
+                Err(FileErr::LimitExceeded.to_string())
+            }
+            MatchStatus::Match((_, (ei, fh, phs))) => {
+                let shnum = fh.shnum as usize;
+                let sh_offset = fh.sh_offset as usize;
+                take_exact(SectionHeaderParser::<ElfAddr32, E>::new(), shnum)
+                    .map(move |shs| (fh, phs.to_owned(), shs))
+                    .map(move |(fh, phs, shs)| ElfHeader32 {
+                        limits,
+                        ..ElfHeader32::new(ei, fh, phs, shs)
+                    })
+                    .parse(input.get(sh_offset..).unwrap_or_default())
+            }
+            MatchStatus::NoMatch(rem) => Ok(MatchStatus::NoMatch(rem)),
+        }
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], ElfHeader64<E>> for ElfHeaderParser<ElfAddr64, E>
+where
+    E: DataEncoding + Default + 'static,
+    FileHeaderParser<ElfAddr64, E>: Parser<'a, &'a [u8], FileHeader<ElfAddr64>>,
+    ProgramHeaderParser<ElfAddr64, E>: Parser<'a, &'a [u8], ProgramHeader64>,
+    SectionHeaderParser<ElfAddr64, E>: Parser<'a, &'a [u8], SectionHeader64>,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], ElfHeader64<E>> {
+        let ms = match EiIdentParser::with_options(self.options).parse(&input)? {
+            MatchStatus::Match((_, ei)) => {
+                FileHeaderParser::<ElfAddr64, E>::with_options(self.options)
+                    .and_then(move |fh| {
+                        let phnum = fh.phnum as usize;
+                        take_exact(ProgramHeaderParser::<ElfAddr64, E>::new(), phnum)
+                            .map(move |phs| (ei, fh, phs))
+                    })
+                    .parse(&input[0..])
+            }
+            MatchStatus::NoMatch(rem) => Ok(MatchStatus::NoMatch(rem)),
+        }?;
+
+        let limits = self.limits;
+        match ms {
+            MatchStatus::Match((_, (_, fh, _)))
+                if usize::from(fh.phnum) > limits.max_segments
+                    || usize::from(fh.shnum) > limits.max_sections =>
+            {
+                Err(FileErr::LimitExceeded.to_string())
+            }
+            MatchStatus::Match((_, (ei, fh, phs))) => {
+                let shnum = fh.shnum as usize;
+                let sh_offset = fh.sh_offset as usize;
+                take_exact(SectionHeaderParser::<ElfAddr64, E>::new(), shnum)
+                    .map(move |shs| (fh, phs.to_owned(), shs))
+                    .map(move |(fh, phs, shs)| ElfHeader64 {
+                        limits,
+                        ..ElfHeader64::new(ei, fh, phs, shs)
+                    })
+                    .parse(input.get(sh_offset..).unwrap_or_default())
+            }
+            MatchStatus::NoMatch(rem) => Ok(MatchStatus::NoMatch(rem)),
+        }
+    }
+}
+
+/// ElfHeaderBuilder assembles an ELF header from its type and machine,
+/// filling the identification and size fields from the address width and
+/// data encoding. The file header, program header table and section header
+/// table are laid out contiguously, with counts and offsets computed on
+/// `build`.
+pub struct ElfHeaderBuilder<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    endianness: std::marker::PhantomData<E>,
+    ei_ident: EiIdent,
+    file_header: FileHeader<A>,
+    program_headers: Vec<A::ProgramHeader>,
+    section_headers: Vec<A::SectionHeader>,
+}
+
+impl<A, E> ElfHeaderBuilder<A, E>
+where
+    A: AddressWidth + Default,
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    /// Returns a builder for a file of the passed type and machine. The entry
+    /// point and flags default to zero, leaving any architecture-specific
+    /// flags to be set explicitly.
+    pub fn new(r#type: Type, machine: Machine) -> Self {
+        Self {
+            endianness: std::marker::PhantomData,
+            ei_ident: EiIdent {
+                ei_class: A::CLASS,
+                ei_data: EiData::from(E::default()),
+                ei_version: EiVersion::One,
+                ei_osabi: EiOsAbi::SysV,
+                ei_abiversion: EiAbiVersion::Zero,
+                pad: [0; 7],
+            },
+            file_header: FileHeader {
+                r#type,
+                machine,
+                version: Version::One,
+                entry_point: A::default(),
+                ph_offset: A::default(),
+                sh_offset: A::default(),
+                flags: 0,
+                eh_size: A::EH_SIZE,
+                phent_size: A::PHENT_SIZE,
+                phnum: 0,
+                shent_size: A::SHENT_SIZE,
+                shnum: 0,
+                shstrndx: 0,
+            },
+            program_headers: vec![],
+            section_headers: vec![],
+        }
+    }
+}
+
+impl<A, E> ElfHeaderBuilder<A, E>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+{
+    pub fn osabi(mut self, ei_osabi: EiOsAbi) -> Self {
+        self.ei_ident.ei_osabi = ei_osabi;
+        self
+    }
+
+    pub fn entry_point(mut self, entry_point: A) -> Self {
+        self.file_header.entry_point = entry_point;
+        self
+    }
+
+    /// Sets the architecture-specific e_flags.
+    pub fn flags(mut self, flags: u32) -> Self {
+        self.file_header.flags = flags;
+        self
+    }
+
+    pub fn shstrndx(mut self, shstrndx: u16) -> Self {
+        self.file_header.shstrndx = shstrndx;
+        self
+    }
+
+    pub fn program_header(mut self, program_header: A::ProgramHeader) -> Self {
+        self.program_headers.push(program_header);
+        self
+    }
+
+    pub fn section_header(mut self, section_header: A::SectionHeader) -> Self {
+        self.section_headers.push(section_header);
+        self
+    }
+}
+
+impl<A, E> ElfHeaderBuilder<A, E>
+where
+    A: AddressWidth + std::convert::TryFrom<u64> + Copy,
+    A::ProgramHeader: ProgramHeader,
+    E: DataEncoding,
+{
+    /// Checks the headers against the expectations of the file's type and
+    /// computes the final file header. Segments may cover the file header
+    /// and header tables in their entirety, as the first PT_LOAD commonly
+    /// does, but must not partially overlap them.
+    fn layout(&self) -> Result<FileHeader<A>, ValidationError> {
+        use std::convert::TryFrom;
+
+        let has_load_segment = self
+            .program_headers
+            .iter()
+            .any(|ph| ph.p_type() == ProgramHeaderType::Load);
+        match self.file_header.r#type {
+            Type::Rel if !self.program_headers.is_empty() => {
+                return Err(ValidationError::RelocatableWithProgramHeaders)
+            }
+            Type::Exec if !has_load_segment => {
+                return Err(ValidationError::ExecutableWithoutLoadSegment)
+            }
+            _ => (),
+        }
+
+        let phnum = u16::try_from(self.program_headers.len())
+            .map_err(|_| ValidationError::TooManyHeaders)?;
+        let shnum = u16::try_from(self.section_headers.len())
+            .map_err(|_| ValidationError::TooManyHeaders)?;
+        let fh = self.file_header;
+        let eh_size = u64::from(fh.eh_size);
+        let ph_size = u64::from(phnum) * u64::from(fh.phent_size);
+        let sh_size = u64::from(shnum) * u64::from(fh.shent_size);
+        let (ph_offset, sh_offset) = (eh_size, eh_size + ph_size);
+
+        let tables = [
+            (0, eh_size),
+            (ph_offset, ph_offset + ph_size),
+            (sh_offset, sh_offset + sh_size),
+        ];
+        let partially_overlaps = |start: u64, end: u64| {
+            tables.iter().any(|&(table_start, table_end)| {
+                let overlaps = start < table_end && table_start < end;
+                let contains = start <= table_start && table_end <= end;
+                overlaps && !contains
+            })
+        };
+        if let Some(segment) = self.program_headers.iter().position(|ph| {
+            ph.p_filesz() > 0
+                && partially_overlaps(ph.p_offset(), ph.p_offset().saturating_add(ph.p_filesz()))
+        }) {
+            return Err(ValidationError::SegmentOverlapsHeaders { segment });
+        }
+
+        let offset = |present: bool, offset: u64| {
+            A::try_from(if present { offset } else { 0 })
+                .map_err(|_| ValidationError::TooManyHeaders)
+        };
+
+        Ok(FileHeader {
+            ph_offset: offset(phnum > 0, ph_offset)?,
+            sh_offset: offset(shnum > 0, sh_offset)?,
+            phnum,
+            shnum,
+            ..fh
+        })
+    }
+}
+
+impl<E> ElfHeaderBuilder<ElfAddr32, E>
+where
+    E: DataEncoding + Default + 'static,
+{
+    pub fn build(self) -> Result<ElfHeader32<E>, ValidationError> {
+        let file_header = self.layout()?;
+        Ok(ElfHeader32::new(
+            self.ei_ident,
+            file_header,
+            self.program_headers,
+            self.section_headers,
+        ))
+    }
+}
+
+impl<E> ElfHeaderBuilder<ElfAddr64, E>
+where
+    E: DataEncoding,
+{
+    pub fn build(self) -> Result<ElfHeader64<E>, ValidationError> {
+        let file_header = self.layout()?;
+        Ok(ElfHeader64::new(
+            self.ei_ident,
+            file_header,
+            self.program_headers,
+            self.section_headers,
+        ))
+    }
+}
+
+/// ElfFile holds a parsed ELF header of any class and data encoding.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ElfFile {
+    Elf32Little(ElfHeader32<LittleEndian>),
+    Elf32Big(ElfHeader32<BigEndian>),
+    Elf64Little(ElfHeader64<LittleEndian>),
+    Elf64Big(ElfHeader64<BigEndian>),
+}
+
+/// ParseLimits bounds the size of the tables a file may declare, protecting
+/// callers from crafted files that would otherwise trigger large
+/// allocations. Limits are checked against the declared counts and sizes
+/// before the corresponding tables are parsed. `ElfHeaderParser::with_limits`
+/// applies them to the header tables, and the parsed header carries them on
+/// to its symbol, note and decompression accessors. Tables without a limit of
+/// their own, such as the dynamic and relocation tables or DWARF line
+/// tables, never hold more entries than their data in the input can encode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    pub max_sections: usize,
+    pub max_segments: usize,
+    /// The maximum number of entries in any one symbol table.
+    pub max_symbols: usize,
+    /// The maximum size, in bytes, of any one note section or segment.
+    pub max_note_size: u64,
+    /// The maximum size, in bytes, a compressed section may inflate to.
+    pub max_decompressed_size: u64,
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self {
+            max_sections: 1 << 15,
+            max_segments: 1 << 12,
+            max_symbols: 1 << 24,
+            max_note_size: 1 << 24,
+            max_decompressed_size: 1 << 30,
+        }
+    }
+}
+
+/// Returns true if `input` begins with the ELF magic number. Nothing beyond
+/// the first four bytes is examined, making this a cheap pre-filter ahead of
+/// a full parse.
+pub fn is_elf(input: &[u8]) -> bool {
+    input.len() >= 4 && input[..4] == ELF_MAGIC
+}
+
+/// Parses an ELF file, selecting the header parser by the class and data
+/// encoding of its identification bytes. The default `ParseLimits` apply.
+/// See `parse_elf_with_encoding` for overriding a damaged EI_DATA byte.
+pub fn parse_elf(input: &[u8]) -> Result<ElfFile, FileErr> {
+    parse_elf_with_limits(input, ParseLimits::default())
+}
+
+/// Parses an ELF file as with `parse_elf`, returning
+/// `FileErr::LimitExceeded` if it declares tables exceeding `limits`.
+pub fn parse_elf_with_limits(input: &[u8], limits: ParseLimits) -> Result<ElfFile, FileErr> {
+    let ident = match EiIdentParser::new().parse(input) {
+        Ok(MatchStatus::Match((_, ident))) => ident,
+        _ => return Err(FileErr::InvalidFile),
+    };
+
+    parse_elf_as(input, ident.ei_class, ident.ei_data, limits)
+}
+
+/// Parses an ELF file as with `parse_elf`, but decodes it with the passed
+/// data encoding regardless of the EI_DATA byte. This is an escape hatch for
+/// damaged files whose identification disagrees with their actual encoding.
+/// The returned identification retains the EI_DATA byte as read.
+pub fn parse_elf_with_encoding(input: &[u8], encoding: EiData) -> Result<ElfFile, FileErr> {
+    let ident = match EiIdentParser::new().parse(input) {
+        Ok(MatchStatus::Match((_, ident))) => ident,
+        _ => return Err(FileErr::InvalidFile),
+    };
+
+    parse_elf_as(input, ident.ei_class, encoding, ParseLimits::default())
+}
+
+fn parse_elf_as(
+    input: &[u8],
+    class: EiClass,
+    encoding: EiData,
+    limits: ParseLimits,
+) -> Result<ElfFile, FileErr> {
+    let elf = match (class, encoding) {
+        (EiClass::ThirtyTwoBit, EiData::Little) => {
+            parse_limited_header::<ElfAddr32, LittleEndian, _>(input, limits)
+                .map(ElfFile::Elf32Little)
+        }
+        (EiClass::ThirtyTwoBit, EiData::Big) => {
+            parse_limited_header::<ElfAddr32, BigEndian, _>(input, limits).map(ElfFile::Elf32Big)
+        }
+        (EiClass::SixtyFourBit, EiData::Little) => {
+            parse_limited_header::<ElfAddr64, LittleEndian, _>(input, limits)
+                .map(ElfFile::Elf64Little)
+        }
+        (EiClass::SixtyFourBit, EiData::Big) => {
+            parse_limited_header::<ElfAddr64, BigEndian, _>(input, limits).map(ElfFile::Elf64Big)
+        }
+    }?;
+
+    match &elf {
+        ElfFile::Elf32Little(eh) => {
+            check_table_limits(&eh.program_headers, &eh.section_headers, 16, limits)
+        }
+        ElfFile::Elf32Big(eh) => {
+            check_table_limits(&eh.program_headers, &eh.section_headers, 16, limits)
+        }
+        ElfFile::Elf64Little(eh) => {
+            check_table_limits(&eh.program_headers, &eh.section_headers, 24, limits)
+        }
+        ElfFile::Elf64Big(eh) => {
+            check_table_limits(&eh.program_headers, &eh.section_headers, 24, limits)
+        }
+    }?;
+
+    Ok(elf)
+}
+
+/// Parses the header of an ELF file with `limits` applied, distinguishing a
+/// header whose table counts exceed them from an invalid file.
+fn parse_limited_header<'a, A, E, H>(input: &'a [u8], limits: ParseLimits) -> Result<H, FileErr>
+where
+    A: AddressWidth,
+    E: DataEncoding,
+    ElfHeaderParser<A, E>: Parser<'a, &'a [u8], H>,
+{
+    match ElfHeaderParser::<A, E>::new()
+        .with_limits(limits)
+        .parse(input)
+    {
+        Ok(MatchStatus::Match((_, elf_header))) => Ok(elf_header),
+        Err(error) if error == FileErr::LimitExceeded.to_string() => Err(FileErr::LimitExceeded),
+        _ => Err(FileErr::InvalidFile),
+    }
+}
+
+/// Checks the declared symbol counts and note sizes of a file's tables
+/// against `limits`, where `symbol_size` is the size of a symbol table entry
+/// for the file's class.
+fn check_table_limits<P, S>(
+    program_headers: &[P],
+    section_headers: &[S],
+    symbol_size: u64,
+    limits: ParseLimits,
+) -> Result<(), FileErr>
+where
+    P: ProgramHeader,
+    S: SectionHeader,
+{
+    let too_many_symbols = section_headers
+        .iter()
+        .filter(|sh| matches!(sh.sh_type(), ShType::SymTab | ShType::DynSym))
+        .any(|sh| sh.sh_size() / symbol_size > limits.max_symbols as u64);
+    let oversized_note = program_headers
+        .iter()
+        .filter(|ph| ph.p_type() == ProgramHeaderType::Note)
+        .map(|ph| ph.p_filesz())
+        .chain(
+            section_headers
+                .iter()
+                .filter(|sh| sh.sh_type() == ShType::Note)
+                .map(|sh| sh.sh_size()),
+        )
+        .any(|size| size > limits.max_note_size);
+
+    if too_many_symbols || oversized_note {
+        Err(FileErr::LimitExceeded)
+    } else {
+        Ok(())
+    }
+}
+
+/// Parses the section header at index `idx` directly from `file`, located at
+/// `sh_offset + idx * shent_size`, without parsing the remainder of the
+/// section header table. Returns `FileErr::InvalidFile` if `idx` is not
+/// below shnum or the header lies outside of `file`.
+pub fn parse_section_header(
+    file: &[u8],
+    fh: &FileHeader<ElfAddr64>,
+    data: EiData,
+    idx: usize,
+) -> Result<SectionHeader64, FileErr> {
+    let input = Some(idx)
+        .filter(|&idx| idx < usize::from(fh.shnum))
+        .and_then(|idx| idx.checked_mul(usize::from(fh.shent_size)))
+        .and_then(|offset| offset.checked_add(std::convert::TryFrom::try_from(fh.sh_offset).ok()?))
+        .and_then(|start| file.get(start..))
+        .ok_or(FileErr::InvalidFile)?;
+
+    let section_header = match data {
+        EiData::Little => SectionHeaderParser::<ElfAddr64, LittleEndian>::new().parse(input),
+        EiData::Big => SectionHeaderParser::<ElfAddr64, BigEndian>::new().parse(input),
+    };
+    match section_header {
+        Ok(MatchStatus::Match((_, section_header))) => Ok(section_header),
+        _ => Err(FileErr::InvalidFile),
+    }
+}
+
+/// Leniently parses the identification and file header of `input`,
+/// returning a description of each nonstandard value that doesn't prevent
+/// parsing, such as a zero eh_size or an unrecognized OS/ABI. Input without a
+/// parseable header has no warnings to report.
+pub fn warnings(input: &[u8]) -> Vec<String> {
+    let options = ParseOptions { strict: false };
+    let ident = match EiIdentParser::with_options(options).parse(input) {
+        Ok(MatchStatus::Match((_, ident))) => ident,
+        _ => return vec![],
+    };
+
+    match (ident.ei_class, ident.ei_data) {
+        (EiClass::ThirtyTwoBit, EiData::Little) => file_header_warnings(
+            input,
+            ident,
+            FileHeaderParser::<ElfAddr32, LittleEndian>::with_options(options).parse(input),
+            SectionHeaderParser::<ElfAddr32, LittleEndian>::new(),
+        ),
+        (EiClass::ThirtyTwoBit, EiData::Big) => file_header_warnings(
+            input,
+            ident,
+            FileHeaderParser::<ElfAddr32, BigEndian>::with_options(options).parse(input),
+            SectionHeaderParser::<ElfAddr32, BigEndian>::new(),
+        ),
+        (EiClass::SixtyFourBit, EiData::Little) => file_header_warnings(
+            input,
+            ident,
+            FileHeaderParser::<ElfAddr64, LittleEndian>::with_options(options).parse(input),
+            SectionHeaderParser::<ElfAddr64, LittleEndian>::new(),
+        ),
+        (EiClass::SixtyFourBit, EiData::Big) => file_header_warnings(
+            input,
+            ident,
+            FileHeaderParser::<ElfAddr64, BigEndian>::with_options(options).parse(input),
+            SectionHeaderParser::<ElfAddr64, BigEndian>::new(),
+        ),
+    }
+}
+
+fn file_header_warnings<'a, A, P, S>(
+    input: &'a [u8],
+    ident: EiIdent,
+    file_header: parcel::ParseResult<'a, &'a [u8], FileHeader<A>>,
+    section_header_parser: P,
+) -> Vec<String>
+where
+    A: Into<u64> + Copy,
+    P: Parser<'a, &'a [u8], S>,
+    S: SectionHeader,
+{
+    let file_header = match file_header {
+        Ok(MatchStatus::Match((_, file_header))) => file_header,
+        _ => return vec![],
+    };
+    let mut warnings = vec![];
+
+    if matches!(ident.ei_version, EiVersion::Unknown(_)) {
+        warnings.push("unknown identification version".to_string());
+    }
+    if matches!(ident.ei_osabi, EiOsAbi::Unknown(_)) {
+        warnings.push("unknown OS/ABI".to_string());
+    }
+    if matches!(file_header.machine, Machine::Other(_)) {
+        warnings.push("unknown machine".to_string());
+    }
+    if matches!(file_header.version, Version::Other(_)) {
+        warnings.push("unknown file version".to_string());
+    }
+    if let Err(err) = file_header.validate_eh_size(ident.ei_class) {
+        warnings.push(format!("{:?}", err));
+    }
+    if let Err(err) = file_header.validate_shstrndx() {
+        warnings.push(format!("{:?}", err));
+    }
+    if file_header.phnum == 0 && file_header.r#type != Type::Rel {
+        warnings.push(format!(
+            "{} file has no program headers",
+            file_header.r#type
+        ));
+    }
+    if file_header.shnum == 0 {
+        warnings.push("file has no section headers".to_string());
+    }
+    if file_header.r#type == Type::Exec && file_header.entry_point.into() == 0 {
+        warnings.push("executable has an entry point of 0".to_string());
+    }
+
+    let null_section = std::convert::TryFrom::try_from(file_header.sh_offset.into())
+        .ok()
+        .filter(|&offset: &usize| offset != 0 && file_header.shnum != 0)
+        .and_then(|offset| input.get(offset..))
+        .and_then(|table| match section_header_parser.parse(table) {
+            Ok(MatchStatus::Match((_, null))) => Some(null),
+            _ => None,
+        });
+    if let Err(err) = validate_null_section(&file_header, null_section.as_slice()) {
+        warnings.push(format!("{:?}", err));
+    }
+
+    warnings
+}
+
+/// Parses an ELF file as with `parse_elf`, additionally returning the bytes
+/// of `input` past the file's declared extent: the furthest end of its
+/// header tables, sections and segments. The trailer is the header's
+/// `trailing_data`.
+pub fn parse_elf_with_trailer(input: &[u8]) -> Result<(ElfFile, &[u8]), FileErr> {
+    let elf = parse_elf(input)?;
+    let trailer = match &elf {
+        ElfFile::Elf32Little(eh) => eh.trailing_data(input),
+        ElfFile::Elf32Big(eh) => eh.trailing_data(input),
+        ElfFile::Elf64Little(eh) => eh.trailing_data(input),
+        ElfFile::Elf64Big(eh) => eh.trailing_data(input),
+    };
+
+    Ok((elf, trailer))
+}
+
+/// LoadableElf holds the portions of an ELF file needed to load it into
+/// memory: the identification, file header and program headers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LoadableElf {
+    Elf32 {
+        ei_ident: EiIdent,
+        file_header: FileHeader<ElfAddr32>,
+        program_headers: Vec<ProgramHeader32>,
+    },
+    Elf64 {
+        ei_ident: EiIdent,
+        file_header: FileHeader<ElfAddr64>,
+        program_headers: Vec<ProgramHeader64>,
+    },
+}
+
+impl LoadableElf {
+    /// Returns a LoadSegment for each PT_LOAD segment, in program header
+    /// order.
+    pub fn load_map(&self) -> Vec<LoadSegment> {
+        match self {
+            Self::Elf32 {
+                program_headers, ..
+            } => load_map(program_headers),
+            Self::Elf64 {
+                program_headers, ..
+            } => load_map(program_headers),
+        }
+    }
+}
+
+/// Parses the file header and the program header table found at phoff,
+/// without reading the section header table. This allows files with
+/// missing or corrupt section headers to still be loaded.
+pub fn parse_loadable(input: &[u8]) -> Result<LoadableElf, FileErr> {
+    let ei_ident = match EiIdentParser::new().parse(input) {
+        Ok(MatchStatus::Match((_, ident))) => ident,
+        _ => return Err(FileErr::InvalidFile),
+    };
+
+    match (ei_ident.ei_class, ei_ident.ei_data) {
+        (EiClass::ThirtyTwoBit, EiData::Little) => {
+            parse_loadable_headers::<ElfAddr32, LittleEndian, _>(input).map(
+                |(file_header, program_headers)| LoadableElf::Elf32 {
+                    ei_ident,
+                    file_header,
+                    program_headers,
+                },
+            )
+        }
+        (EiClass::ThirtyTwoBit, EiData::Big) => parse_loadable_headers::<ElfAddr32, BigEndian, _>(
+            input,
+        )
+        .map(|(file_header, program_headers)| LoadableElf::Elf32 {
+            ei_ident,
+            file_header,
+            program_headers,
+        }),
+        (EiClass::SixtyFourBit, EiData::Little) => {
+            parse_loadable_headers::<ElfAddr64, LittleEndian, _>(input).map(
+                |(file_header, program_headers)| LoadableElf::Elf64 {
+                    ei_ident,
+                    file_header,
+                    program_headers,
+                },
+            )
+        }
+        (EiClass::SixtyFourBit, EiData::Big) => parse_loadable_headers::<ElfAddr64, BigEndian, _>(
+            input,
+        )
+        .map(|(file_header, program_headers)| LoadableElf::Elf64 {
+            ei_ident,
+            file_header,
+            program_headers,
+        }),
+    }
+}
+
+/// Parses a file header followed by the phnum program headers located at
+/// its phoff.
+fn parse_loadable_headers<'a, A, E, P>(input: &'a [u8]) -> Result<(FileHeader<A>, Vec<P>), FileErr>
+where
+    A: AddressWidth + Into<u64> + Copy,
+    E: DataEncoding,
+    FileHeaderParser<A, E>: Parser<'a, &'a [u8], FileHeader<A>>,
+    ProgramHeaderParser<A, E>: Parser<'a, &'a [u8], P>,
+{
+    use std::convert::TryFrom;
+
+    let file_header = match FileHeaderParser::<A, E>::default().parse(input) {
+        Ok(MatchStatus::Match((_, fh))) => fh,
+        _ => return Err(FileErr::InvalidFile),
+    };
+
+    let program_header_table = usize::try_from(file_header.ph_offset.into())
+        .ok()
+        .and_then(|ph_offset| input.get(ph_offset..))
+        .ok_or(FileErr::InvalidFile)?;
+
+    match take_exact(
+        ProgramHeaderParser::<A, E>::new(),
+        file_header.phnum as usize,
+    )
+    .parse(program_header_table)
+    {
+        Ok(MatchStatus::Match((_, program_headers))) => Ok((file_header, program_headers)),
+        _ => Err(FileErr::InvalidFile),
+    }
+}
+
+/// LoadedElf is an ELF image read from memory, such as the vDSO, rather than
+/// from disk. Its headers are parsed in place, but section and segment
+/// contents are located by virtual address instead of file offset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoadedElf<'a> {
+    pub image: &'a [u8],
+    /// The virtual address, in the file's address space, that the first byte
+    /// of `image` is mapped at.
+    pub load_bias: u64,
+    pub elf: ElfFile,
+}
+
+impl<'a> LoadedElf<'a> {
+    /// Returns the in-memory contents of a section, located by sh_addr.
+    pub fn section_data<S: SectionHeader>(&self, section: &S) -> Option<&'a [u8]> {
+        if section.sh_addr() == 0 {
+            return None;
+        }
+        self.data_at(section.sh_addr(), section.sh_size())
+    }
+
+    /// Returns the in-memory contents of a segment, located by p_vaddr and
+    /// spanning p_memsz.
+    pub fn segment_data<P: ProgramHeader>(&self, segment: &P) -> Option<&'a [u8]> {
+        self.data_at(segment.p_vaddr(), segment.p_memsz())
+    }
+
+    fn data_at(&self, vaddr: u64, size: u64) -> Option<&'a [u8]> {
+        use std::convert::TryFrom;
+
+        let start = usize::try_from(vaddr.checked_sub(self.load_bias)?).ok()?;
+        let end = start.checked_add(usize::try_from(size).ok()?)?;
+        self.image.get(start..end)
+    }
+}
+
+/// Parses an ELF image that has been mapped into memory, where `load_bias`
+/// is the virtual address the start of `image` corresponds to. The header
+/// tables are expected at their file offsets from the start of the image, as
+/// is the case for the vDSO.
+pub fn parse_loaded(image: &[u8], load_bias: u64) -> Result<LoadedElf<'_>, FileErr> {
+    parse_elf(image).map(|elf| LoadedElf {
+        image,
+        load_bias,
+        elf,
+    })
+}
+
+/// ParsedElf pairs a parsed ELF file with the image it was parsed from,
+/// allowing it to be rendered as a readelf-style report through its
+/// `Display` implementation.
+#[derive(Debug, Clone)]
+pub struct ParsedElf<'a> {
+    pub image: &'a [u8],
+    pub elf: ElfFile,
+    /// Section names, resolved against the section header string table on
+    /// the first call to `section_name`. Modifying `elf` after this point
+    /// leaves the cache stale.
+    names_cache: std::sync::OnceLock<Vec<Option<&'a str>>>,
+}
+
+impl<'a> ParsedElf<'a> {
+    pub fn new(image: &'a [u8], elf: ElfFile) -> Self {
+        Self {
+            image,
+            elf,
+            names_cache: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Parses the passed image.
+    pub fn parse(image: &'a [u8]) -> Result<Self, FileErr> {
+        parse_elf(image).map(|elf| Self::new(image, elf))
+    }
+
+    /// Returns the name of the section at the passed index. Names aren't
+    /// resolved while parsing, instead every name is resolved once, on the
+    /// first call, and cached for subsequent lookups.
+    pub fn section_name(&self, idx: usize) -> Option<&'a str> {
+        let image = self.image;
+        let names = self.names_cache.get_or_init(|| match &self.elf {
+            ElfFile::Elf32Little(eh) => {
+                section_names(&eh.section_headers, eh.file_header.shstrndx, image)
+            }
+            ElfFile::Elf32Big(eh) => {
+                section_names(&eh.section_headers, eh.file_header.shstrndx, image)
+            }
+            ElfFile::Elf64Little(eh) => {
+                section_names(&eh.section_headers, eh.file_header.shstrndx, image)
+            }
+            ElfFile::Elf64Big(eh) => {
+                section_names(&eh.section_headers, eh.file_header.shstrndx, image)
+            }
+        });
+
+        names.get(idx).copied().flatten()
+    }
+}
+
+impl<'a> PartialEq for ParsedElf<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.image == other.image && self.elf == other.elf
+    }
+}
+
+impl<'a> std::fmt::Display for ParsedElf<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.elf {
+            ElfFile::Elf32Little(eh) => eh.report(self.image).fmt(f),
+            ElfFile::Elf32Big(eh) => eh.report(self.image).fmt(f),
+            ElfFile::Elf64Little(eh) => eh.report(self.image).fmt(f),
+            ElfFile::Elf64Big(eh) => eh.report(self.image).fmt(f),
+        }
+    }
+}
+
+/// AnyFileHeader holds a file header of either class, with the address width
+/// selected at runtime from the identification bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnyFileHeader {
+    Elf32(FileHeader<ElfAddr32>),
+    Elf64(FileHeader<ElfAddr64>),
+}
+
+/// AutoElfParser parses a file of any class and data encoding, reading the
+/// identification bytes first to select the underlying parsers at runtime.
+/// It produces either an `AnyFileHeader` or a full `ParsedElf`, the latter
+/// through `parse_elf` and so subject to the default `ParseLimits`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct AutoElfParser;
+
+impl AutoElfParser {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<'a> parcel::Parser<'a, &'a [u8], AnyFileHeader> for AutoElfParser {
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], AnyFileHeader> {
+        let ident = match EiIdentParser::new().parse(input)? {
+            MatchStatus::Match((_, ident)) => ident,
+            MatchStatus::NoMatch(rem) => return Ok(MatchStatus::NoMatch(rem)),
+        };
+
+        match (ident.ei_class, ident.ei_data) {
+            (EiClass::ThirtyTwoBit, EiData::Little) => FileHeaderParser::elf32_le()
+                .map(AnyFileHeader::Elf32)
+                .parse(input),
+            (EiClass::ThirtyTwoBit, EiData::Big) => FileHeaderParser::elf32_be()
+                .map(AnyFileHeader::Elf32)
+                .parse(input),
+            (EiClass::SixtyFourBit, EiData::Little) => FileHeaderParser::elf64_le()
+                .map(AnyFileHeader::Elf64)
+                .parse(input),
+            (EiClass::SixtyFourBit, EiData::Big) => FileHeaderParser::elf64_be()
+                .map(AnyFileHeader::Elf64)
+                .parse(input),
+        }
+    }
+}
+
+/// The whole input is consumed, as the parsed file retains it as its image.
+impl<'a> parcel::Parser<'a, &'a [u8], ParsedElf<'a>> for AutoElfParser {
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], ParsedElf<'a>> {
+        match ParsedElf::parse(input) {
+            Ok(parsed) => Ok(MatchStatus::Match((&input[input.len()..], parsed))),
+            Err(_) => Ok(MatchStatus::NoMatch(input)),
+        }
+    }
+}
+
+/// ArHeader represents the 60-byte header preceding each member of an `ar`
+/// archive. Only the fields needed to locate members are retained.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArHeader {
+    /// The raw name field with trailing spaces removed. GNU archives
+    /// terminate short names with `/` and reference long names as `/offset`
+    /// into the `//` member.
+    pub name: String,
+    pub size: usize,
+}
+
+/// ArHeaderParser implements a parser for a single `ar` member header.
+#[derive(Default)]
+pub struct ArHeaderParser;
+
+impl<'a> parcel::Parser<'a, &'a [u8], ArHeader> for ArHeaderParser {
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], ArHeader> {
+        use parcel::parsers::byte::any_byte;
+
+        let fields = parcel::join(
+            parcel::take_n(any_byte(), 16),
+            parcel::right(parcel::join(
+                // mtime, uid, gid and mode
+                parcel::take_n(any_byte(), 32),
+                parcel::left(parcel::join(
+                    parcel::take_n(any_byte(), 10),
+                    expect_bytes(b"`\n"),
+                )),
+            )),
+        )
+        .parse(input)?;
+
+        match fields {
+            MatchStatus::Match((rem, (name, size))) => {
+                let name = std::str::from_utf8(&name).map(|name| name.trim_end().to_string());
+                let size = std::str::from_utf8(&size)
+                    .ok()
+                    .and_then(|size| size.trim_end().parse().ok());
+
+                match (name, size) {
+                    (Ok(name), Some(size)) => {
+                        Ok(MatchStatus::Match((rem, ArHeader { name, size })))
+                    }
+                    _ => Ok(MatchStatus::NoMatch(input)),
+                }
+            }
+            MatchStatus::NoMatch(_) => Ok(MatchStatus::NoMatch(input)),
+        }
+    }
+}
+
+/// Parses each ELF member of an `ar` archive, such as a static library,
+/// returning the member's name alongside its parsed contents. The symbol
+/// table and GNU extended name table members are consumed rather than
+/// returned, with the latter used to resolve long member names.
+pub fn parse_archive(input: &[u8]) -> Result<Vec<(String, ParsedElf<'_>)>, FileErr> {
+    let mut rem = match expect_bytes(b"!<arch>\n").parse(input) {
+        Ok(MatchStatus::Match((rem, _))) => rem,
+        _ => return Err(FileErr::InvalidFile),
+    };
+    let mut extended_names: Option<&[u8]> = None;
+    let mut members = vec![];
+
+    while !rem.is_empty() {
+        let (data, header) = match ArHeaderParser.parse(rem) {
+            Ok(MatchStatus::Match(m)) => m,
+            _ => return Err(FileErr::InvalidFile),
+        };
+        let member = data.get(..header.size).ok_or(FileErr::InvalidFile)?;
+        // members are aligned to an even offset, the padding byte may be
+        // omitted from the final member.
+        rem = data.get(header.size + header.size % 2..).unwrap_or(&[]);
+
+        match header.name.as_str() {
+            "/" | "/SYM64/" => continue,
+            "//" => extended_names = Some(member),
+            name => {
+                let name = match name.strip_prefix('/') {
+                    Some(offset) => offset
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|offset| extended_names?.get(offset..))
+                        .and_then(|names| names.split(|&b| b == b'\n').next())
+                        .and_then(|name| std::str::from_utf8(name).ok())
+                        .map(|name| name.trim_end_matches('/'))
+                        .ok_or(FileErr::InvalidFile)?,
+                    None => name.trim_end_matches('/'),
+                };
+
+                members.push((name.to_string(), ParsedElf::parse(member)?));
+            }
+        }
+    }
+
+    Ok(members)
+}
+
+/// Report collects the parsed components of a file, independent of its class
+/// and data encoding, for rendering in the style of `readelf -a`.
+struct Report<'h, A, P, S, D, Y> {
+    image: &'h [u8],
+    ei_ident: &'h EiIdent,
+    file_header: &'h FileHeader<A>,
+    program_headers: &'h [P],
+    section_headers: &'h [S],
+    dynamic: Vec<D>,
+    symbol_tables: Vec<(usize, Vec<Y>)>,
+}
+
+impl<'h, A, P, S, D, Y> Report<'h, A, P, S, D, Y>
+where
+    A: Into<u64> + Copy,
+    P: ProgramHeader,
+    S: SectionHeader,
+    D: DynamicEntry,
+    Y: Symbol,
+{
+    /// The number of hex digits used to render an address.
+    fn address_digits(&self) -> usize {
+        match self.ei_ident.ei_class {
+            EiClass::ThirtyTwoBit => 8,
+            EiClass::SixtyFourBit => 16,
+        }
+    }
+
+    fn section_name(&self, section: &S) -> &'h str {
+        shstrtab(self.section_headers, self.file_header.shstrndx, self.image)
+            .and_then(|strtab| strtab.get(section.sh_name() as usize))
+            .unwrap_or("")
+    }
+
+    fn fmt_file_header(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ei = self.ei_ident;
+        let fh = self.file_header;
+
+        writeln!(
+            f,
+            "ELF Header:
+  Class:                             {}
+  Data:                              {}
+  Version:                           {}
+  OS/ABI:                            {}
+  ABI Version:                       {}
+  Type:                              {}
+  Machine:                           {}
+  Version:                           {}
+  Entry point address:               0x{:x}
+  Start of program headers:          {} (bytes into file)
+  Start of section headers:          {} (bytes into file)
+  Flags:                             0x{:x}
+  Size of this header:               {} (bytes)
+  Size of program headers:           {} (bytes)
+  Number of program headers:         {}
+  Size of section headers:           {} (bytes)
+  Number of section headers:         {}
+  Section header string table index: {}",
+            ei.ei_class,
+            ei.ei_data,
+            ei.ei_version,
+            ei.ei_osabi,
+            ei.ei_abiversion,
+            fh.r#type,
+            fh.machine,
+            fh.version,
+            fh.entry_point.into(),
+            fh.ph_offset.into(),
+            fh.sh_offset.into(),
+            fh.flags,
+            fh.eh_size,
+            fh.phent_size,
+            fh.phnum,
+            fh.shent_size,
+            fh.shnum,
+            fh.shstrndx
+        )
+    }
+
+    fn fmt_section_headers(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.address_digits();
+
+        writeln!(f, "\nSection Headers:")?;
+        writeln!(
+            f,
+            "  [Nr] {:<17} {:<15} {:<w$} {:<8} {:<w$} {:<w$} {:>3} {:>3} {:>5}",
+            "Name",
+            "Type",
+            "Address",
+            "Off",
+            "Size",
+            "EntSize",
+            "Lk",
+            "Inf",
+            "Al",
+            w = digits
+        )?;
+        for (idx, sh) in self.section_headers.iter().enumerate() {
+            writeln!(
+                f,
+                "  [{:>2}] {:<17} {:<15} {:0w$x} {:08x} {:0w$x} {:0w$x} {:>3} {:>3} {:>5}",
+                idx,
+                self.section_name(sh),
+                sh.sh_type().type_str(),
+                sh.sh_addr(),
+                sh.sh_offset(),
+                sh.sh_size(),
+                sh.sh_entsize(),
+                sh.sh_link(),
+                sh.sh_info(),
+                sh.sh_addr_align(),
+                w = digits
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_program_headers(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.address_digits();
+
+        if self.program_headers.is_empty() {
+            return writeln!(f, "\nThere are no program headers in this file.");
+        }
+
+        writeln!(f, "\nProgram Headers:")?;
+        writeln!(
+            f,
+            "  {:<14} {:<w$} {:<w$} {:<w$} {:<w$} {:<w$} Flg Align",
+            "Type",
+            "Offset",
+            "VirtAddr",
+            "PhysAddr",
+            "FileSiz",
+            "MemSiz",
+            w = digits + 2
+        )?;
+        for ph in self.program_headers {
+            writeln!(
+                f,
+                "  {:<14} 0x{:0w$x} 0x{:0w$x} 0x{:0w$x} 0x{:0w$x} 0x{:0w$x} {} 0x{:x}",
+                ph.p_type().type_str(),
+                ph.p_offset(),
+                ph.p_vaddr(),
+                ph.p_paddr(),
+                ph.p_filesz(),
+                ph.p_memsz(),
+                Permissions::from(ph.p_flags()),
+                ph.p_align(),
+                w = digits
+            )?;
+        }
+
+        writeln!(f, "\n Section to Segment mapping:")?;
+        writeln!(f, "  Segment Sections...")?;
+        for (idx, ph) in self.program_headers.iter().enumerate() {
+            let names: Vec<&str> = self
+                .section_headers
+                .iter()
+                .filter(|sh| segment_contains_section(ph, *sh))
+                .map(|sh| self.section_name(sh))
+                .collect();
+            writeln!(f, "   {:02}     {}", idx, names.join(" "))?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_dynamic(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.address_digits();
+        let strtab = dynamic_strtab(
+            self.program_headers,
+            self.section_headers,
+            &self.dynamic,
+            self.image,
+        );
+        let entries: Vec<&D> = match self
+            .dynamic
+            .iter()
+            .position(|entry| entry.tag() == Some(DynamicTag::Null))
+        {
+            Some(null) => self.dynamic[..=null].iter().collect(),
+            None => self.dynamic.iter().collect(),
+        };
+
+        if entries.is_empty() {
+            return writeln!(f, "\nThere is no dynamic section in this file.");
+        }
+
+        writeln!(f, "\nDynamic section contains {} entries:", entries.len())?;
+        writeln!(
+            f,
+            "  {:<w$} {:<20} Name/Value",
+            "Tag",
+            "Type",
+            w = digits + 2
+        )?;
+        for entry in entries {
+            let name = |label: &str| match strtab.and_then(|t| t.get(entry.d_val() as usize)) {
+                Some(name) => format!("{}: [{}]", label, name),
+                None => format!("0x{:x}", entry.d_val()),
+            };
+            let (tag, value) = match entry.tag() {
+                Some(DynamicTag::Needed) => ("(NEEDED)".to_string(), name("Shared library")),
+                Some(DynamicTag::SoName) => ("(SONAME)".to_string(), name("Library soname")),
+                Some(DynamicTag::RPath) => ("(RPATH)".to_string(), name("Library rpath")),
+                Some(DynamicTag::RunPath) => ("(RUNPATH)".to_string(), name("Library runpath")),
+                Some(tag) => (format!("({})", tag), format!("0x{:x}", entry.d_val())),
+                None => ("(<unknown>)".to_string(), format!("0x{:x}", entry.d_val())),
+            };
+
+            writeln!(
+                f,
+                " 0x{:0w$x} {:<20} {}",
+                entry.d_tag(),
+                tag,
+                value,
+                w = digits
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn fmt_symbols(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let digits = self.address_digits();
+
+        for (idx, symbols) in self.symbol_tables.iter() {
+            let section = &self.section_headers[*idx];
+            let strtab = self
+                .section_headers
+                .get(section.sh_link() as usize)
+                .and_then(|sh| sh.data(self.image))
+                .map(StrTab::new);
+
+            writeln!(
+                f,
+                "\nSymbol table '{}' contains {} entries:",
+                self.section_name(section),
+                symbols.len()
+            )?;
+            writeln!(
+                f,
+                "   Num: {:<w$}  Size Type    Bind   Vis       Ndx Name",
+                "Value",
+                w = digits
+            )?;
+            for (num, symbol) in symbols.iter().enumerate() {
+                let ndx = match symbol.special_section_index() {
+                    Some(SpecialSectionIndex::Undefined) => "UND".to_string(),
+                    Some(SpecialSectionIndex::Absolute) => "ABS".to_string(),
+                    Some(SpecialSectionIndex::Common) => "COM".to_string(),
+                    _ => symbol.st_shndx().to_string(),
+                };
+
+                let line = format!(
+                    "{:>6}: {:0w$x} {:>5} {:<7} {:<6} {:<9} {:>3} {}",
+                    num,
+                    symbol.st_value(),
+                    symbol.st_size(),
+                    symbol
+                        .symbol_type()
+                        .map_or_else(|| "<unknown>".to_string(), |t| t.to_string()),
+                    symbol
+                        .binding()
+                        .map_or_else(|| "<unknown>".to_string(), |b| b.to_string()),
+                    symbol.visibility().to_string(),
+                    ndx,
+                    strtab
+                        .and_then(|t| t.get(symbol.st_name() as usize))
+                        .unwrap_or(""),
+                    w = digits
+                );
+                writeln!(f, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'h, A, P, S, D, Y> std::fmt::Display for Report<'h, A, P, S, D, Y>
+where
+    A: Into<u64> + Copy,
+    P: ProgramHeader,
+    S: SectionHeader,
+    D: DynamicEntry,
+    Y: Symbol,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_file_header(f)?;
+        self.fmt_section_headers(f)?;
+        self.fmt_program_headers(f)?;
+        self.fmt_dynamic(f)?;
+        self.fmt_symbols(f)
+    }
+}
+
+/// Returns true if a section lies within a segment, by file range for
+/// sections occupying file space or by address range for NOBITS sections.
+fn segment_contains_section<P: ProgramHeader, S: SectionHeader>(segment: &P, section: &S) -> bool {
+    if section.sh_type() == ShType::Null || section.sh_size() == 0 {
+        return false;
+    }
+
+    let (start, size, segment_start, segment_size) = if section.sh_type() == ShType::NoBits {
+        if section.sh_addr() == 0 {
+            return false;
+        }
+        (
+            section.sh_addr(),
+            section.sh_size(),
+            segment.p_vaddr(),
+            segment.p_memsz(),
+        )
+    } else {
+        (
+            section.sh_offset(),
+            section.sh_size(),
+            segment.p_offset(),
+            segment.p_filesz(),
+        )
+    };
+
+    match (
+        start.checked_add(size),
+        segment_start.checked_add(segment_size),
+    ) {
+        (Some(end), Some(segment_end)) => start >= segment_start && end <= segment_end,
+        _ => false,
+    }
+}
+
+/// GRP_COMDAT flags a section group as a COMDAT group, of which the linker
+/// keeps only one copy per signature.
+pub const GRP_COMDAT: u32 = 0x01;
+
+/// SectionGroup represents the contents of a SHT_GROUP section: a flags word
+/// followed by the section header indices of the group's members.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SectionGroup {
+    pub flags: u32,
+    pub members: Vec<u32>,
+}
+
+impl SectionGroup {
+    /// Returns true if the group is flagged GRP_COMDAT.
+    pub fn is_comdat(&self) -> bool {
+        self.flags & GRP_COMDAT != 0
+    }
+}
+
+/// SectionGroupParser implements a parser for the full contents of a
+/// SHT_GROUP section of a given endianness.
+pub struct SectionGroupParser<E>
+where
+    E: DataEncoding,
+{
+    endianness: std::marker::PhantomData<E>,
+}
+
+impl<E> SectionGroupParser<E>
+where
+    E: DataEncoding,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for SectionGroupParser<E>
+where
+    E: DataEncoding,
+{
+    fn default() -> Self {
+        Self {
+            endianness: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], SectionGroup> for SectionGroupParser<E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], SectionGroup> {
+        let encoding = EiData::from(E::default());
+        let member_count = input.len().saturating_sub(4) / 4;
+
+        parcel::join(
+            match_u32(encoding),
+            take_exact(match_u32(encoding), member_count),
+        )
+        .map(|(flags, members)| SectionGroup { flags, members })
+        .parse(input)
+    }
+}
+
+/// N_UNDF marks the header entry of each compilation unit in a `.stab`
+/// section. These entries carry the size of the unit's string table in
+/// n_value.
+const N_UNDF: u8 = 0x00;
+
+/// Stab represents a single entry of the legacy `.stab` debugging format. The
+/// entry layout is identical for both 32-bit and 64-bit files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stab {
+    pub n_strx: u32,
+    pub n_type: u8,
+    pub n_other: u8,
+    pub n_desc: u16,
+    pub n_value: u32,
+}
+
+/// StabParser implements a parser for a single `.stab` entry from a source of
+/// a given endianness.
+pub struct StabParser<E>
+where
+    E: DataEncoding,
+{
+    endianness: std::marker::PhantomData<E>,
+}
+
+impl<E> StabParser<E>
+where
+    E: DataEncoding,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for StabParser<E>
+where
+    E: DataEncoding,
+{
+    fn default() -> Self {
+        Self {
+            endianness: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], Stab> for StabParser<E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], Stab> {
+        use parcel::parsers::byte::any_byte;
+        let encoding = EiData::from(E::default());
+
+        parcel::join(
+            match_u32(encoding),
+            parcel::join(
+                any_byte(),
+                parcel::join(
+                    any_byte(),
+                    parcel::join(match_u16(encoding), match_u32(encoding)),
+                ),
+            ),
+        )
+        .map(|(n_strx, (n_type, (n_other, (n_desc, n_value))))| Stab {
+            n_strx,
+            n_type,
+            n_other,
+            n_desc,
+            n_value,
+        })
+        .parse(input)
+    }
+}
+
+/// DwarfString represents a DWARF string value, either held inline or as an
+/// offset into a string section that isn't resolved here.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DwarfString {
+    Inline(String),
+    /// An offset into `.debug_str`.
+    Strp(u64),
+    /// An offset into `.debug_line_str`.
+    LineStrp(u64),
+}
+
+/// DebugLineFileEntry represents a single entry of a `.debug_line` file name
+/// table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugLineFileEntry {
+    pub name: DwarfString,
+    /// An index into the include directories of the header.
+    pub directory_index: u64,
+    pub modification_time: u64,
+    pub length: u64,
+    /// The MD5 digest of the file, only present from version 5.
+    pub md5: Option<[u8; 16]>,
+}
+
+/// DebugLineHeader represents the header of a DWARF `.debug_line` line
+/// number program, as defined for DWARF versions 2 through 5, up to and
+/// including the directory and file name tables.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugLineHeader {
+    pub unit_length: u64,
+    /// Set when the unit uses the 64-bit DWARF format, widening unit_length
+    /// and header_length to eight bytes.
+    pub is_dwarf64: bool,
+    pub version: u16,
+    /// The address size, only present from version 5.
+    pub address_size: Option<u8>,
+    /// The segment selector size, only present from version 5.
+    pub segment_selector_size: Option<u8>,
+    pub header_length: u64,
+    pub minimum_instruction_length: u8,
+    /// Only present from version 4, implicitly 1 for earlier versions.
+    pub maximum_operations_per_instruction: u8,
+    pub default_is_stmt: bool,
+    pub line_base: i8,
+    pub line_range: u8,
+    pub opcode_base: u8,
+    pub standard_opcode_lengths: Vec<u8>,
+    pub include_directories: Vec<DwarfString>,
+    pub file_names: Vec<DebugLineFileEntry>,
+}
+
+/// DebugLineHeaderParser implements a parser for a `.debug_line` unit header
+/// from a source of a given endianness.
+pub struct DebugLineHeaderParser<E>
+where
+    E: DataEncoding,
+{
+    endianness: std::marker::PhantomData<E>,
+}
+
+impl<E> DebugLineHeaderParser<E>
+where
+    E: DataEncoding,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<E> Default for DebugLineHeaderParser<E>
+where
+    E: DataEncoding,
+{
+    fn default() -> Self {
+        Self {
+            endianness: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'a, E> parcel::Parser<'a, &'a [u8], DebugLineHeader> for DebugLineHeaderParser<E>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    fn parse(&self, input: &'a [u8]) -> parcel::ParseResult<'a, &'a [u8], DebugLineHeader> {
+        use parcel::parsers::byte::any_byte;
+        let encoding = EiData::from(E::default());
+
+        let header = parcel::one_of(vec![
+            parcel::right(parcel::join(
+                expect_u32(encoding, 0xffff_ffff),
+                match_u64(encoding),
+            ))
+            .map(|unit_length| (true, unit_length)),
+            match_u32(encoding).map(|unit_length| (false, u64::from(unit_length))),
+        ])
+        .and_then(move |(is_dwarf64, unit_length)| {
+            match_u16(encoding).and_then(move |version| {
+                parcel::join(
+                    optional(version >= 5, parcel::join(any_byte(), any_byte())),
+                    parcel::join(
+                        parcel::join(
+                            dwarf_offset(encoding, is_dwarf64),
+                            parcel::join(
+                                any_byte(),
+                                parcel::join(
+                                    optional(version >= 4, any_byte()),
+                                    parcel::join(
+                                        any_byte(),
+                                        parcel::join(
+                                            any_byte(),
+                                            parcel::join(
+                                                any_byte(),
+                                                any_byte().and_then(|opcode_base: u8| {
+                                                    parcel::take_n(
+                                                        any_byte(),
+                                                        opcode_base.saturating_sub(1) as usize,
+                                                    )
+                                                    .map(move |lengths| (opcode_base, lengths))
+                                                }),
+                                            ),
+                                        ),
+                                    ),
+                                ),
+                            ),
+                        ),
+                        debug_line_tables(encoding, is_dwarf64, version),
+                    ),
+                )
+                .map(
+                    move |(
+                        sizes,
+                        (
+                            (
+                                header_length,
+                                (
+                                    minimum_instruction_length,
+                                    (
+                                        maximum_operations_per_instruction,
+                                        (
+                                            default_is_stmt,
+                                            (
+                                                line_base,
+                                                (
+                                                    line_range,
+                                                    (opcode_base, standard_opcode_lengths),
+                                                ),
+                                            ),
+                                        ),
+                                    ),
+                                ),
+                            ),
+                            (include_directories, file_names),
+                        ),
+                    )| DebugLineHeader {
+                        unit_length,
+                        is_dwarf64,
+                        version,
+                        address_size: sizes.map(|(address_size, _)| address_size),
+                        segment_selector_size: sizes.map(|(_, selector_size)| selector_size),
+                        header_length,
+                        minimum_instruction_length,
+                        maximum_operations_per_instruction: maximum_operations_per_instruction
+                            .unwrap_or(1),
+                        default_is_stmt: default_is_stmt != 0,
+                        line_base: line_base as i8,
+                        line_range,
+                        opcode_base,
+                        standard_opcode_lengths,
+                        include_directories,
+                        file_names,
+                    },
+                )
+            })
+        })
+        .parse(input)?;
+
+        match header {
+            MatchStatus::Match((_, ref dlh)) if (2..=5).contains(&dlh.version) => Ok(header),
+            _ => Ok(MatchStatus::NoMatch(input)),
+        }
+    }
+}
+
+// DW_LNCT content type codes, describing a field of a version 5 directory or
+// file name entry.
+const DW_LNCT_PATH: u64 = 0x1;
+const DW_LNCT_DIRECTORY_INDEX: u64 = 0x2;
+const DW_LNCT_TIMESTAMP: u64 = 0x3;
+const DW_LNCT_SIZE: u64 = 0x4;
+const DW_LNCT_MD5: u64 = 0x5;
+
+// DW_FORM codes permitted in a version 5 entry format.
+const DW_FORM_DATA2: u64 = 0x05;
+const DW_FORM_DATA4: u64 = 0x06;
+const DW_FORM_DATA8: u64 = 0x07;
+const DW_FORM_STRING: u64 = 0x08;
+const DW_FORM_BLOCK: u64 = 0x09;
+const DW_FORM_DATA1: u64 = 0x0b;
+const DW_FORM_STRP: u64 = 0x0e;
+const DW_FORM_UDATA: u64 = 0x0f;
+const DW_FORM_DATA16: u64 = 0x1e;
+const DW_FORM_LINE_STRP: u64 = 0x1f;
+
+/// A decoded attribute value of a version 5 directory or file name entry.
+enum DwarfFormValue<'a> {
+    String(DwarfString),
+    Constant(u64),
+    Block(&'a [u8]),
+}
+
+/// Matches a single attribute value of the passed DW_FORM, returning a
+/// `NoMatch` for forms that aren't permitted in a `.debug_line` header.
+fn dwarf_form_value<'a>(
+    encoding: EiData,
+    is_dwarf64: bool,
+    form: u64,
+) -> impl Parser<'a, &'a [u8], DwarfFormValue<'a>> {
+    use parcel::parsers::byte::any_byte;
+
+    move |input: &'a [u8]| {
+        let block = |len: usize, input: &'a [u8]| match input.get(..len) {
+            Some(block) => Ok(MatchStatus::Match((
+                &input[len..],
+                DwarfFormValue::Block(block),
+            ))),
+            None => Ok(MatchStatus::NoMatch(input)),
+        };
+
+        match form {
+            DW_FORM_STRING => c_string()
+                .map(|s: &str| DwarfFormValue::String(DwarfString::Inline(s.to_string())))
+                .parse(input),
+            DW_FORM_STRP => dwarf_offset(encoding, is_dwarf64)
+                .map(|offset| DwarfFormValue::String(DwarfString::Strp(offset)))
+                .parse(input),
+            DW_FORM_LINE_STRP => dwarf_offset(encoding, is_dwarf64)
+                .map(|offset| DwarfFormValue::String(DwarfString::LineStrp(offset)))
+                .parse(input),
+            DW_FORM_UDATA => uleb128().map(DwarfFormValue::Constant).parse(input),
+            DW_FORM_DATA1 => any_byte()
+                .map(|v| DwarfFormValue::Constant(u64::from(v)))
+                .parse(input),
+            DW_FORM_DATA2 => match_u16(encoding)
+                .map(|v| DwarfFormValue::Constant(u64::from(v)))
+                .parse(input),
+            DW_FORM_DATA4 => match_u32(encoding)
+                .map(|v| DwarfFormValue::Constant(u64::from(v)))
+                .parse(input),
+            DW_FORM_DATA8 => match_u64(encoding)
+                .map(DwarfFormValue::Constant)
+                .parse(input),
+            DW_FORM_DATA16 => block(16, input),
+            DW_FORM_BLOCK => match uleb128().parse(input)? {
+                MatchStatus::Match((rem, len)) => match block(len as usize, rem)? {
+                    MatchStatus::Match(m) => Ok(MatchStatus::Match(m)),
+                    MatchStatus::NoMatch(_) => Ok(MatchStatus::NoMatch(input)),
+                },
+                MatchStatus::NoMatch(_) => Ok(MatchStatus::NoMatch(input)),
+            },
+            _ => Ok(MatchStatus::NoMatch(input)),
+        }
+    }
+}
+
+/// Matches the include directory and file name tables that close out a
+/// `.debug_line` header. Versions 2 through 4 terminate each table with an
+/// empty entry, while version 5 describes its entries with a leading entry
+/// format and count.
+fn debug_line_tables<'a>(
+    encoding: EiData,
+    is_dwarf64: bool,
+    version: u16,
+) -> impl Parser<'a, &'a [u8], (Vec<DwarfString>, Vec<DebugLineFileEntry>)> {
+    move |input: &'a [u8]| {
+        let tables = if version >= 5 {
+            debug_line_v5_tables(encoding, is_dwarf64, input)
+        } else {
+            debug_line_legacy_tables(input)
+        };
+
+        Ok(match tables {
+            Some(m) => MatchStatus::Match(m),
+            None => MatchStatus::NoMatch(input),
+        })
+    }
+}
+
+/// Matches a parser, returning its remainder and result only on a match.
+fn matched<'a, P, T>(parser: P, input: &'a [u8]) -> Option<(&'a [u8], T)>
+where
+    P: Parser<'a, &'a [u8], T>,
+{
+    match parser.parse(input) {
+        Ok(MatchStatus::Match(m)) => Some(m),
+        _ => None,
+    }
+}
+
+type DebugLineTables<'a> = (&'a [u8], (Vec<DwarfString>, Vec<DebugLineFileEntry>));
+
+fn debug_line_legacy_tables(mut input: &[u8]) -> Option<DebugLineTables<'_>> {
+    let mut include_directories = vec![];
+    loop {
+        let (rem, dir) = matched(c_string(), input)?;
+        input = rem;
+        if dir.is_empty() {
+            break;
+        }
+        include_directories.push(DwarfString::Inline(dir.to_string()));
+    }
+
+    let mut file_names = vec![];
+    loop {
+        let (rem, name) = matched(c_string(), input)?;
+        input = rem;
+        if name.is_empty() {
+            break;
+        }
+
+        let (rem, (directory_index, (modification_time, length))) = matched(
+            parcel::join(uleb128(), parcel::join(uleb128(), uleb128())),
+            input,
+        )?;
+        input = rem;
+        file_names.push(DebugLineFileEntry {
+            name: DwarfString::Inline(name.to_string()),
+            directory_index,
+            modification_time,
+            length,
+            md5: None,
+        });
+    }
+
+    Some((input, (include_directories, file_names)))
+}
+
+fn debug_line_v5_tables(
+    encoding: EiData,
+    is_dwarf64: bool,
+    input: &[u8],
+) -> Option<DebugLineTables<'_>> {
+    use std::convert::TryInto;
+
+    // each table is a list of (content type, form) pairs followed by the
+    // entries they describe.
+    let entries = |input| {
+        let (&format_count, mut rem) = <[u8]>::split_first(input)?;
+        let mut format = vec![];
+        for _ in 0..format_count {
+            let (next, pair) = matched(parcel::join(uleb128(), uleb128()), rem)?;
+            format.push(pair);
+            rem = next;
+        }
+
+        // a count the remaining input can't hold, or entries described by an
+        // empty format, are rejected rather than iterated.
+        let (mut rem, count) = matched(uleb128(), rem)?;
+        if (format.is_empty() && count > 0) || count > rem.len() as u64 {
+            return None;
+        }
+        let mut entries = vec![];
+        for _ in 0..count {
+            let mut entry = DebugLineFileEntry {
+                name: DwarfString::Inline(String::new()),
+                directory_index: 0,
+                modification_time: 0,
+                length: 0,
+                md5: None,
+            };
+
+            for &(content_type, form) in format.iter() {
+                let (next, value) = matched(dwarf_form_value(encoding, is_dwarf64, form), rem)?;
+                rem = next;
+                match (content_type, value) {
+                    (DW_LNCT_PATH, DwarfFormValue::String(name)) => entry.name = name,
+                    (DW_LNCT_DIRECTORY_INDEX, DwarfFormValue::Constant(idx)) => {
+                        entry.directory_index = idx
+                    }
+                    (DW_LNCT_TIMESTAMP, DwarfFormValue::Constant(mtime)) => {
+                        entry.modification_time = mtime
+                    }
+                    (DW_LNCT_SIZE, DwarfFormValue::Constant(len)) => entry.length = len,
+                    (DW_LNCT_MD5, DwarfFormValue::Block(digest)) => {
+                        entry.md5 = Some(digest.try_into().ok()?)
+                    }
+                    // vendor content types and unexpected forms are skipped.
+                    _ => (),
+                }
+            }
+            entries.push(entry);
+        }
+
+        Some((rem, entries))
+    };
+
+    let (rem, directories) = entries(input)?;
+    let (rem, file_names) = entries(rem)?;
+    let include_directories = directories.into_iter().map(|dir| dir.name).collect();
+
+    Some((rem, (include_directories, file_names)))
+}
+
+/// The sub-subsection tag scoping attributes to the whole file.
+const ARM_ATTRIBUTE_TAG_FILE: u8 = 0x01;
+
+/// The attribute tag pairing a ULEB128 flag with a vendor name.
+const ARM_ATTRIBUTE_TAG_COMPATIBILITY: u64 = 32;
+
+/// ArmAttributeValue represents the value of a single `.ARM.attributes` tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArmAttributeValue {
+    Integer(u64),
+    String(String),
+    Compatibility { flag: u64, vendor: String },
+}
+
+/// Returns true if an `aeabi` attribute tag takes a NUL-terminated string
+/// value. Tag_CPU_raw_name, Tag_CPU_name, Tag_also_compatible_with and
+/// Tag_conformance are strings, as are any other odd tags numbered 32 and
+/// above.
+fn arm_attribute_is_string(tag: u64) -> bool {
+    matches!(tag, 4 | 5 | 65 | 67) || (tag > ARM_ATTRIBUTE_TAG_COMPATIBILITY && tag % 2 == 1)
+}
+
+/// Parses the file-scoped attributes of the `aeabi` vendor subsection from
+/// the contents of a `.ARM.attributes` section.
+fn parse_arm_attributes(
+    encoding: EiData,
+    data: &[u8],
+) -> Option<std::collections::BTreeMap<u64, ArmAttributeValue>> {
+    let length = |input: &[u8]| match match_u32(encoding).parse(input) {
+        Ok(MatchStatus::Match((_, len))) => Some(len as usize),
+        _ => None,
+    };
+    let string = |input| StrTab::new(input).get(0).map(|s| s.to_string());
+
+    let (&format_version, mut subsections) = data.split_first()?;
+    if format_version != b'A' {
+        return None;
+    }
+
+    let mut attributes = std::collections::BTreeMap::new();
+    while !subsections.is_empty() {
+        // each length includes the length field itself.
+        let subsection_len = length(subsections)?;
+        let subsection = subsections.get(4..subsection_len)?;
+        subsections = &subsections[subsection_len..];
+
+        let vendor = string(subsection)?;
+        if vendor != "aeabi" {
+            continue;
+        }
+
+        let mut scopes = &subsection[vendor.len() + 1..];
+        while !scopes.is_empty() {
+            let scope_len = length(scopes.get(1..)?)?;
+            let mut rem = scopes.get(5..scope_len)?;
+            let scope = scopes[0];
+            scopes = &scopes[scope_len..];
+
+            if scope != ARM_ATTRIBUTE_TAG_FILE {
+                continue;
+            }
+
+            while !rem.is_empty() {
+                let (next, tag) = match uleb128().parse(rem) {
+                    Ok(MatchStatus::Match(m)) => m,
+                    _ => return None,
+                };
+
+                let (next, value) = if tag == ARM_ATTRIBUTE_TAG_COMPATIBILITY {
+                    let (next, flag) = match uleb128().parse(next) {
+                        Ok(MatchStatus::Match(m)) => m,
+                        _ => return None,
+                    };
+                    let vendor = string(next)?;
+                    (
+                        &next[vendor.len() + 1..],
+                        ArmAttributeValue::Compatibility { flag, vendor },
+                    )
+                } else if arm_attribute_is_string(tag) {
+                    let value = string(next)?;
+                    (&next[value.len() + 1..], ArmAttributeValue::String(value))
+                } else {
+                    match uleb128().parse(next) {
+                        Ok(MatchStatus::Match((next, value))) => {
+                            (next, ArmAttributeValue::Integer(value))
+                        }
+                        _ => return None,
+                    }
+                };
+
+                attributes.insert(tag, value);
+                rem = next;
+            }
+        }
+    }
+
+    Some(attributes)
+}
+
+/// The reflected form of the IEEE 802.3 CRC-32 polynomial.
+const CRC32_POLYNOMIAL: u32 = 0xedb8_8320;
+
+/// CRC32_TABLE holds the CRC of each possible byte value, computed at compile
+/// time.
+const CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut byte = 0;
+
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ CRC32_POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+
+    table
+}
+
+/// Computes the CRC-32 of a debug file as recorded in a `.gnu_debuglink`
+/// section. This is the IEEE CRC-32 used by GNU's `gnu_debuglink_crc32`,
+/// with an initial value of zero.
+pub fn debuglink_crc(debug_file: &[u8]) -> u32 {
+    !debug_file.iter().fold(!0u32, |crc, &b| {
+        CRC32_TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8)
+    })
+}
+
+/// Returns true if the CRC recorded in a `.gnu_debuglink` section matches the
+/// contents of the passed debug file.
+pub fn verify_debuglink_crc(link_crc: u32, debug_file: &[u8]) -> bool {
+    debuglink_crc(debug_file) == link_crc
+}
+
+/// Decompresses the payload of an SHF_COMPRESSED section following its
+/// compression header. Only zlib compression is supported.
+fn decompress_section(header: &CompressionHeader, payload: &[u8]) -> Option<Vec<u8>> {
+    use std::convert::TryFrom;
+
+    let size = usize::try_from(header.ch_size).ok()?;
+    match header.ch_type {
+        CompressionType::Zlib => zlib_inflate(payload, size).filter(|data| data.len() == size),
+        _ => None,
+    }
+}
+
+/// Inflates a zlib (RFC 1950) stream, verifying its Adler-32 checksum.
+/// Returns `None` if the stream inflates to more than `limit` bytes.
+fn zlib_inflate(data: &[u8], limit: usize) -> Option<Vec<u8>> {
+    let (cmf, flg) = (*data.first()?, *data.get(1)?);
+    let has_dictionary = flg & 0x20 != 0;
+    if cmf & 0x0f != 8 || (u16::from(cmf) << 8 | u16::from(flg)) % 31 != 0 || has_dictionary {
+        return None;
+    }
+
+    let mut reader = BitReader::new(&data[2..]);
+    let out = inflate(&mut reader, limit)?;
+
+    reader.align();
+    let checksum = reader.bytes(4)?;
+    if u32::from_be_bytes([checksum[0], checksum[1], checksum[2], checksum[3]]) == adler32(&out) {
+        Some(out)
+    } else {
+        None
+    }
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), &byte| {
+        let a = (a + u32::from(byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+/// BitReader reads the LSB-first bit stream of a DEFLATE block.
+struct BitReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn bits(&mut self, n: u32) -> Option<u32> {
+        let mut value = 0;
+        for i in 0..n {
+            let byte = self.data.get(self.pos / 8)?;
+            value |= u32::from((byte >> (self.pos % 8)) & 1) << i;
+            self.pos += 1;
+        }
+        Some(value)
+    }
+
+    fn align(&mut self) {
+        self.pos = (self.pos + 7) & !7;
+    }
+
+    fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        let start = self.pos / 8;
+        let bytes = self.data.get(start..start + n)?;
+        self.pos += n * 8;
+        Some(bytes)
+    }
+}
+
+/// Huffman holds a canonical Huffman code as the count of codes of each
+/// length and the symbols ordered by code.
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(lengths: &[u8]) -> Self {
+        let mut counts = [0u16; 16];
+        for &len in lengths {
+            counts[usize::from(len)] += 1;
+        }
+        counts[0] = 0;
+
+        let mut offsets = [0u16; 16];
+        for len in 1..15 {
+            offsets[len + 1] = offsets[len] + counts[len];
+        }
+
+        let mut symbols = vec![0; lengths.len()];
+        for (symbol, &len) in lengths.iter().enumerate().filter(|&(_, &len)| len != 0) {
+            symbols[usize::from(offsets[usize::from(len)])] = symbol as u16;
+            offsets[usize::from(len)] += 1;
+        }
+
+        Self { counts, symbols }
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Option<u16> {
+        let (mut code, mut first, mut index) = (0i32, 0i32, 0i32);
+        for &count in &self.counts[1..] {
+            code |= reader.bits(1)? as i32;
+            let count = i32::from(count);
+            if code - first < count {
+                return self.symbols.get((index + code - first) as usize).copied();
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        None
+    }
+}
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+/// Inflates a raw DEFLATE (RFC 1951) stream, stopping once the output
+/// exceeds `limit` bytes. As `limit` typically comes from the file, the
+/// initial reservation is clamped to a small multiple of the input length.
+fn inflate(reader: &mut BitReader, limit: usize) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(limit.min(reader.data.len().saturating_mul(4)));
+
+    loop {
+        let last = reader.bits(1)? == 1;
+        match reader.bits(2)? {
+            0 => {
+                reader.align();
+                let header = reader.bytes(4)?;
+                let len = u16::from_le_bytes([header[0], header[1]]);
+                if len != !u16::from_le_bytes([header[2], header[3]])
+                    || out.len() + usize::from(len) > limit
+                {
+                    return None;
+                }
+                out.extend_from_slice(reader.bytes(usize::from(len))?);
+            }
+            1 => {
+                let mut lengths = [8u8; 288];
+                lengths[144..256].iter_mut().for_each(|len| *len = 9);
+                lengths[256..280].iter_mut().for_each(|len| *len = 7);
+                let literals = Huffman::new(&lengths);
+                let distances = Huffman::new(&[5; 30]);
+                inflate_block(reader, &mut out, limit, &literals, &distances)?;
+            }
+            2 => {
+                let (literals, distances) = dynamic_huffman_codes(reader)?;
+                inflate_block(reader, &mut out, limit, &literals, &distances)?;
+            }
+            _ => return None,
+        }
+
+        if last {
+            return Some(out);
+        }
+    }
+}
+
+fn dynamic_huffman_codes(reader: &mut BitReader) -> Option<(Huffman, Huffman)> {
+    let literal_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+
+    let mut code_lengths = [0u8; 19];
+    for &idx in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+        code_lengths[idx] = reader.bits(3)? as u8;
+    }
+    let code_lengths = Huffman::new(&code_lengths);
+
+    let mut lengths = Vec::with_capacity(literal_count + distance_count);
+    while lengths.len() < literal_count + distance_count {
+        let (len, repeat) = match code_lengths.decode(reader)? {
+            len @ 0..=15 => (len as u8, 1),
+            16 => (*lengths.last()?, 3 + reader.bits(2)?),
+            17 => (0, 3 + reader.bits(3)?),
+            18 => (0, 11 + reader.bits(7)?),
+            _ => return None,
+        };
+        lengths.resize(lengths.len() + repeat as usize, len);
+    }
+    if lengths.len() != literal_count + distance_count {
+        return None;
+    }
+
+    Some((
+        Huffman::new(&lengths[..literal_count]),
+        Huffman::new(&lengths[literal_count..]),
+    ))
+}
+
+fn inflate_block(
+    reader: &mut BitReader,
+    out: &mut Vec<u8>,
+    limit: usize,
+    literals: &Huffman,
+    distances: &Huffman,
+) -> Option<()> {
+    loop {
+        let symbol = usize::from(literals.decode(reader)?);
+        match symbol {
+            0..=255 if out.len() < limit => out.push(symbol as u8),
+            0..=255 => return None,
+            256 => return Some(()),
+            _ => {
+                let idx = symbol - 257;
+                let len = usize::from(*LENGTH_BASE.get(idx)?)
+                    + reader.bits(u32::from(LENGTH_EXTRA[idx]))? as usize;
+                let idx = usize::from(distances.decode(reader)?);
+                let distance = usize::from(*DISTANCE_BASE.get(idx)?)
+                    + reader.bits(u32::from(DISTANCE_EXTRA[idx]))? as usize;
+                if distance > out.len() || out.len() + len > limit {
+                    return None;
+                }
+                let start = out.len() - distance;
+                for i in 0..len {
+                    out.push(out[start + i]);
+                }
+            }
+        }
+    }
+}
+
+/// Parses the contents of a `.gnu_debuglink` section: a NUL-terminated file
+/// name, zero padding to the next 4-byte boundary and a CRC32 of the debug
+/// file.
+fn parse_debuglink(encoding: EiData, data: &[u8]) -> Option<(String, u32)> {
+    let name = StrTab::new(data).get(0)?;
+    let padding_start = name.len() + 1;
+    let crc_offset = (padding_start + 3) & !3;
+
+    if data
+        .get(padding_start..crc_offset)?
+        .iter()
+        .any(|&b| b != 0x00)
+    {
+        return None;
+    }
+
+    match match_u32(encoding).parse(data.get(crc_offset..)?) {
+        Ok(MatchStatus::Match((_, crc))) => Some((name.to_string(), crc)),
+        _ => None,
+    }
+}
+
+/// Parses every entry of a `.stab` section, resolving each entry's string
+/// against `.stabstr`. String offsets are relative to the current compilation
+/// unit, whose base is advanced by each N_UNDF header entry.
+fn resolve_stabs<'a, E>(stab: &'a [u8], stabstr: StrTab<'a>) -> Vec<(Stab, Option<&'a str>)>
+where
+    EiData: From<E>,
+    E: DataEncoding + Default + 'static,
+{
+    let entries = match take_exact(StabParser::<E>::new(), stab.len() / 12).parse(stab) {
+        Ok(MatchStatus::Match((_, entries))) => entries,
+        _ => return vec![],
+    };
+
+    let mut unit_base = 0usize;
+    let mut next_unit_base = 0usize;
+    entries
+        .into_iter()
+        .map(|entry| {
+            if entry.n_type == N_UNDF {
+                unit_base = next_unit_base;
+                next_unit_base = next_unit_base.saturating_add(entry.n_value as usize);
+            }
+
+            let name = unit_base
+                .checked_add(entry.n_strx as usize)
+                .and_then(|offset| stabstr.get(offset));
+            (entry, name)
+        })
+        .collect()
+}
+
+/// Matches the passed parser exactly n times, like `parcel::take_n`, but
+/// collects the results into a vector allocated up front with capacity for
+/// all n values, avoiding reallocation while parsing large tables.
+fn take_exact<'a, P, T>(parser: P, n: usize) -> impl Parser<'a, &'a [u8], Vec<T>>
+where
+    P: Parser<'a, &'a [u8], T>,
+{
+    move |input: &'a [u8]| {
+        let mut values = Vec::with_capacity(n);
+        let mut rem = input;
+
+        for _ in 0..n {
+            match parser.parse(rem)? {
+                MatchStatus::Match((next, value)) => {
+                    rem = next;
+                    values.push(value);
+                }
+                MatchStatus::NoMatch(_) => return Ok(MatchStatus::NoMatch(input)),
+            }
+        }
+
+        Ok(MatchStatus::Match((rem, values)))
+    }
+}
+
+/// Matches a `len_width`-byte length of the passed endianness followed by
+/// that many bytes, returning the bytes. When `aligned` is set, padding
+/// following the blob up to the next 4-byte boundary, measured from the start
+/// of the length, is also consumed. Padding may be omitted at the end of the
+/// input. Widths other than 1, 2, 4 or 8 bytes never match.
+pub fn take_len_prefixed<'a>(
+    endianness: EiData,
+    len_width: usize,
+    aligned: bool,
+) -> impl Parser<'a, &'a [u8], &'a [u8]> {
+    use std::convert::TryFrom;
+
+    move |input: &'a [u8]| {
+        let len = match len_width {
+            1 => matched(parcel::parsers::byte::any_byte(), input).map(|(_, v)| u64::from(v)),
+            2 => matched(match_u16(endianness), input).map(|(_, v)| u64::from(v)),
+            4 => matched(match_u32(endianness), input).map(|(_, v)| u64::from(v)),
+            8 => matched(match_u64(endianness), input).map(|(_, v)| v),
+            _ => None,
+        };
+        let end = len
+            .and_then(|len| usize::try_from(len).ok())
+            .and_then(|len| len.checked_add(len_width));
+
+        match end.and_then(|end| input.get(len_width..end).map(|blob| (end, blob))) {
+            Some((end, blob)) => {
+                let padded_end = if aligned { (end + 3) & !3 } else { end };
+                let rem = &input[std::cmp::min(padded_end, input.len())..];
+                Ok(MatchStatus::Match((rem, blob)))
+            }
+            None => Ok(MatchStatus::NoMatch(input)),
+        }
+    }
+}
+
+/// Applies the passed parser only when `present` is set, otherwise matching
+/// `None` without consuming any input.
+fn optional<'a, P, T>(present: bool, parser: P) -> impl Parser<'a, &'a [u8], Option<T>>
+where
+    P: Parser<'a, &'a [u8], T>,
+{
+    move |input: &'a [u8]| {
+        if present {
+            parser.parse(input).map(|ms| match ms {
+                MatchStatus::Match((rem, v)) => MatchStatus::Match((rem, Some(v))),
+                MatchStatus::NoMatch(rem) => MatchStatus::NoMatch(rem),
+            })
+        } else {
+            Ok(MatchStatus::Match((input, None)))
+        }
+    }
+}
+
+/// Wraps a parser so that a `NoMatch` becomes an error built by `err`, such as
+/// a `FileErr` or a message naming the field that was expected. As parsers
+/// report errors as strings, the error is rendered via its `Display`
+/// implementation. Matches and existing errors are passed through unchanged.
+pub fn require<'a, P, T, F, D>(parser: P, err: F) -> impl Parser<'a, &'a [u8], T>
+where
+    P: Parser<'a, &'a [u8], T>,
+    F: Fn() -> D,
+    D: core::fmt::Display,
+{
+    move |input: &'a [u8]| match parser.parse(input)? {
+        MatchStatus::NoMatch(_) => Err(err().to_string()),
+        matched => Ok(matched),
+    }
+}
+
+/// Matches a DWARF section offset or length, which is four bytes wide in the
+/// 32-bit DWARF format and eight bytes wide in the 64-bit format.
+fn dwarf_offset<'a>(endianness: EiData, is_dwarf64: bool) -> impl Parser<'a, &'a [u8], u64> {
+    move |input: &'a [u8]| {
+        if is_dwarf64 {
+            match_u64(endianness).parse(input)
+        } else {
+            match_u32(endianness).map(u64::from).parse(input)
+        }
+    }
+}
+
+/// Matches a NUL-terminated UTF-8 string, consuming the terminator. A
+/// `NoMatch` is returned if no terminator is found before the end of input
+/// or the string is not valid UTF-8.
+fn c_string<'a>() -> impl Parser<'a, &'a [u8], &'a str> {
+    move |input: &'a [u8]| {
+        let terminated = input
+            .iter()
+            .position(|&b| b == 0x00)
+            .and_then(|len| Some((std::str::from_utf8(&input[..len]).ok()?, len)));
+
+        match terminated {
+            Some((string, len)) => Ok(MatchStatus::Match((&input[len + 1..], string))),
+            None => Ok(MatchStatus::NoMatch(input)),
+        }
+    }
+}
+
+/// Matches an unsigned LEB128 encoded integer, returning a `NoMatch` if the
+/// encoding is unterminated or overflows a u64.
+pub fn uleb128<'a>() -> impl Parser<'a, &'a [u8], u64> {
+    move |input: &'a [u8]| {
+        let mut value: u64 = 0;
+
+        for (idx, &byte) in input.iter().enumerate() {
+            let shift = idx * 7;
+            let bits = u64::from(byte & 0x7f);
+            if shift >= 64 || (bits << shift) >> shift != bits {
+                break;
+            }
+
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                return Ok(MatchStatus::Match((&input[idx + 1..], value)));
+            }
+        }
+
+        Ok(MatchStatus::NoMatch(input))
+    }
+}
+
+/// Matches a signed LEB128 encoded integer, sign extending from the final
+/// byte and returning a `NoMatch` if the encoding is unterminated or
+/// overflows an i64.
+pub fn sleb128<'a>() -> impl Parser<'a, &'a [u8], i64> {
+    move |input: &'a [u8]| {
+        let mut value: i64 = 0;
+
+        for (idx, &byte) in input.iter().enumerate() {
+            let shift = idx * 7;
+            let bits = i64::from(byte & 0x7f);
+            // only the sign bit remains at a shift of 63, so the final byte
+            // must be either all zeros or all ones.
+            if shift >= 64 || (shift == 63 && bits != 0 && bits != 0x7f) {
+                break;
+            }
+
+            value |= bits << shift;
+            if byte & 0x80 == 0 {
+                if shift + 7 < 64 && bits & 0x40 != 0 {
+                    value |= -1i64 << (shift + 7);
+                }
+                return Ok(MatchStatus::Match((&input[idx + 1..], value)));
+            }
+        }
+
+        Ok(MatchStatus::NoMatch(input))
+    }
+}
+
+/// Wraps a parser so that it only matches when parsing leniently, returning a
+/// `NoMatch` otherwise. This functions as a fallback for values that strict
+/// parsing rejects.
+fn lenient<'a, P, T>(options: ParseOptions, parser: P) -> impl Parser<'a, &'a [u8], T>
+where
+    P: Parser<'a, &'a [u8], T>,
+{
+    move |input: &'a [u8]| {
+        if options.strict {
+            Ok(MatchStatus::NoMatch(input))
+        } else {
+            parser.parse(input)
+        }
+    }
+}
+
+/// Matches a single provided static byte array, returning a match if the next
+/// bytes in the array match the expected byte array. Otherwise, a `NoMatch` is
+/// returned.
+fn expect_bytes<'a>(expected: &'static [u8]) -> impl Parser<'a, &'a [u8], Vec<u8>> {
+    move |input: &'a [u8]| {
+        let preparse_input = input;
+        let expected_len = expected.len();
+        let next: Vec<u8> = input.iter().take(expected_len).copied().collect();
+        if next == expected {
+            Ok(MatchStatus::Match((&input[expected_len..], next)))
+        } else {
+            Ok(MatchStatus::NoMatch(preparse_input))
+        }
+    }
+}
+
+/// FromBytes represents a fixed-width integer that can be decoded from its
+/// byte representation in either byte order.
+trait FromBytes: PartialEq + Copy {
+    /// The width of the integer in bytes.
+    const SIZE: usize;
+
+    /// Decodes an integer from exactly `SIZE` bytes of the passed byte order.
+    fn from_bytes(endianness: EiData, bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_from_bytes {
+    ($($int:ty),*) => {
+        $(
+            impl FromBytes for $int {
+                const SIZE: usize = std::mem::size_of::<$int>();
+
+                fn from_bytes(endianness: EiData, bytes: &[u8]) -> Self {
+                    use std::convert::TryInto;
+
+                    bytes
+                        .try_into()
+                        .map(|ep| match endianness {
+                            EiData::Little => <$int>::from_le_bytes(ep),
+                            EiData::Big => <$int>::from_be_bytes(ep),
+                        })
+                        .unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_bytes!(u16, u32, u64, i32, i64);
+
+/// Matches a single provided static integer, returning a match if the next
+/// `T::SIZE` bytes in the array match the expected value. Otherwise, a
+/// `NoMatch` is returned.
+fn expect_int<'a, T>(endianness: EiData, expected: T) -> impl Parser<'a, &'a [u8], T>
+where
+    T: FromBytes + 'a,
+{
+    move |input: &'a [u8]| {
+        let preparse_input = input;
+        match match_int::<T>(endianness).parse(input) {
+            Ok(MatchStatus::Match((rem, v))) if v == expected => {
+                Ok(MatchStatus::Match((rem, expected)))
+            }
+            _ => Ok(MatchStatus::NoMatch(preparse_input)),
+        }
+    }
+}
+
+/// Matches any given integer by endianness returning a corresponding value.
+fn match_int<'a, T>(endianness: EiData) -> impl Parser<'a, &'a [u8], T>
+where
+    T: FromBytes + 'a,
+{
+    use parcel::parsers::byte::any_byte;
+
+    parcel::take_n(any_byte(), T::SIZE).map(move |b| T::from_bytes(endianness, &b))
+}
+
+/// Matches a single provided static u16, returning a match if the next
+/// two bytes in the array match the expected u16. Otherwise, a `NoMatch` is
+/// returned.
+fn expect_u16<'a>(endianness: EiData, expected: u16) -> impl Parser<'a, &'a [u8], u16> {
+    expect_int(endianness, expected)
+}
+
+/// Matches a single provided static u32, returning a match if the next
+/// four bytes in the array match the expected u32. Otherwise, a `NoMatch` is
+/// returned.
+fn expect_u32<'a>(endianness: EiData, expected: u32) -> impl Parser<'a, &'a [u8], u32> {
+    expect_int(endianness, expected)
+}
+
+/// Matches any given u16 by endianness returning a corresponding u16 value.
+fn match_u16<'a>(endianness: EiData) -> impl Parser<'a, &'a [u8], u16> {
+    match_int(endianness)
+}
+
+/// Matches any given u32 by endianness returning a corresponding u32 value.
+fn match_u32<'a>(endianness: EiData) -> impl Parser<'a, &'a [u8], u32> {
+    match_int(endianness)
+}
+
+/// Matches any given u64 by endianness returning a corresponding u64 value.
+fn match_u64<'a>(endianness: EiData) -> impl Parser<'a, &'a [u8], u64> {
+    match_int(endianness)
+}
+
+/// Matches any given i32 by endianness returning a corresponding i32 value.
+fn match_i32<'a>(endianness: EiData) -> impl Parser<'a, &'a [u8], i32> {
+    match_int(endianness)
+}
+
+/// Matches any given i64 by endianness returning a corresponding i64 value.
+fn match_i64<'a>(endianness: EiData) -> impl Parser<'a, &'a [u8], i64> {
+    match_int(endianness)
+}
+
+/// Matches a single provided address of the given width, returning a match
+/// if the next `size_of::<A>()` bytes match the expected address. Otherwise, a
+/// `NoMatch` is returned.
+fn expect_addr<'a, A>(endianness: EiData, expected: A) -> impl Parser<'a, &'a [u8], A>
+where
+    A: AddressWidth + PartialEq + Copy + 'a,
+{
+    move |input: &'a [u8]| {
+        let preparse_input = input;
+        match match_addr::<A>(endianness).parse(input) {
+            Ok(MatchStatus::Match((rem, v))) if v == expected => {
+                Ok(MatchStatus::Match((rem, expected)))
+            }
+            _ => Ok(MatchStatus::NoMatch(preparse_input)),
+        }
+    }
+}
+
+/// Matches an address of the given width, reading four bytes for
+/// 32-bit addresses and eight for 64-bit addresses.
+fn match_addr<'a, A>(endianness: EiData) -> impl Parser<'a, &'a [u8], A>
+where
+    A: AddressWidth + 'a,
+{
+    move |input: &'a [u8]| {
+        let width = std::mem::size_of::<A>();
+        match input
+            .get(..width)
+            .and_then(|bytes| A::from_bytes(endianness, bytes))
+        {
+            Some(addr) => Ok(MatchStatus::Match((&input[width..], addr))),
+            None => Ok(MatchStatus::NoMatch(input)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    macro_rules! generate_file_header {
+        () => {
+            vec![
+                // File Header
+                0x7f, 0x45, 0x4c, 0x46, // magic
+                0x01, // ei_class
+                0x01, // ei_data
+                0x01, // ei_version
+                0x00, // ei_osabi
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // padding
+                0x00, 0x00, // type
+                0x03, 0x00, // machine
+                0x01, 0x00, 0x00, 0x00, //version
+                0x05, 0x00, 0x00, 0x00, // entry
+                0x0A, 0x00, 0x00, 0x00, // phoff
+                0x0B, 0x00, 0x00, 0x00, // shoff
+                0x02, 0x00, 0x00, 0x00, // flags
+                0x00, 0x00, // eh_size
+                0x01, 0x00, // phentsize
+                0x01, 0x00, // phnum
+                0x01, 0x00, // shentsize
+                0x01, 0x00, // shnum
+                0x01, 0x00, // shstrndx
+            ]
+        };
+    }
+
+    macro_rules! generate_program_header {
+        () => {
+            vec![
+                // Program Header
+                0x00, 0x00, 0x00, 0x00, // p_type
+                0x00, 0x00, 0x00, 0x00, // p_offset
+                0x00, 0x00, 0x00, 0x00, // p_vaddr
+                0x00, 0x00, 0x00, 0x00, // p_paddr
+                0x00, 0x00, 0x00, 0x00, // p_filesz
+                0x00, 0x00, 0x00, 0x00, // p_memsz
+                0x00, 0x00, 0x00, 0x00, // p_flags
+                0x00, 0x00, 0x00, 0x00, // p_align
+            ]
+        };
+    }
+
+    /// Returns a zeroed 64-bit section header of the passed type to be used as
+    /// a template for `generate_elf64_le_image`.
+    fn section_header64(sh_type: ShType) -> SectionHeader64 {
+        SectionHeader64 {
+            sh_name: 0,
+            sh_type,
+            sh_flags: ShFlags64::Other(0),
+            sh_addr: 0,
+            sh_offset: 0,
+            sh_size: 0,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addr_align: 0,
+            sh_entsize: 0,
+        }
+    }
+
+    /// Encodes a little-endian 64-bit symbol table entry.
+    fn symbol64(st_name: u32, st_info: u8, st_shndx: u16, st_value: u64) -> Vec<u8> {
+        [
+            st_name.to_le_bytes().to_vec(),
+            vec![st_info, 0x00],
+            st_shndx.to_le_bytes().to_vec(),
+            st_value.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+
+    /// Builds a 64-bit program header whose physical address mirrors its
+    /// virtual address, aligned to a 4KiB page.
+    fn program_header64(
+        r#type: ProgramHeaderType,
+        flags: u32,
+        offset: u64,
+        vaddr: u64,
+        filesz: u64,
+        memsz: u64,
+    ) -> ProgramHeader64 {
+        ProgramHeader64 {
+            r#type,
+            flags,
+            offset,
+            vaddr,
+            paddr: vaddr,
+            filesz,
+            memsz,
+            align: 0x1000,
+        }
+    }
+
+    /// Builds a decoded 64-bit symbol table entry.
+    fn symbol64_entry(st_info: u8, st_shndx: u16, st_value: u64) -> Symbol64 {
+        Symbol64 {
+            st_name: 0,
+            st_info,
+            st_other: 0,
+            st_shndx,
+            st_value,
+            st_size: 0,
+        }
+    }
+
+    /// Assembles a little-endian 64-bit image from the passed program headers
+    /// and named sections. A null section is prepended and a `.shstrtab` is
+    /// appended to the section table. Section data is laid out, 8-byte
+    /// aligned, after the program header table with each header's name,
+    /// offset and size filled in. NOBITS sections retain their passed size.
+    fn generate_elf64_le_image(
+        r#type: Type,
+        program_headers: Vec<ProgramHeader64>,
+        sections: Vec<(&str, SectionHeader64, Vec<u8>)>,
+    ) -> Vec<u8> {
+        let data_start = 64 + (program_headers.len() * 56);
+        let mut data: Vec<u8> = vec![];
+        let mut shstrtab = vec![0x00];
+        let mut section_headers = vec![section_header64(ShType::Null)];
+        let align_to_eight_bytes =
+            |data: &mut Vec<u8>| data.resize(((data_start + data.len() + 7) & !7) - data_start, 0);
+
+        let shstrtab_section = (".shstrtab", section_header64(ShType::StrTab), vec![]);
+        for (name, mut sh, contents) in sections.into_iter().chain(vec![shstrtab_section]) {
+            align_to_eight_bytes(&mut data);
+
+            sh.sh_name = shstrtab.len() as u32;
+            shstrtab.extend_from_slice(name.as_bytes());
+            shstrtab.push(0x00);
+            sh.sh_offset = (data_start + data.len()) as u64;
+
+            if name == ".shstrtab" {
+                sh.sh_size = shstrtab.len() as u64;
+                data.extend_from_slice(&shstrtab);
+            } else if sh.sh_type != ShType::NoBits {
+                sh.sh_size = contents.len() as u64;
+                data.extend(contents);
+            }
+            section_headers.push(sh);
+        }
+        align_to_eight_bytes(&mut data);
+
+        let file_header = FileHeader::<ElfAddr64> {
+            r#type,
+            machine: Machine::X86_64,
+            version: Version::One,
+            entry_point: 0,
+            ph_offset: if program_headers.is_empty() { 0 } else { 64 },
+            sh_offset: (data_start + data.len()) as u64,
+            flags: 0,
+            eh_size: 64,
+            phent_size: 56,
+            phnum: program_headers.len() as u16,
+            shent_size: 64,
+            shnum: section_headers.len() as u16,
+            shstrndx: (section_headers.len() - 1) as u16,
+        };
+
+        let mut image = vec![
+            0x7f, 0x45, 0x4c, 0x46, 0x02, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        image.extend(Serialize::<ElfAddr64, LittleEndian>::serialize(
+            &file_header,
+        ));
+        for ph in program_headers.iter() {
+            image.extend(Serialize::<ElfAddr64, LittleEndian>::serialize(ph));
+        }
+        image.extend(data);
+        for sh in section_headers.iter() {
+            image.extend(Serialize::<ElfAddr64, LittleEndian>::serialize(sh));
+        }
+
+        image
+    }
+
+    /// Encodes a little-endian 64-bit dynamic section entry.
+    fn dynamic_entry64(d_tag: DynamicTag, d_val: u64) -> Vec<u8> {
+        [Into::<i64>::into(d_tag).to_le_bytes(), d_val.to_le_bytes()].concat()
+    }
+
+    /// Assembles a little-endian 64-bit image with a `.dynstr` section mapped
+    /// at 0x400 and a `.dynamic` section holding the passed entries, followed
+    /// by DT_STRTAB and DT_NULL entries.
+    fn generate_dynamic_elf64_le_image(
+        r#type: Type,
+        entries: Vec<(DynamicTag, u64)>,
+        dynstr: &[u8],
+    ) -> Vec<u8> {
+        let dynamic: Vec<u8> = entries
+            .into_iter()
+            .chain(vec![(DynamicTag::StrTab, 0x400), (DynamicTag::Null, 0)])
+            .flat_map(|(d_tag, d_val)| dynamic_entry64(d_tag, d_val))
+            .collect();
+        let dynstr_header = SectionHeader64 {
+            sh_addr: 0x400,
+            ..section_header64(ShType::StrTab)
+        };
+
+        generate_elf64_le_image(
+            r#type,
+            vec![],
+            vec![
+                (".dynstr", dynstr_header, dynstr.to_vec()),
+                (".dynamic", section_header64(ShType::Dynamic), dynamic),
+            ],
+        )
+    }
+
+    /// Assembles a little-endian 64-bit shared object without section
+    /// headers. A single PT_LOAD segment maps the whole file at 0x10000, and
+    /// the passed dynamic entries, followed by DT_STRTAB and DT_NULL entries,
+    /// are located by a PT_DYNAMIC segment.
+    fn generate_section_stripped_elf64_le_image(
+        entries: Vec<(DynamicTag, u64)>,
+        dynstr: &[u8],
+    ) -> Vec<u8> {
+        let base = 0x10000;
+        // the file header and two program headers precede the string table.
+        let dynstr_offset = 64 + 2 * 56;
+        let dynamic_offset = (dynstr_offset + dynstr.len() + 7) & !7;
+        let dynamic: Vec<u8> = entries
+            .into_iter()
+            .chain(vec![
+                (DynamicTag::StrTab, base + dynstr_offset as u64),
+                (DynamicTag::Null, 0),
+            ])
+            .flat_map(|(d_tag, d_val)| dynamic_entry64(d_tag, d_val))
+            .collect();
+        let file_size = (dynamic_offset + dynamic.len()) as u64;
+
+        let program_headers = vec![
+            program_header64(ProgramHeaderType::Load, 0x04, 0, base, file_size, file_size),
+            program_header64(
+                ProgramHeaderType::Dynamic,
+                0x04,
+                dynamic_offset as u64,
+                base + dynamic_offset as u64,
+                dynamic.len() as u64,
+                dynamic.len() as u64,
+            ),
+        ];
+
+        let mut image = generate_elf64_le_image(
+            Type::Dyn,
+            program_headers,
+            vec![
+                (".dynstr", section_header64(ShType::StrTab), dynstr.to_vec()),
+                (".dynamic", section_header64(ShType::Dynamic), dynamic),
+            ],
+        );
+        // drop the section header table, clearing shoff, shnum and shstrndx.
+        image.truncate(file_size as usize);
+        image[40..48].copy_from_slice(&[0x00; 8]);
+        image[60..64].copy_from_slice(&[0x00; 4]);
+        image
+    }
+
+    #[test]
+    fn parse_preamble_should_return_expected_results() {
+        let thirty_two_bit_input = [
+            0x7f, 0x45, 0x4c, 0x46, 0x01, 0x01, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        let sixty_four_bit_input = [
+            0x7f, 0x45, 0x4c, 0x46, 0x02, 0x01, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        let invalid_input = [0xff, 0xff, 0xff, 0xff, 0xff];
+
+        assert_eq!(
+            Ok(EiClass::ThirtyTwoBit),
+            EiIdentParser::new()
+                .parse(&thirty_two_bit_input)
+                .map(|ms| ms.unwrap().ei_class)
+        );
+        assert_eq!(
+            Ok(EiClass::SixtyFourBit),
+            EiIdentParser::new()
+                .parse(&sixty_four_bit_input)
+                .map(|ms| ms.unwrap().ei_class)
+        );
+        assert!(EiIdentParser::new()
+            .parse(&invalid_input)
+            .and_then(|ms| match ms {
+                MatchStatus::Match(_) => Err("invalid input shouldn't match".to_string()),
+                MatchStatus::NoMatch(_) => Ok(()),
+            })
+            .is_ok());
+    }
+
+    #[test]
+    fn parse_known_good_file_header() {
+        let input: Vec<u8> = generate_file_header!();
+
+        assert_eq!(
+            FileHeader::<ElfAddr32> {
+                r#type: Type::None,
+                machine: Machine::X386,
+                version: Version::One,
+                entry_point: 5u32,
+                ph_offset: 10u32,
+                sh_offset: 11u32,
+                flags: 2,
+                eh_size: 0,
+                phent_size: 1,
+                phnum: 1,
+                shent_size: 1,
+                shnum: 1,
+                shstrndx: 1
+            },
+            FileHeaderParser::<ElfAddr32, LittleEndian>::new()
+                .parse(&input)
+                .unwrap()
+                .unwrap(),
+        )
+    }
+
+    #[test]
+    fn parse_known_good_program_header() {
+        let input: Vec<u8> = generate_program_header!();
+
+        assert_eq!(
             ProgramHeader32 {
                 r#type: ProgramHeaderType::Null,
                 offset: 0x00,
@@ -2403,51 +10120,3818 @@ mod tests {
                 flags: 0x00,
                 align: 0x00,
             },
-            ProgramHeaderParser::<ElfAddr32, LittleEndian>::new()
-                .parse(&input)
-                .unwrap()
-                .unwrap()
-        )
+            ProgramHeaderParser::<ElfAddr32, LittleEndian>::new()
+                .parse(&input)
+                .unwrap()
+                .unwrap()
+        )
+    }
+
+    #[test]
+    fn should_serialize_a_header_to_bytes() {
+        let elf_header = ElfHeader64::<LittleEndian>::new(
+            EiIdent {
+                ei_data: EiData::Little,
+                ei_class: EiClass::SixtyFourBit,
+                ei_osabi: EiOsAbi::SysV,
+                ei_version: EiVersion::One,
+                ei_abiversion: EiAbiVersion::One,
+                pad: [0; 7],
+            },
+            FileHeader::<ElfAddr64> {
+                r#type: Type::None,
+                machine: Machine::X86_64,
+                version: Version::One,
+                entry_point: 5u64,
+                ph_offset: 10u64,
+                sh_offset: 11u64,
+                flags: 2,
+                eh_size: 0,
+                phent_size: 1,
+                phnum: 1,
+                shent_size: 1,
+                shnum: 1,
+                shstrndx: 1,
+            },
+            vec![ProgramHeader64 {
+                r#type: ProgramHeaderType::Null,
+                offset: 0x00,
+                vaddr: 0x00,
+                paddr: 0x00,
+                filesz: 0x00,
+                memsz: 0x00,
+                flags: 0x00,
+                align: 0x00,
+            }],
+            vec![],
+        );
+
+        assert_eq!(120, Into::<Vec<u8>>::into(elf_header).len())
+    }
+
+    #[test]
+    fn should_resolve_stab_entries_against_stabstr() {
+        let stab_entry = |n_strx: u32, n_type: u8, n_desc: u16, n_value: u32| {
+            vec![
+                n_strx.to_le_bytes().to_vec(),
+                vec![n_type, 0x00],
+                n_desc.to_le_bytes().to_vec(),
+                n_value.to_le_bytes().to_vec(),
+            ]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<u8>>()
+        };
+        let stab: Vec<u8> = vec![
+            // first compilation unit header
+            stab_entry(1, N_UNDF, 1, 13),
+            stab_entry(5, 0x24, 0, 0x1000),
+            // second compilation unit header
+            stab_entry(1, N_UNDF, 0, 5),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let stabstr = b"\0a.c\0main:F1\0\0b.c\0".to_vec();
+
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![
+                (".stab", section_header64(ShType::ProgBits), stab),
+                (".stabstr", section_header64(ShType::StrTab), stabstr),
+            ],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        let stabs = elf_header.stabs(&image);
+        assert_eq!(
+            vec![Some("a.c"), Some("main:F1"), Some("b.c")],
+            stabs.iter().map(|(_, name)| *name).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Stab {
+                n_strx: 5,
+                n_type: 0x24,
+                n_other: 0x00,
+                n_desc: 0,
+                n_value: 0x1000,
+            },
+            stabs[1].0
+        );
+    }
+
+    #[test]
+    fn should_iterate_section_headers_of_a_borrowed_header() {
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![(".text", section_header64(ShType::ProgBits), vec![0x90; 4])],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        let mut names = vec![];
+        for section in &elf_header {
+            names.push(elf_header.section_name(section, &image));
+        }
+
+        assert_eq!(vec![Some(""), Some(".text"), Some(".shstrtab")], names);
+    }
+
+    #[test]
+    fn should_only_accept_unknown_osabi_when_parsing_leniently() {
+        let mut image = generate_elf64_le_image(Type::Rel, vec![], vec![]);
+        // bogus ei_osabi
+        image[7] = 0x99;
+
+        assert_eq!(
+            Ok(false),
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .map(|ms| matches!(ms, MatchStatus::Match(_)))
+        );
+
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::with_options(ParseOptions {
+            strict: false,
+        })
+        .parse(&image)
+        .unwrap()
+        .unwrap();
+        assert_eq!(EiOsAbi::Unknown(0x99), elf_header.ei_ident.ei_osabi);
+        assert_eq!("<unknown: 99>", elf_header.ei_ident.ei_osabi.to_string());
+        assert_eq!(Version::One, elf_header.file_header.version);
+    }
+
+    #[test]
+    fn should_retain_bogus_versions_when_parsing_leniently() {
+        let mut image = generate_elf64_le_image(Type::Rel, vec![], vec![]);
+        // bogus ei_version and e_version
+        image[6] = 0x07;
+        image[20..24].copy_from_slice(&0x2au32.to_le_bytes());
+
+        assert_eq!(
+            Ok(false),
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .map(|ms| matches!(ms, MatchStatus::Match(_)))
+        );
+
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::with_options(ParseOptions {
+            strict: false,
+        })
+        .parse(&image)
+        .unwrap()
+        .unwrap();
+        assert_eq!(EiVersion::Unknown(0x07), elf_header.ei_ident.ei_version);
+        assert_eq!(Version::Other(0x2a), elf_header.file_header.version);
+        assert_eq!("<unknown: 2a>", elf_header.file_header.version.to_string());
+
+        let serialized: Vec<u8> = elf_header.into();
+        assert_eq!(&image[..64], &serialized[..64]);
+    }
+
+    #[test]
+    fn should_decode_symbol_visibility_from_st_other() {
+        let input = vec![
+            0x01, 0x00, 0x00, 0x00, // st_name
+            0x12, // st_info
+            0x02, // st_other
+            0x0d, 0x00, // st_shndx
+            0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // st_value
+            0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // st_size
+        ];
+
+        let symbol = SymbolParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(0x1000, symbol.st_value);
+        assert_eq!(SymbolVisibility::Hidden, symbol.visibility());
+        assert_eq!("HIDDEN", symbol.visibility().to_string());
+    }
+
+    #[test]
+    fn should_resolve_needed_libraries_from_the_dynamic_section() {
+        let image = generate_dynamic_elf64_le_image(
+            Type::Dyn,
+            vec![(DynamicTag::Needed, 1), (DynamicTag::Needed, 11)],
+            b"\0libc.so.6\0libm.so.6\0",
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            vec!["libc.so.6".to_string(), "libm.so.6".to_string()],
+            elf_header.needed_libraries(&image)
+        );
+
+        let no_dynamic = generate_elf64_le_image(Type::Exec, vec![], vec![]);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&no_dynamic)
+            .unwrap()
+            .unwrap();
+        assert!(elf_header.needed_libraries(&no_dynamic).is_empty());
+    }
+
+    #[test]
+    fn should_resolve_soname_and_runpath_from_the_dynamic_section() {
+        let shared_object = generate_dynamic_elf64_le_image(
+            Type::Dyn,
+            vec![(DynamicTag::SoName, 1)],
+            b"\0libfoo.so.1\0",
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&shared_object)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            Some("libfoo.so.1".to_string()),
+            elf_header.soname(&shared_object)
+        );
+        assert!(elf_header.runpath(&shared_object).is_empty());
+
+        let binary = generate_dynamic_elf64_le_image(
+            Type::Exec,
+            vec![(DynamicTag::RPath, 1), (DynamicTag::RunPath, 9)],
+            b"\0/ignore\0$ORIGIN/../lib:/opt/lib\0",
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&binary)
+            .unwrap()
+            .unwrap();
+        assert_eq!(None, elf_header.soname(&binary));
+        assert_eq!(
+            vec!["$ORIGIN/../lib".to_string(), "/opt/lib".to_string()],
+            elf_header.runpath(&binary)
+        );
+    }
+
+    #[test]
+    fn should_clear_the_execute_bit_of_a_segment_and_reserialize() {
+        let gnu_stack = ProgramHeader64 {
+            r#type: ProgramHeaderType::GnuStack,
+            flags: 0x07,
+            offset: 0x00,
+            vaddr: 0x00,
+            paddr: 0x00,
+            filesz: 0x00,
+            memsz: 0x00,
+            align: 0x10,
+        };
+        let image = generate_elf64_le_image(Type::Exec, vec![gnu_stack], vec![]);
+        let mut elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        let original: Vec<u8> = elf_header.clone().into();
+
+        let rw = Permissions {
+            read: true,
+            write: true,
+            execute: false,
+        };
+        assert!(elf_header.set_segment_permissions(1, rw).is_err());
+        assert!(elf_header.set_segment_permissions(0, rw).is_ok());
+
+        let modified: Vec<u8> = elf_header.clone().into();
+        let changed: Vec<(u8, u8)> = original
+            .into_iter()
+            .zip(modified)
+            .filter(|(before, after)| before != after)
+            .collect();
+        assert_eq!(vec![(0x07, 0x06)], changed);
+
+        let ph_bytes =
+            Serialize::<ElfAddr64, LittleEndian>::serialize(&elf_header.program_headers[0]);
+        assert_eq!(
+            Ok(ProgramHeader64 {
+                flags: 0x06,
+                ..gnu_stack
+            }),
+            ProgramHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&ph_bytes)
+                .map(|ms| ms.unwrap())
+        );
+    }
+
+    #[test]
+    fn should_report_overlapping_section_file_ranges() {
+        let section = |sh_type: ShType, sh_offset: u64, sh_size: u64| SectionHeader64 {
+            sh_offset,
+            sh_size,
+            ..section_header64(sh_type)
+        };
+        let image = generate_elf64_le_image(Type::Rel, vec![], vec![]);
+        let mut elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        elf_header.section_headers = vec![
+            section(ShType::Null, 0x00, 0x00),
+            section(ShType::ProgBits, 0x40, 0x20),
+            section(ShType::ProgBits, 0x50, 0x20),
+            section(ShType::NoBits, 0x50, 0x100),
+            section(ShType::ProgBits, 0x70, 0x10),
+        ];
+
+        assert_eq!(vec![(1, 2)], elf_header.validate_section_layout());
+    }
+
+    #[test]
+    fn should_render_a_readelf_style_report() {
+        let dynstr = b"\0libc.so.6\0puts\0".to_vec();
+        let dynsym: Vec<u8> = [
+            vec![0x00; 24],
+            vec![
+                0x0b, 0x00, 0x00, 0x00, // st_name
+                0x12, // st_info
+                0x00, // st_other
+                0x00, 0x00, // st_shndx
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // st_value
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // st_size
+            ],
+        ]
+        .concat();
+        let dynamic = [
+            dynamic_entry64(DynamicTag::Needed, 0x01),
+            dynamic_entry64(DynamicTag::StrTab, 0x400),
+            dynamic_entry64(DynamicTag::Null, 0x00),
+        ]
+        .concat();
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0x00,
+            vaddr: 0x00,
+            paddr: 0x00,
+            filesz: 0x1000,
+            memsz: 0x1000,
+            align: 0x1000,
+        };
+        let image = generate_elf64_le_image(
+            Type::Dyn,
+            vec![load],
+            vec![
+                (
+                    ".dynstr",
+                    SectionHeader64 {
+                        sh_addr: 0x400,
+                        ..section_header64(ShType::StrTab)
+                    },
+                    dynstr,
+                ),
+                (
+                    ".dynsym",
+                    SectionHeader64 {
+                        sh_link: 1,
+                        sh_info: 1,
+                        sh_entsize: 24,
+                        ..section_header64(ShType::DynSym)
+                    },
+                    dynsym,
+                ),
+                (
+                    ".dynamic",
+                    SectionHeader64 {
+                        sh_link: 1,
+                        sh_entsize: 16,
+                        ..section_header64(ShType::Dynamic)
+                    },
+                    dynamic,
+                ),
+            ],
+        );
+
+        let report = ParsedElf::parse(&image).unwrap().to_string();
+        assert_eq!(
+            include_str!("../tests/fixtures/elf64_le_dyn.golden"),
+            report
+        );
+    }
+
+    #[test]
+    fn should_compute_the_minimum_file_size_implied_by_the_headers() {
+        let image = generate_dynamic_elf64_le_image(Type::Dyn, vec![], b"\0");
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        // section headers are placed at the end of the generated image.
+        assert_eq!(image.len() as u64, elf_header.expected_min_size());
+
+        let truncated = &image[..image.len() - 1];
+        assert!((truncated.len() as u64) < elf_header.expected_min_size());
+    }
+
+    #[test]
+    fn should_allocate_header_tables_with_exact_capacity() {
+        let sections = (0..32)
+            .map(|_| ("", section_header64(ShType::ProgBits), vec![0x00; 8]))
+            .collect();
+        let program_headers = (0..16)
+            .map(|_| ProgramHeader64 {
+                r#type: ProgramHeaderType::Load,
+                flags: 0x04,
+                offset: 0x00,
+                vaddr: 0x00,
+                paddr: 0x00,
+                filesz: 0x00,
+                memsz: 0x00,
+                align: 0x1000,
+            })
+            .collect();
+        let image = generate_elf64_le_image(Type::Exec, program_headers, sections);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(16, elf_header.program_headers.len());
+        assert_eq!(
+            elf_header.program_headers.len(),
+            elf_header.program_headers.capacity()
+        );
+        assert_eq!(34, elf_header.section_headers.len());
+        assert_eq!(
+            elf_header.section_headers.len(),
+            elf_header.section_headers.capacity()
+        );
+    }
+
+    #[test]
+    fn should_validate_the_phdr_segment_against_the_program_header_table() {
+        let phdr = ProgramHeader64 {
+            r#type: ProgramHeaderType::PhDr,
+            flags: 0x04,
+            offset: 0x40,
+            vaddr: 0x400040,
+            paddr: 0x400040,
+            filesz: 0x70,
+            memsz: 0x70,
+            align: 0x08,
+        };
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0x00,
+            vaddr: 0x400000,
+            paddr: 0x400000,
+            filesz: 0x1000,
+            memsz: 0x1000,
+            align: 0x1000,
+        };
+        let image = generate_elf64_le_image(Type::Exec, vec![phdr, load], vec![]);
+        let mut elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(Ok(()), elf_header.validate_phdr_segment());
+
+        elf_header.program_headers[0].filesz = 0x38;
+        assert_eq!(
+            Err(ValidationError::PhdrSizeMismatch {
+                expected: 0x70,
+                found: 0x38
+            }),
+            elf_header.validate_phdr_segment()
+        );
+    }
+
+    #[test]
+    fn should_parse_modern_machine_values() {
+        let header = |machine: u16| {
+            let mut image = generate_elf64_le_image(Type::Exec, vec![], vec![]);
+            image[18..20].copy_from_slice(&machine.to_le_bytes());
+            image
+        };
+
+        for &(code, machine) in &[(0x102, Machine::LoongArch), (0x53, Machine::AVR)] {
+            let image = header(code);
+            let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(machine, elf_header.file_header.machine);
+            assert_eq!(code, u16::from(elf_header.file_header.machine));
+        }
+
+        // unrecognized codes parse in either mode and survive a round trip.
+        let image = header(0x1234);
+        for &strict in &[true, false] {
+            let elf_header =
+                ElfHeaderParser::<ElfAddr64, LittleEndian>::with_options(ParseOptions { strict })
+                    .parse(&image)
+                    .unwrap()
+                    .unwrap();
+
+            assert_eq!(Machine::Other(0x1234), elf_header.file_header.machine);
+            assert_eq!(
+                "<unknown: 1234>",
+                elf_header.file_header.machine.to_string()
+            );
+            let serialized: Vec<u8> = elf_header.into();
+            assert_eq!(&image[..64], &serialized[..64]);
+        }
+    }
+
+    #[test]
+    fn should_parse_loadable_headers_despite_a_broken_section_table() {
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0x00,
+            vaddr: 0x400000,
+            paddr: 0x400000,
+            filesz: 0x1000,
+            memsz: 0x1000,
+            align: 0x1000,
+        };
+        let mut image = generate_elf64_le_image(Type::Exec, vec![load], vec![]);
+        // point e_shoff far past the end of the file.
+        image[40..48].copy_from_slice(&0xffff_ffffu64.to_le_bytes());
+
+        match parse_loadable(&image) {
+            Ok(LoadableElf::Elf64 {
+                file_header,
+                program_headers,
+                ..
+            }) => {
+                assert_eq!(Type::Exec, file_header.r#type);
+                assert_eq!(vec![load], program_headers);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_parse_the_debug_file_name_and_crc_from_gnu_debuglink() {
+        let debuglink = [
+            b"app.debug\0".to_vec(),
+            vec![0x00, 0x00],
+            0xdeadbeefu32.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let image = generate_elf64_le_image(
+            Type::Exec,
+            vec![],
+            vec![(
+                ".gnu_debuglink",
+                section_header64(ShType::ProgBits),
+                debuglink,
+            )],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            Some(("app.debug".to_string(), 0xdeadbeef)),
+            elf_header.debuglink(&image)
+        );
+    }
+
+    #[test]
+    fn should_borrow_program_headers_as_a_slice() {
+        fn loadable_segments(program_headers: &[ProgramHeader64]) -> usize {
+            program_headers
+                .iter()
+                .filter(|ph| ph.r#type == ProgramHeaderType::Load)
+                .count()
+        }
+
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0x00,
+            vaddr: 0x00,
+            paddr: 0x00,
+            filesz: 0x00,
+            memsz: 0x00,
+            align: 0x1000,
+        };
+        let image = generate_elf64_le_image(Type::Exec, vec![load, load], vec![]);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(2, loadable_segments(elf_header.program_headers()));
+        assert_eq!(2, loadable_segments(elf_header.as_ref()));
+        assert_eq!(2, elf_header.section_headers().len());
+    }
+
+    #[test]
+    fn should_verify_the_debuglink_crc_of_a_debug_file() {
+        assert_eq!(0x0000_0000, debuglink_crc(b""));
+        assert_eq!(0xcbf4_3926, debuglink_crc(b"123456789"));
+        assert!(verify_debuglink_crc(
+            0x414f_a339,
+            b"The quick brown fox jumps over the lazy dog"
+        ));
+        assert!(!verify_debuglink_crc(
+            0x414f_a339,
+            b"The quick brown fox jumps over the lazy cat"
+        ));
+    }
+
+    #[test]
+    fn should_parse_a_version_4_debug_line_header() {
+        let debug_line = vec![
+            0x40, 0x00, 0x00, 0x00, // unit_length
+            0x04, 0x00, // version
+            0x20, 0x00, 0x00, 0x00, // header_length
+            0x01, // minimum_instruction_length
+            0x01, // maximum_operations_per_instruction
+            0x01, // default_is_stmt
+            0xfb, // line_base
+            0x0e, // line_range
+            0x0d, // opcode_base
+            0x00, 0x01, 0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x01, b's', b'r',
+            b'c', 0x00, 0x00, // include_directories
+            b'm', b'a', b'i', b'n', b'.', b'c', 0x00, 0x01, 0x00, 0x00, // file_names
+            0x00,
+        ];
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![(
+                ".debug_line",
+                section_header64(ShType::ProgBits),
+                debug_line,
+            )],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            Some(DebugLineHeader {
+                unit_length: 0x40,
+                is_dwarf64: false,
+                version: 4,
+                address_size: None,
+                segment_selector_size: None,
+                header_length: 0x20,
+                minimum_instruction_length: 1,
+                maximum_operations_per_instruction: 1,
+                default_is_stmt: true,
+                line_base: -5,
+                line_range: 14,
+                opcode_base: 13,
+                standard_opcode_lengths: vec![0, 1, 1, 1, 1, 0, 0, 0, 1, 0, 0, 1],
+                include_directories: vec![DwarfString::Inline("src".to_string())],
+                file_names: vec![DebugLineFileEntry {
+                    name: DwarfString::Inline("main.c".to_string()),
+                    directory_index: 1,
+                    modification_time: 0,
+                    length: 0,
+                    md5: None,
+                }],
+            }),
+            elf_header.debug_line_header(&image)
+        );
+    }
+
+    #[test]
+    fn should_parse_a_version_5_dwarf64_debug_line_header() {
+        let mut debug_line = vec![
+            0xff, 0xff, 0xff, 0xff, // 64-bit DWARF escape
+            0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // unit_length
+            0x05, 0x00, // version
+            0x08, // address_size
+            0x00, // segment_selector_size
+            0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // header_length
+            0x04, 0x01, 0x01, 0xfb, 0x0e, // min_inst, max_ops, is_stmt, line_base, line_range
+            0x02, 0x00, // opcode_base, standard_opcode_lengths
+            0x01, 0x01, 0x1f, // directory format: path as line_strp
+            0x01, // directories_count
+            0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x01, 0x08, 0x02, 0x0b, 0x05,
+            0x1e, // file format: path, dir, md5
+            0x01, // file_names_count
+            b'a', b'.', b'c', 0x00, 0x00,
+        ];
+        debug_line.extend(0..16u8);
+
+        let header = DebugLineHeaderParser::<LittleEndian>::new()
+            .parse(&debug_line)
+            .unwrap()
+            .unwrap();
+        let digest: [u8; 16] = std::array::from_fn(|idx| idx as u8);
+
+        assert!(header.is_dwarf64);
+        assert_eq!((Some(8), 0x40), (header.address_size, header.header_length));
+        assert_eq!(
+            vec![DwarfString::LineStrp(0x10)],
+            header.include_directories
+        );
+        assert_eq!(
+            vec![DebugLineFileEntry {
+                name: DwarfString::Inline("a.c".to_string()),
+                directory_index: 0,
+                modification_time: 0,
+                length: 0,
+                md5: Some(digest),
+            }],
+            header.file_names
+        );
+    }
+
+    #[test]
+    fn should_reject_hostile_version_5_debug_line_tables() {
+        // no directory format, yet a near-maximal directory count.
+        let formatless = [
+            0x00, // directory_entry_format_count
+            0xff, 0xff, 0xff, 0xff, 0x0f, // directories_count
+        ];
+        assert_eq!(
+            None,
+            debug_line_v5_tables(EiData::Little, false, &formatless)
+        );
+
+        // an inline path format with more directories than bytes remaining.
+        let overlong = [
+            0x01, 0x01, 0x08, // directory format: path as string
+            0x80, 0x80, 0x04, // directories_count
+            b'a', 0x00,
+        ];
+        assert_eq!(None, debug_line_v5_tables(EiData::Little, false, &overlong));
+
+        let empty = [0x00, 0x00, 0x00, 0x00];
+        assert_eq!(
+            Some((&[][..], (vec![], vec![]))),
+            debug_line_v5_tables(EiData::Little, false, &empty)
+        );
+    }
+
+    #[test]
+    fn should_compare_idents_ignoring_padding() {
+        let image = generate_elf64_le_image(Type::Rel, vec![], vec![]);
+        let ident = EiIdentParser::new().parse(&image).unwrap().unwrap();
+        let mut padded = image.clone();
+        padded[9..16].copy_from_slice(&[0xde, 0xad, 0xbe, 0xef, 0x00, 0x00, 0x00]);
+        let padded_ident = EiIdentParser::new().parse(&padded).unwrap().unwrap();
+
+        assert_ne!(ident, padded_ident);
+        assert!(ident.ident_eq_ignoring_padding(&padded_ident));
+        assert!(!ident.ident_eq_ignoring_padding(&EiIdent {
+            ei_osabi: EiOsAbi::FreeBSD,
+            ..ident
+        }));
+    }
+
+    #[test]
+    fn should_read_legacy_constructors_in_execution_order() {
+        let ctors: Vec<u8> = [u64::MAX, 0x401000, 0x402000, 0x00]
+            .iter()
+            .flat_map(|addr| addr.to_le_bytes().to_vec())
+            .collect();
+        let image = generate_elf64_le_image(
+            Type::Exec,
+            vec![],
+            vec![(".ctors", section_header64(ShType::ProgBits), ctors)],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(vec![0x402000, 0x401000], elf_header.ctors(&image));
+        assert!(elf_header.dtors(&image).is_empty());
+    }
+
+    /// Encodes an `ar` member, including its header and any padding needed to
+    /// align the following member.
+    fn ar_member(name: &str, data: &[u8]) -> Vec<u8> {
+        let mut member = format!(
+            "{:<16}{:<12}{:<6}{:<6}{:<8}{:<10}`\n",
+            name,
+            0,
+            0,
+            0,
+            644,
+            data.len()
+        )
+        .into_bytes();
+        member.extend_from_slice(data);
+        if data.len() % 2 == 1 {
+            member.push(b'\n');
+        }
+
+        member
+    }
+
+    #[test]
+    fn should_parse_each_elf_member_of_an_archive() {
+        let rel = generate_elf64_le_image(Type::Rel, vec![], vec![]);
+        let long_name = "a_particularly_long_object_name.o";
+        let extended_names = format!("{}/\n", long_name);
+        let archive = [
+            b"!<arch>\n".to_vec(),
+            ar_member("//", extended_names.as_bytes()),
+            ar_member("short.o/", &rel),
+            ar_member("/0", &rel),
+        ]
+        .concat();
+
+        let members = parse_archive(&archive).unwrap();
+        let names: Vec<&str> = members.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(vec!["short.o", long_name], names);
+        assert!(members.iter().all(|(_, elf)| matches!(
+            &elf.elf,
+            ElfFile::Elf64Little(eh) if eh.file_header.r#type == Type::Rel
+        )));
+
+        assert_eq!(Err(FileErr::InvalidFile), parse_archive(&rel).map(|_| ()));
+    }
+
+    #[test]
+    fn should_resolve_section_names_on_first_use() {
+        let sections = (0..8)
+            .map(|_| ("", section_header64(ShType::ProgBits), vec![]))
+            .chain(vec![(
+                ".text",
+                section_header64(ShType::ProgBits),
+                vec![0x90],
+            )])
+            .collect();
+        let image = generate_elf64_le_image(Type::Exec, vec![], sections);
+        let parsed = ParsedElf::parse(&image).unwrap();
+
+        assert_eq!(Some(""), parsed.section_name(0));
+        assert_eq!(Some(".text"), parsed.section_name(9));
+        assert_eq!(Some(".shstrtab"), parsed.section_name(10));
+        assert_eq!(None, parsed.section_name(11));
+        assert_eq!(parsed, ParsedElf::parse(&image).unwrap());
+    }
+
+    #[test]
+    fn should_build_headers_for_each_file_type() {
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0x00,
+            vaddr: 0x400000,
+            paddr: 0x400000,
+            filesz: 0x1000,
+            memsz: 0x1000,
+            align: 0x1000,
+        };
+        let builder =
+            |r#type| ElfHeaderBuilder::<ElfAddr64, LittleEndian>::new(r#type, Machine::X86_64);
+
+        let exec = builder(Type::Exec)
+            .entry_point(0x401000)
+            .program_header(load)
+            .section_header(section_header64(ShType::Null))
+            .build()
+            .unwrap();
+        assert_eq!(EiClass::SixtyFourBit, exec.ei_ident.ei_class);
+        assert_eq!(EiData::Little, exec.ei_ident.ei_data);
+        assert_eq!(0, exec.file_header.flags);
+        assert_eq!(64, exec.file_header.eh_size);
+        assert_eq!(
+            (1, 64),
+            (exec.file_header.phnum, exec.file_header.ph_offset)
+        );
+        assert_eq!(
+            (1, 120),
+            (exec.file_header.shnum, exec.file_header.sh_offset)
+        );
+        assert_eq!(
+            Err(ValidationError::ExecutableWithoutLoadSegment),
+            builder(Type::Exec).build().map(|_| ())
+        );
+
+        let rel = builder(Type::Rel)
+            .section_header(section_header64(ShType::Null))
+            .build()
+            .unwrap();
+        assert_eq!((0, 0), (rel.file_header.phnum, rel.file_header.ph_offset));
+        assert_eq!(64, rel.file_header.sh_offset);
+        assert_eq!(
+            Err(ValidationError::RelocatableWithProgramHeaders),
+            builder(Type::Rel).program_header(load).build().map(|_| ())
+        );
+
+        assert!(builder(Type::Dyn).build().is_ok());
+        assert!(builder(Type::Core).program_header(load).build().is_ok());
+
+        let elf32 = ElfHeaderBuilder::<ElfAddr32, BigEndian>::new(Type::Rel, Machine::PPC)
+            .flags(0x8000_0000)
+            .build()
+            .unwrap();
+        assert_eq!(EiClass::ThirtyTwoBit, elf32.ei_ident.ei_class);
+        assert_eq!(EiData::Big, elf32.ei_ident.ei_data);
+        assert_eq!(
+            (52, 0x8000_0000),
+            (elf32.file_header.eh_size, elf32.file_header.flags)
+        );
+    }
+
+    #[test]
+    fn should_warn_on_structural_mismatches_with_the_file_type() {
+        let segment = |r#type| program_header64(r#type, 0x04, 0, 0, 0, 0);
+        let parse = |r#type, program_headers| {
+            let image = generate_elf64_le_image(r#type, program_headers, vec![]);
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .unwrap()
+                .unwrap()
+        };
+
+        assert_eq!(
+            vec!["EXEC (Executable file) file has no PT_LOAD segments".to_string()],
+            parse(Type::Exec, vec![segment(ProgramHeaderType::GnuStack)]).structural_warnings()
+        );
+        assert!(parse(Type::Exec, vec![segment(ProgramHeaderType::Load)])
+            .structural_warnings()
+            .is_empty());
+        assert_eq!(
+            vec!["CORE (Core file) file has no PT_NOTE segments".to_string()],
+            parse(Type::Core, vec![segment(ProgramHeaderType::Load)]).structural_warnings()
+        );
+        assert!(parse(Type::Core, vec![segment(ProgramHeaderType::Note)])
+            .structural_warnings()
+            .is_empty());
+    }
+
+    #[test]
+    fn should_decompose_mips64_relocation_info() {
+        let expected = RelocationInfo {
+            sym: 5,
+            // R_MIPS_REL32 / R_MIPS_64 / R_MIPS_NONE
+            r_type: 3,
+            r_type2: 18,
+            r_type3: 0,
+            r_ssym: 0,
+        };
+        let r_info = [0x00, 0x00, 0x12, 0x03];
+
+        let be_input = [
+            &0x1000u64.to_be_bytes()[..],
+            &5u32.to_be_bytes(),
+            &r_info,
+            &(-8i64).to_be_bytes(),
+        ]
+        .concat();
+        let be_rela = RelaParser::<ElfAddr64, BigEndian>::new()
+            .parse(&be_input)
+            .unwrap()
+            .unwrap();
+        let be_header = ElfHeaderBuilder::<ElfAddr64, BigEndian>::new(Type::Rel, Machine::MIPS)
+            .build()
+            .unwrap();
+        assert_eq!(0x1000, be_rela.r_offset);
+        assert_eq!(-8, be_rela.r_addend);
+        assert_eq!(expected, be_header.relocation_info(&be_rela));
+
+        let le_input = [
+            &0x1000u64.to_le_bytes()[..],
+            &5u32.to_le_bytes(),
+            &r_info,
+            &(-8i64).to_le_bytes(),
+        ]
+        .concat();
+        let le_rela = RelaParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&le_input)
+            .unwrap()
+            .unwrap();
+        let le_header = ElfHeaderBuilder::<ElfAddr64, LittleEndian>::new(Type::Rel, Machine::MIPS)
+            .build()
+            .unwrap();
+        assert_eq!(expected, le_header.relocation_info(&le_rela));
+
+        let x86_64 = ElfHeaderBuilder::<ElfAddr64, LittleEndian>::new(Type::Rel, Machine::X86_64)
+            .build()
+            .unwrap();
+        assert_eq!(
+            RelocationInfo {
+                sym: 5,
+                r_type: 7,
+                r_type2: 0,
+                r_type3: 0,
+                r_ssym: 0,
+            },
+            x86_64.relocation_info(&Rela64 {
+                r_offset: 0x1000,
+                r_info: (5 << 32) | 7,
+                r_addend: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn should_compute_the_expected_min_size_of_a_fixture() {
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![
+                (
+                    ".text",
+                    section_header64(ShType::ProgBits),
+                    vec![0x90; 0x21],
+                ),
+                (
+                    ".bss",
+                    SectionHeader64 {
+                        sh_size: 0x1000,
+                        ..section_header64(ShType::NoBits)
+                    },
+                    vec![],
+                ),
+            ],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(image.len() as u64, elf_header.expected_min_size());
+    }
+
+    #[test]
+    fn should_decode_file_scoped_arm_attributes() {
+        let attributes = [
+            vec![0x05], // Tag_CPU_name
+            b"7-A\0".to_vec(),
+            vec![0x06, 0x0a], // Tag_CPU_arch: ARMv7
+            vec![0x0a, 0x03], // Tag_FP_arch: VFPv3
+        ]
+        .concat();
+        let file_scope = [
+            vec![0x01],
+            ((attributes.len() + 5) as u32).to_le_bytes().to_vec(),
+            attributes,
+        ]
+        .concat();
+        let subsection = [b"aeabi\0".to_vec(), file_scope].concat();
+        let section = [
+            vec![b'A'],
+            ((subsection.len() + 4) as u32).to_le_bytes().to_vec(),
+            subsection,
+        ]
+        .concat();
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![(
+                ".ARM.attributes",
+                section_header64(ShType::ArmAttributes),
+                section,
+            )],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        let attributes = elf_header.arm_attributes(&image).unwrap();
+        assert_eq!(Some(&ArmAttributeValue::Integer(10)), attributes.get(&6));
+        assert_eq!(
+            Some(&ArmAttributeValue::String("7-A".to_string())),
+            attributes.get(&5)
+        );
+        assert_eq!(3, attributes.len());
+    }
+
+    #[test]
+    fn should_match_addresses_of_either_width() {
+        let input = [0x00, 0x10, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff];
+
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[4..], 0x401000u32))),
+            match_addr::<ElfAddr32>(EiData::Little).parse(&input)
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[8..], 0x401000u64))),
+            match_addr::<ElfAddr64>(EiData::Little).parse(&input)
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[4..], 0x00104000u32))),
+            expect_addr::<ElfAddr32>(EiData::Big, 0x00104000).parse(&input)
+        );
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&input[..])),
+            expect_addr::<ElfAddr64>(EiData::Little, 0x401001).parse(&input)
+        );
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&input[4..])),
+            match_addr::<ElfAddr64>(EiData::Little).parse(&input[4..])
+        );
+        assert_eq!(
+            None,
+            <ElfAddr32 as AddressWidth>::from_bytes(EiData::Little, &input)
+        );
+    }
+
+    #[test]
+    fn should_return_data_appended_past_the_expected_min_size() {
+        let mut image = generate_dynamic_elf64_le_image(Type::Dyn, vec![], b"\0");
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        assert!(elf_header.trailing_data(&image).is_empty());
+
+        image.extend_from_slice(&[0xa5; 16]);
+        assert_eq!(&[0xa5; 16], elf_header.trailing_data(&image));
+    }
+
+    #[test]
+    fn should_construct_file_header_parsers_for_either_width() {
+        let parser = FileHeaderParser::<ElfAddr32, LittleEndian>::new();
+        assert_eq!(ParseOptions::default(), parser.options);
+
+        let parser = FileHeaderParser::<ElfAddr64, BigEndian>::new();
+        assert_eq!(ParseOptions::default(), parser.options);
+    }
+
+    #[test]
+    fn should_query_the_gnu_hash_bloom_filter() {
+        let (bloom_shift, present) = (6u32, gnu_hash(b"printf"));
+        let h2 = present >> bloom_shift;
+        let bloom_word: u64 = (1 << (present % 64)) | (1 << (h2 % 64));
+
+        let gnu_hash_data = [1u32, 1, 1, bloom_shift]
+            .iter()
+            .flat_map(|v| v.to_le_bytes().to_vec())
+            .chain(bloom_word.to_le_bytes().to_vec())
+            .collect();
+
+        let image = generate_elf64_le_image(
+            Type::Dyn,
+            vec![],
+            vec![(
+                ".gnu.hash",
+                section_header64(ShType::GnuHash),
+                gnu_hash_data,
+            )],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert!(elf_header.gnu_hash_maybe_contains("printf", &image));
+        assert!(!elf_header.gnu_hash_maybe_contains("not_a_libc_symbol", &image));
+    }
+
+    #[test]
+    fn should_look_up_dynamic_symbols_by_the_available_hash_table() {
+        let words = |words: &[u32]| -> Vec<u8> {
+            words
+                .iter()
+                .flat_map(|w| w.to_le_bytes().to_vec())
+                .collect()
+        };
+        assert_eq!(0x0779_05a6, elf_hash(b"printf"));
+
+        let dynstr = b"\0foo\0bar\0".to_vec();
+        let dynsym = [
+            symbol64(0, 0x00, 0, 0x0000),
+            symbol64(1, 0x12, 1, 0x1000),
+            symbol64(5, 0x12, 1, 0x2000),
+        ]
+        .concat();
+
+        // a single bucket chains foo then bar.
+        let (foo, bar) = (gnu_hash(b"foo"), gnu_hash(b"bar"));
+        let bloom_word: u64 = [foo, bar]
+            .iter()
+            .fold(0, |word, h| word | (1 << (h % 64)) | (1 << ((h >> 6) % 64)));
+        let gnu_hash_data = [
+            words(&[1, 1, 1, 6]),
+            bloom_word.to_le_bytes().to_vec(),
+            words(&[1, foo & !1, bar | 1]),
+        ]
+        .concat();
+        // bar's bucket leads to bar, which chains to foo.
+        let sysv_hash_data = words(&[1, 3, 2, 0, 0, 1]);
+
+        let lookup = |hash_tables: Vec<(&str, ShType, Vec<u8>)>, name| {
+            let mut sections = vec![
+                (".dynstr", section_header64(ShType::StrTab), dynstr.clone()),
+                (
+                    ".dynsym",
+                    SectionHeader64 {
+                        sh_link: 1,
+                        sh_info: 1,
+                        sh_entsize: 24,
+                        ..section_header64(ShType::DynSym)
+                    },
+                    dynsym.clone(),
+                ),
+            ];
+            sections.extend(
+                hash_tables
+                    .into_iter()
+                    .map(|(name, r#type, data)| (name, section_header64(r#type), data)),
+            );
+
+            let image = generate_elf64_le_image(Type::Dyn, vec![], sections);
+            let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .unwrap()
+                .unwrap();
+            elf_header
+                .lookup_dynamic_symbol(&image, name)
+                .map(|symbol| symbol.st_value)
+        };
+        let gnu = (".gnu.hash", ShType::GnuHash, gnu_hash_data);
+        let sysv = (".hash", ShType::Hash, sysv_hash_data);
+
+        for hash_tables in [vec![gnu.clone()], vec![sysv], vec![]] {
+            assert_eq!(Some(0x1000), lookup(hash_tables.clone(), "foo"));
+            assert_eq!(Some(0x2000), lookup(hash_tables.clone(), "bar"));
+            assert_eq!(None, lookup(hash_tables, "baz"));
+        }
+
+        // .gnu.hash is preferred, so an empty .hash bucket is never consulted.
+        let empty_sysv = (".hash", ShType::Hash, words(&[1, 3, 0, 0, 0, 0]));
+        assert_eq!(Some(0x1000), lookup(vec![gnu, empty_sysv.clone()], "foo"));
+        assert_eq!(None, lookup(vec![empty_sysv], "foo"));
+
+        // bucket and chain counts far beyond the table are malformed.
+        let oversized_sysv = words(&[u32::MAX, u32::MAX, 1, 0]);
+        assert_eq!(
+            None,
+            sysv_hash_lookup(EiData::Little, &oversized_sysv, "foo", |_| true)
+        );
+    }
+
+    #[test]
+    fn should_attribute_file_ranges_to_sections() {
+        let image = generate_elf64_le_image(
+            Type::Exec,
+            vec![],
+            vec![
+                (".text", section_header64(ShType::ProgBits), vec![0x90; 16]),
+                (
+                    ".bss",
+                    SectionHeader64 {
+                        sh_size: 0x100,
+                        ..section_header64(ShType::NoBits)
+                    },
+                    vec![],
+                ),
+            ],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        let text = &elf_header.section_headers()[1];
+        let offset = text.sh_offset + 4;
+
+        let names = |indices: Vec<usize>| -> Vec<&str> {
+            indices
+                .iter()
+                .filter_map(|&idx| {
+                    elf_header.section_name(&elf_header.section_headers()[idx], &image)
+                })
+                .collect()
+        };
+        assert_eq!(
+            vec![".text"],
+            names(elf_header.sections_in_range(offset, 1))
+        );
+        assert_eq!(
+            vec![".text", ".shstrtab"],
+            names(elf_header.sections_in_range(offset, 0x100))
+        );
+        assert!(elf_header.sections_in_range(text.sh_offset, 0).is_empty());
+        assert!(elf_header.sections_in_range(0, 0x40).is_empty());
+    }
+
+    #[test]
+    fn should_translate_between_runtime_and_file_addresses() {
+        let image = generate_elf64_le_image(
+            Type::Dyn,
+            vec![
+                program_header64(ProgramHeaderType::Load, 0x05, 0x0, 0x0, 0x1000, 0x1000),
+                program_header64(ProgramHeaderType::Load, 0x05, 0x2000, 0x2000, 0x800, 0x800),
+            ],
+            vec![],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        let load_base = 0x5555_5555_4000;
+
+        assert_eq!(
+            Some(0x2010),
+            elf_header.runtime_to_file_vaddr(0x5555_5555_6010, load_base)
+        );
+        assert_eq!(
+            Some(0x5555_5555_6010),
+            elf_header.file_to_runtime_vaddr(0x2010, load_base)
+        );
+
+        // the gap between segments, past the last segment and below the base.
+        assert_eq!(
+            None,
+            elf_header.runtime_to_file_vaddr(0x5555_5555_5800, load_base)
+        );
+        assert_eq!(
+            None,
+            elf_header.runtime_to_file_vaddr(0x5555_5555_6800, load_base)
+        );
+        assert_eq!(None, elf_header.runtime_to_file_vaddr(0x1000, load_base));
+        assert_eq!(None, elf_header.file_to_runtime_vaddr(0x1800, load_base));
+    }
+
+    #[test]
+    fn should_warn_of_nonstandard_file_header_values() {
+        let input: Vec<u8> = generate_file_header!();
+        assert_eq!(
+            vec![
+                "eh_size 0 does not match the expected header size 52",
+                "shstrndx 1 is out of range for 1 section headers",
+                // shoff points back into the file header itself.
+                "section header 0 has a nonzero sh_addr",
+            ],
+            warnings(&input)
+        );
+
+        // an unknown OS/ABI, no headers and a zero entry point on an executable.
+        let mut input: Vec<u8> = generate_file_header!();
+        input[7] = 0x42;
+        input[16] = 0x02;
+        input[24..28].copy_from_slice(&[0x00; 4]);
+        input[40..42].copy_from_slice(&[0x34, 0x00]);
+        input[44..46].copy_from_slice(&[0x00; 2]);
+        input[48..50].copy_from_slice(&[0x00; 2]);
+        input[50..52].copy_from_slice(&[0x00; 2]);
+        assert_eq!(
+            vec![
+                "unknown OS/ABI",
+                "EXEC (Executable file) file has no program headers",
+                "file has no section headers",
+                "executable has an entry point of 0",
+            ],
+            warnings(&input)
+        );
+
+        assert!(warnings(&[0x7f, 0x45]).is_empty());
+    }
+
+    #[test]
+    fn should_round_trip_idents_through_their_serialized_bytes() {
+        let classes = [EiClass::ThirtyTwoBit, EiClass::SixtyFourBit];
+        let encodings = [EiData::Little, EiData::Big];
+
+        for (&ei_class, &ei_data) in classes
+            .iter()
+            .flat_map(|class| encodings.iter().map(move |data| (class, data)))
+        {
+            let ident = EiIdent {
+                ei_class,
+                ei_data,
+                ei_version: EiVersion::One,
+                ei_osabi: EiOsAbi::FreeBSD,
+                ei_abiversion: EiAbiVersion::One,
+                pad: [0; 7],
+            };
+            let bytes = ident.serialize();
+
+            assert_eq!([0x7f, 0x45, 0x4c, 0x46], bytes[..4]);
+            assert_eq!([0x00; 7], bytes[9..]);
+            assert_eq!(
+                Ok(MatchStatus::Match((&[][..], ident))),
+                EiIdentParser::new().parse(&bytes)
+            );
+            assert_eq!(bytes.to_vec(), Vec::<u8>::from(ident));
+        }
+    }
+
+    #[test]
+    fn should_build_an_ident() {
+        let ident = EiIdent::builder()
+            .class(EiClass::SixtyFourBit)
+            .data(EiData::Little)
+            .osabi(EiOsAbi::SysV)
+            .build();
+
+        assert_eq!(
+            [
+                0x7f, 0x45, 0x4c, 0x46, 0x02, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00
+            ],
+            ident.serialize()
+        );
+        assert_eq!(
+            EiIdent {
+                ei_class: EiClass::ThirtyTwoBit,
+                ei_data: EiData::Big,
+                ..ident
+            },
+            EiIdent::builder()
+                .class(EiClass::ThirtyTwoBit)
+                .data(EiData::Big)
+                .build()
+        );
+    }
+
+    #[test]
+    fn should_reject_files_exceeding_the_parse_limits() {
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![(
+                ".symtab",
+                SectionHeader64 {
+                    sh_entsize: 24,
+                    ..section_header64(ShType::SymTab)
+                },
+                vec![0x00; 24 * 100],
+            )],
+        );
+        let limits = ParseLimits {
+            max_symbols: 10,
+            ..ParseLimits::default()
+        };
+
+        assert!(parse_elf(&image).is_ok());
+        assert_eq!(
+            Err(FileErr::LimitExceeded),
+            parse_elf_with_limits(&image, limits).map(|_| ())
+        );
+
+        // the header parser defers the symbol limit to the parsed header.
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .with_limits(limits)
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        let symtab = elf_header.section_by_name(".symtab", &image).unwrap();
+        assert!(elf_header.symbols(symtab, &image).is_empty());
+        assert_eq!(
+            100,
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .unwrap()
+                .unwrap()
+                .symbols(symtab, &image)
+                .len()
+        );
+
+        // three sections, including the null section and .shstrtab.
+        let limits = ParseLimits {
+            max_sections: 2,
+            ..ParseLimits::default()
+        };
+        assert_eq!(
+            Err(FileErr::LimitExceeded),
+            parse_elf_with_limits(&image, limits).map(|_| ())
+        );
+        assert_eq!(
+            Err(FileErr::LimitExceeded.to_string()),
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .with_limits(limits)
+                .parse(&image)
+                .map(|_| ())
+        );
+    }
+
+    #[test]
+    fn should_reject_a_section_header_table_past_the_end_of_the_input() {
+        let mut image = generate_elf64_le_image(Type::Rel, vec![], vec![]);
+        let sh_offset = (image.len() as u64 + 0x100).to_le_bytes();
+        image[0x28..0x30].copy_from_slice(&sh_offset);
+
+        assert_eq!(Err(FileErr::InvalidFile), parse_elf(&image).map(|_| ()));
+        assert!(matches!(
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new().parse(&image),
+            Ok(MatchStatus::NoMatch(_))
+        ));
+    }
+
+    #[test]
+    fn should_iterate_dynamic_entries_up_to_dt_null() {
+        let dynamic = [
+            dynamic_entry64(DynamicTag::Needed, 0x01),
+            dynamic_entry64(DynamicTag::BindNow, 0x00),
+            dynamic_entry64(DynamicTag::Null, 0x00),
+            dynamic_entry64(DynamicTag::Needed, 0x05),
+            vec![0xff; 3],
+        ]
+        .concat();
+        let image = generate_elf64_le_image(
+            Type::Dyn,
+            vec![],
+            vec![(".dynamic", section_header64(ShType::Dynamic), dynamic)],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        let tags: Vec<Option<DynamicTag>> = elf_header
+            .dynamic_entries(&image)
+            .map(|entry| entry.tag())
+            .collect();
+        assert_eq!(
+            vec![Some(DynamicTag::Needed), Some(DynamicTag::BindNow)],
+            tags
+        );
+        assert_eq!(4, elf_header.dynamic(&image).len());
+
+        let image = generate_elf64_le_image(Type::Dyn, vec![], vec![]);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        assert_eq!(0, elf_header.dynamic_entries(&image).count());
+    }
+
+    #[test]
+    fn should_map_symbols_to_their_sections() {
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![(".text", section_header64(ShType::ProgBits), vec![0x90; 16])],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        let symbol = |st_shndx| symbol64_entry(0x12, st_shndx, 0x10);
+
+        let text = symbol(1);
+        assert_eq!(None, text.special_section_index());
+        assert_eq!(
+            Some(".text"),
+            elf_header
+                .symbol_section(&text)
+                .and_then(|sh| elf_header.section_name(sh, &image))
+        );
+
+        let absolute = symbol(SHN_ABS);
+        assert_eq!(None, elf_header.symbol_section(&absolute));
+        assert_eq!(
+            Some(SpecialSectionIndex::Absolute),
+            absolute.special_section_index()
+        );
+
+        let undefined = symbol(SHN_UNDEF);
+        assert_eq!(None, elf_header.symbol_section(&undefined));
+        assert_eq!(
+            Some(SpecialSectionIndex::Undefined),
+            undefined.special_section_index()
+        );
+
+        assert_eq!(None, elf_header.symbol_section(&symbol(42)));
+    }
+
+    #[test]
+    fn should_parse_gnu_symbol_version_section_types() {
+        for &sh_type in [ShType::GnuVerDef, ShType::GnuVerNeed, ShType::GnuVerSym].iter() {
+            assert_eq!(
+                Ok(MatchStatus::Match((&[][..], sh_type))),
+                ShTypeParser::<LittleEndian>::new().parse(&(sh_type as u32).to_le_bytes())
+            );
+        }
+    }
+
+    #[test]
+    fn should_resolve_symbol_versions() {
+        let u16s = |values: &[u16]| -> Vec<u8> {
+            values
+                .iter()
+                .flat_map(|v| v.to_le_bytes().to_vec())
+                .collect()
+        };
+        let u32s = |values: &[u32]| -> Vec<u8> {
+            values
+                .iter()
+                .flat_map(|v| v.to_le_bytes().to_vec())
+                .collect()
+        };
+        let dynstr = b"\0printf\0libc.so.6\0GLIBC_2.2.5\0foo\0libfoo.so\0V1\0".to_vec();
+        let dynsym = [
+            symbol64(0, 0x00, 0, 0x0),
+            symbol64(1, 0x12, 0, 0x0),
+            symbol64(30, 0x12, 1, 0x1000),
+            symbol64(30, 0x12, 1, 0x1000),
+        ]
+        .concat();
+        // foo's default version is hidden in the last entry.
+        let versym = u16s(&[0, 2, 3, 0x8003]);
+        let verneed = [
+            u16s(&[1, 1]),
+            u32s(&[8, 16, 0]),
+            u32s(&[elf_hash(b"GLIBC_2.2.5")]),
+            u16s(&[0, 2]),
+            u32s(&[18, 0]),
+        ]
+        .concat();
+        let verdef = [
+            u16s(&[1, 1, 1, 1]),
+            u32s(&[elf_hash(b"libfoo.so"), 20, 28]),
+            u32s(&[34, 0]),
+            u16s(&[1, 0, 3, 1]),
+            u32s(&[elf_hash(b"V1"), 20, 0]),
+            u32s(&[44, 0]),
+        ]
+        .concat();
+
+        let linked = |r#type, sh_link| SectionHeader64 {
+            sh_link,
+            ..section_header64(r#type)
+        };
+        let image = generate_elf64_le_image(
+            Type::Dyn,
+            vec![],
+            vec![
+                (".dynstr", section_header64(ShType::StrTab), dynstr),
+                (".dynsym", linked(ShType::DynSym, 1), dynsym),
+                (".gnu.version", linked(ShType::GnuVerSym, 2), versym),
+                (".gnu.version_r", linked(ShType::GnuVerNeed, 1), verneed),
+                (".gnu.version_d", linked(ShType::GnuVerDef, 1), verdef),
+            ],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            vec![
+                ("".to_string(), None),
+                ("printf".to_string(), Some("GLIBC_2.2.5".to_string())),
+                ("foo".to_string(), Some("V1".to_string())),
+                ("foo".to_string(), Some("V1".to_string())),
+            ],
+            elf_header.symbol_versions(&image)
+        );
+    }
+
+    #[test]
+    fn should_display_binutils_machine_and_osabi_names() {
+        let machines = [
+            (Machine::X86_64, "Advanced Micro Devices X86-64"),
+            (Machine::AMD64, "Advanced Micro Devices X86-64"),
+            (Machine::MIPS, "MIPS R3000"),
+            (Machine::PPC, "PowerPC"),
+            (Machine::PPC64, "PowerPC64"),
+            (Machine::ARM, "ARM"),
+            (Machine::AARCH64, "AArch64"),
+            (Machine::BPF, "Linux BPF"),
+        ];
+        for (machine, expected) in machines.iter() {
+            assert_eq!(*expected, machine.to_string());
+        }
+
+        let abis = [
+            (EiOsAbi::SysV, "UNIX - System V"),
+            (EiOsAbi::Linux, "UNIX - GNU"),
+            (EiOsAbi::GNUHurd, "GNU/Hurd"),
+            (EiOsAbi::OpenBSD, "UNIX - OpenBSD"),
+        ];
+        for (abi, expected) in abis.iter() {
+            assert_eq!(*expected, abi.to_string());
+        }
+    }
+
+    #[test]
+    fn should_transparently_decompress_compressed_sections() {
+        let contents = b"DWARF .debug_info contents ".repeat(8);
+        let chdr: Vec<u8> = [1u32, 0]
+            .iter()
+            .flat_map(|v| v.to_le_bytes().to_vec())
+            .chain((contents.len() as u64).to_le_bytes().to_vec())
+            .chain(1u64.to_le_bytes().to_vec())
+            .collect();
+        let zlib_stream = [
+            0x78, 0x9c, 0x73, 0x09, 0x77, 0x0c, 0x72, 0x53, 0xd0, 0x4b, 0x49, 0x4d, 0x2a, 0x4d,
+            0x8f, 0xcf, 0xcc, 0x4b, 0xcb, 0x57, 0x48, 0xce, 0xcf, 0x2b, 0x49, 0xcd, 0x2b, 0x29,
+            0x56, 0x70, 0x19, 0xca, 0x52, 0x00, 0x04, 0x48, 0x4c, 0x11,
+        ];
+        let compressed: Vec<u8> = chdr
+            .into_iter()
+            .chain(zlib_stream.iter().copied())
+            .collect();
+
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![
+                (
+                    ".debug_info",
+                    SectionHeader64 {
+                        sh_flags: ShFlags64::from(SHF_ALLOC | SHF_COMPRESSED),
+                        ..section_header64(ShType::ProgBits)
+                    },
+                    compressed.clone(),
+                ),
+                (
+                    ".debug_str",
+                    SectionHeader64 {
+                        sh_flags: ShFlags64::Other(0x30),
+                        ..section_header64(ShType::ProgBits)
+                    },
+                    b"main\0".to_vec(),
+                ),
+            ],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        let debug_info = &elf_header.section_headers[1];
+        assert_eq!(Some(&compressed[..]), debug_info.data(&image));
+        assert_eq!(
+            Some(&contents[..]),
+            elf_header
+                .section_data_decompressed(debug_info, &image)
+                .as_deref()
+        );
+
+        let debug_str = &elf_header.section_headers[2];
+        assert_eq!(
+            Some(&b"main\0"[..]),
+            elf_header
+                .section_data_decompressed(debug_str, &image)
+                .as_deref()
+        );
+    }
+
+    #[test]
+    fn should_inflate_stored_and_dynamic_huffman_blocks() {
+        let stored = [
+            0x78, 0x01, 0x01, 0x06, 0x00, 0xf9, 0xff, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x09,
+            0x3c, 0x02, 0x92,
+        ];
+        assert_eq!(Some(b"stored".to_vec()), zlib_inflate(&stored, 6));
+
+        let dynamic = [
+            0x78, 0xda, 0x05, 0xc1, 0x01, 0x01, 0x00, 0x00, 0x08, 0xc3, 0xa0, 0xac, 0xec, 0xf6,
+            0xcf, 0x20, 0x68, 0xac, 0x85, 0x4c, 0x9d, 0x07, 0x50, 0x50, 0x07, 0xa6,
+        ];
+        assert_eq!(
+            Some(b"abcaacbcbaaabacabbda".to_vec()),
+            zlib_inflate(&dynamic, 20)
+        );
+        assert_eq!(None, zlib_inflate(&dynamic, 19));
+    }
+
+    #[test]
+    fn should_reject_corrupt_or_oversized_zlib_streams() {
+        let stream = [
+            0x78, 0x01, 0x01, 0x06, 0x00, 0xf9, 0xff, 0x73, 0x74, 0x6f, 0x72, 0x65, 0x64, 0x09,
+            0x3c, 0x02, 0x92,
+        ];
+
+        let mut bad_adler = stream;
+        bad_adler[16] ^= 0xff;
+        assert_eq!(None, zlib_inflate(&bad_adler, 6));
+        assert_eq!(None, zlib_inflate(&stream[..10], 6));
+        // inflating past the declared size stops rather than growing.
+        assert_eq!(None, zlib_inflate(&stream, 5));
+
+        let header = |ch_size| CompressionHeader {
+            ch_type: CompressionType::Zlib,
+            ch_size,
+            ch_addralign: 1,
+        };
+        assert_eq!(
+            Some(b"stored".to_vec()),
+            decompress_section(&header(6), &stream)
+        );
+        assert_eq!(None, decompress_section(&header(5), &stream));
+        assert_eq!(None, decompress_section(&header(7), &stream));
+        assert_eq!(None, decompress_section(&header(u64::MAX), &stream));
+    }
+
+    #[test]
+    fn should_describe_sh_link_and_sh_info_by_section_type() {
+        let linked = |sh_type, sh_link, sh_info| SectionHeader64 {
+            sh_link,
+            sh_info,
+            ..section_header64(sh_type)
+        };
+
+        let symtab = linked(ShType::SymTab, 3, 12);
+        assert_eq!(LinkMeaning::StringTable(3), symtab.link_meaning());
+        assert_eq!(LinkMeaning::FirstNonLocalSymbol(12), symtab.info_meaning());
+
+        for sh_type in [ShType::Rel, ShType::Rela].iter() {
+            let rel = linked(*sh_type, 2, 1);
+            assert_eq!(LinkMeaning::SymbolTable(2), rel.link_meaning());
+            assert_eq!(LinkMeaning::TargetSection(1), rel.info_meaning());
+        }
+
+        let dynamic = linked(ShType::Dynamic, 5, 0);
+        assert_eq!(LinkMeaning::StringTable(5), dynamic.link_meaning());
+        assert_eq!(LinkMeaning::None, dynamic.info_meaning());
+
+        let hash = linked(ShType::Hash, 4, 0);
+        assert_eq!(LinkMeaning::SymbolTable(4), hash.link_meaning());
+        assert_eq!(LinkMeaning::None, hash.info_meaning());
+    }
+
+    #[test]
+    fn should_validate_eh_size_against_the_class() {
+        let input: Vec<u8> = generate_file_header!();
+        let file_header = FileHeaderParser::<ElfAddr32, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            Err(ValidationError::EhSizeMismatch {
+                expected: 52,
+                found: 0
+            }),
+            file_header.validate_eh_size(EiClass::ThirtyTwoBit)
+        );
+
+        let file_header = FileHeader {
+            eh_size: 52,
+            ..file_header
+        };
+        assert_eq!(Ok(()), file_header.validate_eh_size(EiClass::ThirtyTwoBit));
+        assert!(file_header.validate_eh_size(EiClass::SixtyFourBit).is_err());
+    }
+
+    #[test]
+    fn should_collect_resolved_symbols_that_outlive_the_image() {
+        let owned: Vec<OwnedResolvedSymbol> = {
+            let image = generate_elf64_le_image(
+                Type::Rel,
+                vec![],
+                vec![
+                    (
+                        ".strtab",
+                        section_header64(ShType::StrTab),
+                        b"\0main\0".to_vec(),
+                    ),
+                    (
+                        ".symtab",
+                        SectionHeader64 {
+                            sh_link: 1,
+                            sh_info: 1,
+                            sh_entsize: 24,
+                            ..section_header64(ShType::SymTab)
+                        },
+                        [symbol64(0, 0x00, 0, 0), symbol64(1, 0x12, 1, 0x1000)].concat(),
+                    ),
+                ],
+            );
+            let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .unwrap()
+                .unwrap();
+
+            elf_header
+                .resolved_symbols(&elf_header.section_headers[2], &image)
+                .into_iter()
+                .map(ResolvedSymbol::into_owned)
+                .collect()
+        };
+
+        assert_eq!(2, owned.len());
+        assert_eq!(
+            OwnedResolvedSymbol {
+                name: Some("main".to_string()),
+                value: 0x1000,
+                size: 0,
+                binding: Some(SymbolBinding::Global),
+                symbol_type: Some(SymbolType::Func),
+                visibility: SymbolVisibility::Default,
+                shndx: 1,
+                source: SymbolSource::SymTab,
+            },
+            owned[1]
+        );
+    }
+
+    #[test]
+    fn parsed_types_should_be_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+
+        assert_send_sync::<ElfFile>();
+        assert_send_sync::<ParsedElf<'_>>();
+        assert_send_sync::<LoadableElf>();
+        assert_send_sync::<ElfHeader32<LittleEndian>>();
+        assert_send_sync::<ElfHeader32<BigEndian>>();
+        assert_send_sync::<ElfHeader64<LittleEndian>>();
+        assert_send_sync::<ElfHeader64<BigEndian>>();
+        assert_send_sync::<ElfHeaderBuilder<ElfAddr64, LittleEndian>>();
+        assert_send_sync::<EiIdent>();
+        assert_send_sync::<FileHeader<ElfAddr32>>();
+        assert_send_sync::<FileHeader<ElfAddr64>>();
+        assert_send_sync::<ProgramHeader32>();
+        assert_send_sync::<ProgramHeader64>();
+        assert_send_sync::<SectionHeader32>();
+        assert_send_sync::<SectionHeader64>();
+        assert_send_sync::<Symbol32>();
+        assert_send_sync::<Symbol64>();
+        assert_send_sync::<ResolvedSymbol<'_>>();
+        assert_send_sync::<OwnedResolvedSymbol>();
+        assert_send_sync::<DynamicEntry32>();
+        assert_send_sync::<DynamicEntry64>();
+        assert_send_sync::<Rela32>();
+        assert_send_sync::<Rela64>();
+        assert_send_sync::<DebugLineHeader>();
+        assert_send_sync::<ArHeader>();
+        assert_send_sync::<FileErr>();
+        assert_send_sync::<ValidationError>();
+        assert_send_sync::<ElfHeaderParser<ElfAddr64, LittleEndian>>();
+        assert_send_sync::<FileHeaderParser<ElfAddr32, BigEndian>>();
+    }
+
+    #[test]
+    fn should_check_the_phdr_segment_describes_the_program_header_table() {
+        let phdr = ProgramHeader64 {
+            r#type: ProgramHeaderType::PhDr,
+            flags: 0x04,
+            offset: 0x40,
+            vaddr: 0x400040,
+            paddr: 0x400040,
+            filesz: 0x38,
+            memsz: 0x38,
+            align: 0x08,
+        };
+        let image = generate_elf64_le_image(Type::Exec, vec![phdr], vec![]);
+        let mut elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert!(elf_header.phdr_is_consistent());
+
+        elf_header.program_headers[0].offset = 0x1000;
+        assert!(!elf_header.phdr_is_consistent());
+
+        elf_header.program_headers.clear();
+        assert!(elf_header.phdr_is_consistent());
+    }
+
+    #[test]
+    fn should_construct_parsers_for_each_class_and_encoding() {
+        let le64 = generate_elf64_le_image(Type::Exec, vec![], vec![]);
+        let elf64 = ElfHeaderParser::elf64_le().parse(&le64).unwrap().unwrap();
+        assert_eq!(
+            elf64.file_header,
+            FileHeaderParser::elf64_le().parse(&le64).unwrap().unwrap()
+        );
+
+        let file_header = FileHeader {
+            sh_offset: 0,
+            shnum: 0,
+            ..elf64.file_header
+        };
+        let be64 = [
+            le64[..16].to_vec(),
+            Serialize::<ElfAddr64, BigEndian>::serialize(&file_header),
+        ]
+        .concat();
+        assert_eq!(
+            file_header,
+            FileHeaderParser::elf64_be().parse(&be64).unwrap().unwrap()
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((
+                &be64[..],
+                ElfHeader64::new(elf64.ei_ident, file_header, vec![], vec![])
+            ))),
+            ElfHeaderParser::elf64_be().parse(&be64)
+        );
+
+        let le32: Vec<u8> = generate_file_header!();
+        let ei_ident = EiIdentParser::new().parse(&le32).unwrap().unwrap();
+        let file_header = FileHeader {
+            phnum: 0,
+            sh_offset: 0,
+            shnum: 0,
+            ..FileHeaderParser::elf32_le().parse(&le32).unwrap().unwrap()
+        };
+        let le32 = [
+            le32[..16].to_vec(),
+            Serialize::<ElfAddr32, LittleEndian>::serialize(&file_header),
+        ]
+        .concat();
+        let be32 = [
+            le32[..16].to_vec(),
+            Serialize::<ElfAddr32, BigEndian>::serialize(&file_header),
+        ]
+        .concat();
+        assert_eq!(
+            file_header,
+            FileHeaderParser::elf32_be().parse(&be32).unwrap().unwrap()
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((
+                &le32[..],
+                ElfHeader32::new(ei_ident, file_header, vec![], vec![])
+            ))),
+            ElfHeaderParser::elf32_le().parse(&le32)
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((
+                &be32[..],
+                ElfHeader32::new(ei_ident, file_header, vec![], vec![])
+            ))),
+            ElfHeaderParser::elf32_be().parse(&be32)
+        );
+
+        let program_header32 = ProgramHeader32 {
+            r#type: ProgramHeaderType::Null,
+            offset: 0,
+            vaddr: 0,
+            paddr: 0,
+            filesz: 0,
+            memsz: 0,
+            flags: 0,
+            align: 0,
+        };
+        let program_header: Vec<u8> = generate_program_header!();
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], program_header32))),
+            ProgramHeaderParser::elf32_le().parse(&program_header)
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], program_header32))),
+            ProgramHeaderParser::elf32_be().parse(&program_header)
+        );
+        let zeroed_program_header64 = ProgramHeader64 {
+            align: 0,
+            ..program_header64(ProgramHeaderType::Null, 0, 0, 0, 0, 0)
+        };
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], zeroed_program_header64))),
+            ProgramHeaderParser::elf64_le().parse(&[0u8; 56])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], zeroed_program_header64))),
+            ProgramHeaderParser::elf64_be().parse(&[0u8; 56])
+        );
+
+        let section_header32 = SectionHeader32 {
+            sh_name: 0,
+            sh_type: ShType::Null,
+            sh_flags: ShFlags32::Other(0),
+            sh_addr: 0,
+            sh_offset: 0,
+            sh_size: 0,
+            sh_link: 0,
+            sh_info: 0,
+            sh_addr_align: 0,
+            sh_entsize: 0,
+        };
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], section_header32))),
+            SectionHeaderParser::elf32_le().parse(&[0u8; 40])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], section_header32))),
+            SectionHeaderParser::elf32_be().parse(&[0u8; 40])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((
+                &[][..],
+                section_header64(ShType::Null)
+            ))),
+            SectionHeaderParser::elf64_le().parse(&[0u8; 64])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((
+                &[][..],
+                section_header64(ShType::Null)
+            ))),
+            SectionHeaderParser::elf64_be().parse(&[0u8; 64])
+        );
+    }
+
+    #[test]
+    fn should_return_the_bytes_of_the_file_header() {
+        let image = generate_dynamic_elf64_le_image(Type::Dyn, vec![], b"\0");
+        let elf_header = ElfHeaderParser::elf64_le().parse(&image).unwrap().unwrap();
+
+        let header_bytes = elf_header.file_header_bytes(&image);
+        assert_eq!(64, header_bytes.len());
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], elf_header.file_header))),
+            FileHeaderParser::elf64_le().parse(header_bytes)
+        );
+        assert_eq!(
+            elf_header.ei_ident,
+            EiIdentParser::new().parse(header_bytes).unwrap().unwrap()
+        );
+    }
+
+    #[test]
+    fn should_rebuild_a_parsed_header_with_an_added_section() {
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0x00,
+            vaddr: 0x400000,
+            paddr: 0x400000,
+            filesz: 0x1000,
+            memsz: 0x1000,
+            align: 0x1000,
+        };
+        let image = generate_elf64_le_image(
+            Type::Exec,
+            vec![load],
+            vec![(".text", section_header64(ShType::ProgBits), vec![0x90; 16])],
+        );
+        let parsed = ElfHeaderParser::elf64_le().parse(&image).unwrap().unwrap();
+        let original_sections = parsed.section_headers.clone();
+
+        let rebuilt = parsed
+            .into_builder()
+            .section_header(section_header64(ShType::NoBits))
+            .build()
+            .unwrap();
+        assert_eq!(4, rebuilt.file_header.shnum);
+        assert_eq!(64 + 56, rebuilt.file_header.sh_offset);
+
+        let rebuilt_image: Vec<u8> = [
+            rebuilt.ei_ident.serialize().to_vec(),
+            Serialize::<ElfAddr64, LittleEndian>::serialize(&rebuilt.file_header),
+            Serialize::<ElfAddr64, LittleEndian>::serialize(&rebuilt.program_headers[0]),
+            rebuilt
+                .section_headers
+                .iter()
+                .flat_map(Serialize::<ElfAddr64, LittleEndian>::serialize)
+                .collect(),
+        ]
+        .concat();
+        let reparsed = ElfHeaderParser::elf64_le()
+            .parse(&rebuilt_image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(4, reparsed.file_header.shnum);
+        assert_eq!(&original_sections[..], &reparsed.section_headers[..3]);
+        assert_eq!(ShType::NoBits, reparsed.section_headers[3].sh_type);
+    }
+
+    #[test]
+    fn should_parse_the_members_of_a_section_group() {
+        let group = [GRP_COMDAT, 2, 3]
+            .iter()
+            .flat_map(|v| v.to_le_bytes().to_vec())
+            .collect();
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![
+                (".group", section_header64(ShType::Group), group),
+                (".text.f", section_header64(ShType::ProgBits), vec![0xc3]),
+                (".data.f", section_header64(ShType::ProgBits), vec![0x00]),
+            ],
+        );
+        let elf_header = ElfHeaderParser::elf64_le().parse(&image).unwrap().unwrap();
+
+        let group = elf_header
+            .section_group(&elf_header.section_headers[1], &image)
+            .unwrap();
+        assert_eq!(ShType::Group, elf_header.section_headers[1].sh_type);
+        assert!(group.is_comdat());
+        assert_eq!(vec![2, 3], group.members);
+    }
+
+    #[test]
+    fn should_check_the_class_against_the_machine() {
+        let elf32 = |machine| {
+            ElfHeaderBuilder::<ElfAddr32, LittleEndian>::new(Type::Rel, machine)
+                .build()
+                .unwrap()
+        };
+
+        assert!(!elf32(Machine::PPC64).class_matches_machine());
+        assert!(elf32(Machine::ARM).class_matches_machine());
+        assert!(elf32(Machine::MIPS).class_matches_machine());
+        assert!(elf32(Machine::X86_64).class_matches_machine());
+        assert!(!elf32(Machine::Alpha).class_matches_machine());
+        assert!(elf32(Machine::AARCH64).class_matches_machine());
+        assert!(elf32(Machine::X386).class_matches_machine());
+        assert!(elf32(Machine::None).class_matches_machine());
+        assert!(
+            ElfHeaderBuilder::<ElfAddr64, LittleEndian>::new(Type::Rel, Machine::X86_64)
+                .build()
+                .unwrap()
+                .class_matches_machine()
+        );
+    }
+
+    #[test]
+    fn should_summarize_the_class_and_data_encoding() {
+        let image = generate_elf64_le_image(Type::Exec, vec![], vec![]);
+        let ident = EiIdentParser::new().parse(&image).unwrap().unwrap();
+
+        assert_eq!((64, "little"), ident.summary());
+        assert_eq!(
+            (32, "big"),
+            EiIdent {
+                ei_class: EiClass::ThirtyTwoBit,
+                ei_data: EiData::Big,
+                ..ident
+            }
+            .summary()
+        );
+    }
+
+    #[test]
+    fn should_return_bytes_appended_past_the_declared_extent() {
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0x00,
+            vaddr: 0x400000,
+            paddr: 0x400000,
+            filesz: 0x40,
+            memsz: 0x40,
+            align: 0x1000,
+        };
+        let mut image = generate_elf64_le_image(Type::Exec, vec![load], vec![]);
+        let (elf, trailer) = parse_elf_with_trailer(&image).unwrap();
+        assert!(matches!(elf, ElfFile::Elf64Little(_)));
+        assert!(trailer.is_empty());
+
+        image.extend_from_slice(b"appended payload");
+        let (_, trailer) = parse_elf_with_trailer(&image).unwrap();
+        assert_eq!(b"appended payload", trailer);
+    }
+
+    #[test]
+    fn should_agree_on_trailing_data_covered_by_a_segment() {
+        let load =
+            |filesz| program_header64(ProgramHeaderType::Load, 0x04, 0, 0x400000, filesz, filesz);
+        // the segment's file data extends 16 bytes past the section header
+        // table.
+        let size = generate_elf64_le_image(Type::Exec, vec![load(0)], vec![]).len() as u64;
+        let mut image = generate_elf64_le_image(Type::Exec, vec![load(size + 16)], vec![]);
+        let load_end = image.len() + 16;
+        image.extend_from_slice(&[0x5a; 16]);
+        image.extend_from_slice(b"appended payload");
+
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        let (_, trailer) = parse_elf_with_trailer(&image).unwrap();
+        assert!((elf_header.expected_min_size() as usize) < load_end);
+        assert_eq!(b"appended payload", elf_header.trailing_data(&image));
+        assert_eq!(b"appended payload", trailer);
+    }
+
+    #[test]
+    fn should_locate_loaded_contents_by_virtual_address() {
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0x00,
+            vaddr: 0xffffffffff700000,
+            paddr: 0xffffffffff700000,
+            filesz: 0x1010,
+            memsz: 0x1010,
+            align: 0x1000,
+        };
+        let text = SectionHeader64 {
+            sh_addr: 0xffffffffff701000,
+            sh_size: 0x10,
+            ..section_header64(ShType::ProgBits)
+        };
+        let mut image = generate_elf64_le_image(
+            Type::Dyn,
+            vec![load],
+            vec![(".text", text, vec![0x90; 0x10])],
+        );
+        image.resize(0x1010, 0x00);
+        image[0x1000..].copy_from_slice(&[0xc3; 0x10]);
+
+        let loaded = parse_loaded(&image, 0xffffffffff700000).unwrap();
+        let eh = match &loaded.elf {
+            ElfFile::Elf64Little(eh) => eh,
+            _ => panic!("unexpected class"),
+        };
+        let text = &eh.section_headers[1];
+
+        assert_ne!(text.sh_offset, text.sh_addr - loaded.load_bias);
+        assert_eq!(Some(&[0x90; 0x10][..]), text.data(&image));
+        assert_eq!(Some(&[0xc3; 0x10][..]), loaded.section_data(text));
+        assert_eq!(
+            Some(&image[..]),
+            loaded.segment_data(&eh.program_headers[0])
+        );
+    }
+
+    #[test]
+    fn should_detect_the_relro_level() {
+        let relro = ProgramHeader64 {
+            r#type: ProgramHeaderType::GnuRelro,
+            flags: 0x04,
+            offset: 0x0,
+            vaddr: 0x0,
+            paddr: 0x0,
+            filesz: 0x0,
+            memsz: 0x0,
+            align: 0x1,
+        };
+        let relro_level = |entries, with_relro: bool| {
+            let image = generate_dynamic_elf64_le_image(Type::Dyn, entries, b"\0");
+            let mut eh = ElfHeaderParser::elf64_le().parse(&image).unwrap().unwrap();
+            if with_relro {
+                eh.program_headers.push(relro);
+            }
+            eh.relro_level(&image)
+        };
+
+        assert_eq!(RelroLevel::None, relro_level(vec![], false));
+        assert_eq!(
+            RelroLevel::None,
+            relro_level(vec![(DynamicTag::BindNow, 0)], false)
+        );
+        assert_eq!(RelroLevel::Partial, relro_level(vec![], true));
+        assert_eq!(
+            RelroLevel::Full,
+            relro_level(vec![(DynamicTag::Flags, DF_BIND_NOW)], true)
+        );
+        assert_eq!(
+            RelroLevel::Full,
+            relro_level(vec![(DynamicTag::Flags1, DF_1_NOW)], true)
+        );
+        assert_eq!("Full RELRO", RelroLevel::Full.to_string());
+    }
+
+    #[test]
+    fn should_parse_big_endian_machines() {
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], Machine::SPARC))),
+            MachineParser::<BigEndian>::new().parse(&[0x00, 0x02])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], Machine::PPC64))),
+            MachineParser::<BigEndian>::new().parse(&[0x00, 0x15])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], Machine::ARM))),
+            MachineParser::<LittleEndian>::new().parse(&[0x28, 0x00])
+        );
+
+        // short input previously panicked rather than failing to match.
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&[0x00][..])),
+            MachineParser::<BigEndian>::new().parse(&[0x00])
+        );
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&[][..])),
+            MachineParser::<LittleEndian>::new().parse(&[])
+        );
+    }
+
+    #[test]
+    fn should_decode_big_endian_mips_flags() {
+        let mut input: Vec<u8> = generate_file_header!();
+        input[5] = 0x02;
+        input[16..24].copy_from_slice(&[0x00, 0x02, 0x00, 0x08, 0x00, 0x00, 0x00, 0x01]);
+        input[36..40].copy_from_slice(&[0x70, 0x00, 0x10, 0x07]);
+        let file_header = FileHeaderParser::<ElfAddr32, BigEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(Machine::MIPS, file_header.machine);
+        assert_eq!(
+            vec!["noreorder", "PIC", "CPIC", "o32 ABI", "mips32r2"],
+            file_header.decode_flags()
+        );
+        assert!(FileHeader {
+            machine: Machine::X86_64,
+            ..file_header
+        }
+        .decode_flags()
+        .is_empty());
+    }
+
+    #[test]
+    fn parse_known_good_big_endian_file_header() {
+        let input = vec![
+            0x7f, 0x45, 0x4c, 0x46, // magic
+            0x01, // ei_class
+            0x02, // ei_data
+            0x01, // ei_version
+            0x00, // ei_osabi
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // padding
+            0x00, 0x02, // type
+            0x00, 0x02, // machine
+            0x00, 0x00, 0x00, 0x01, // version
+            0x00, 0x01, 0x00, 0x74, // entry
+            0x00, 0x00, 0x00, 0x34, // phoff
+            0x00, 0x00, 0x01, 0x28, // shoff
+            0x00, 0x00, 0x00, 0x00, // flags
+            0x00, 0x34, // eh_size
+            0x00, 0x20, // phentsize
+            0x00, 0x02, // phnum
+            0x00, 0x28, // shentsize
+            0x00, 0x05, // shnum
+            0x00, 0x04, // shstrndx
+        ];
+
+        assert_eq!(
+            FileHeader::<ElfAddr32> {
+                r#type: Type::Exec,
+                machine: Machine::SPARC,
+                version: Version::One,
+                entry_point: 0x10074,
+                ph_offset: 0x34,
+                sh_offset: 0x128,
+                flags: 0,
+                eh_size: 52,
+                phent_size: 32,
+                phnum: 2,
+                shent_size: 40,
+                shnum: 5,
+                shstrndx: 4
+            },
+            FileHeaderParser::<ElfAddr32, BigEndian>::new()
+                .parse(&input)
+                .unwrap()
+                .unwrap(),
+        );
+
+        let mut ppc64 = input[..16].to_vec();
+        ppc64[4] = 0x02;
+        ppc64.extend_from_slice(&[
+            0x00, 0x03, // type
+            0x00, 0x15, // machine
+            0x00, 0x00, 0x00, 0x01, // version
+            0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x08, 0x00, // entry
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, // phoff
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, // shoff
+            0x00, 0x00, 0x00, 0x02, // flags
+            0x00, 0x40, // eh_size
+            0x00, 0x38, // phentsize
+            0x00, 0x01, // phnum
+            0x00, 0x40, // shentsize
+            0x00, 0x03, // shnum
+            0x00, 0x02, // shstrndx
+        ]);
+        let file_header = FileHeaderParser::<ElfAddr64, BigEndian>::new()
+            .parse(&ppc64)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            (Type::Dyn, Machine::PPC64, 0x10000800, 0x2000, 2),
+            (
+                file_header.r#type,
+                file_header.machine,
+                file_header.entry_point,
+                file_header.sh_offset,
+                file_header.flags
+            )
+        );
+    }
+
+    #[test]
+    fn should_detect_stack_protector_and_fortify_symbols() {
+        let hardening = |names: &[&str]| {
+            let dynstr: Vec<u8> = std::iter::once(&b""[..])
+                .chain(names.iter().map(|name| name.as_bytes()))
+                .flat_map(|name| name.iter().copied().chain(std::iter::once(0)))
+                .collect();
+            let mut st_name = 1;
+            let dynsym: Vec<u8> = std::iter::once(symbol64(0, 0x00, 0, 0))
+                .chain(names.iter().map(|name| {
+                    let symbol = symbol64(st_name, 0x12, 0, 0);
+                    st_name += name.len() as u32 + 1;
+                    symbol
+                }))
+                .flatten()
+                .collect();
+            let image = generate_elf64_le_image(
+                Type::Dyn,
+                vec![],
+                vec![
+                    (".dynstr", section_header64(ShType::StrTab), dynstr),
+                    (
+                        ".dynsym",
+                        SectionHeader64 {
+                            sh_link: 1,
+                            ..section_header64(ShType::DynSym)
+                        },
+                        dynsym,
+                    ),
+                ],
+            );
+            let eh = ElfHeaderParser::elf64_le().parse(&image).unwrap().unwrap();
+
+            (eh.has_stack_protector(&image), eh.uses_fortify(&image))
+        };
+
+        assert_eq!(
+            (true, true),
+            hardening(&["puts", "__stack_chk_fail", "__printf_chk"])
+        );
+        assert_eq!((true, false), hardening(&["__stack_chk_fail"]));
+        assert_eq!(
+            (true, true),
+            hardening(&["__stack_chk_fail@GLIBC_2.4", "__memcpy_chk@@GLIBC_2.3.4"])
+        );
+        assert_eq!((false, false), hardening(&["puts", "printf"]));
+    }
+
+    #[test]
+    fn should_match_nul_terminated_strings() {
+        let input = b"libc.so.6\0\0tail";
+
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[10..], "libc.so.6"))),
+            c_string().parse(&input[..])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[11..], ""))),
+            c_string().parse(&input[10..])
+        );
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&input[11..])),
+            c_string().parse(&input[11..])
+        );
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&[0xff, 0xfe, 0x00][..])),
+            c_string().parse(&[0xff, 0xfe, 0x00])
+        );
+    }
+
+    #[test]
+    fn should_match_leb128_encoded_integers() {
+        let input = [0xe5, 0x8e, 0x26, 0xc0, 0xbb, 0x78];
+
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[3..], 624485))),
+            uleb128().parse(&input[..])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[6..], -123456))),
+            sleb128().parse(&input[3..])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], -1))),
+            sleb128().parse(&[0x7f][..])
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], i64::MIN))),
+            sleb128().parse(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7f][..])
+        );
+
+        // unterminated and overflowing sequences
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&[0x80, 0x80][..])),
+            sleb128().parse(&[0x80, 0x80][..])
+        );
+        let overlong = [0xff; 11];
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&overlong[..])),
+            uleb128().parse(&overlong[..])
+        );
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&overlong[..])),
+            sleb128().parse(&overlong[..])
+        );
+    }
+
+    #[test]
+    fn should_detect_stripped_binaries() {
+        let parse = |sections| {
+            let image = generate_elf64_le_image(Type::Dyn, vec![], sections);
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .unwrap()
+                .unwrap()
+        };
+
+        let unstripped = parse(vec![
+            (".dynsym", section_header64(ShType::DynSym), vec![0; 24]),
+            (".symtab", section_header64(ShType::SymTab), vec![0; 24]),
+        ]);
+        assert!(!unstripped.is_stripped());
+
+        let stripped = parse(vec![(
+            ".dynsym",
+            section_header64(ShType::DynSym),
+            vec![0; 24],
+        )]);
+        assert!(stripped.is_stripped());
+        assert!(parse(vec![]).is_stripped());
+    }
+
+    #[test]
+    fn should_validate_shstrndx_against_the_section_table() {
+        let mut image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![(".text", section_header64(ShType::ProgBits), vec![0x90; 8])],
+        );
+        let parse = |image: &[u8]| {
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(image)
+                .unwrap()
+                .unwrap()
+        };
+        let elf_header = parse(&image);
+        assert_eq!(Ok(()), elf_header.file_header.validate_shstrndx());
+        let shstrndx = elf_header.file_header.shstrndx;
+        let sh_offset = elf_header.file_header.sh_offset as usize;
+
+        // shstrndx points past the section table.
+        image[62..64].copy_from_slice(&7u16.to_le_bytes());
+        let elf_header = parse(&image);
+        assert_eq!(
+            Err(ValidationError::ShstrndxOutOfRange {
+                shstrndx: 7,
+                shnum: 3
+            }),
+            elf_header.file_header.validate_shstrndx()
+        );
+        assert_eq!(
+            None,
+            elf_header.section_name(&elf_header.section_headers[1], &image)
+        );
+        assert_eq!(None, elf_header.section_by_name(".text", &image));
+        assert!(warnings(&image)
+            .contains(&"shstrndx 7 is out of range for 3 section headers".to_string()));
+
+        // SHN_XINDEX defers to the sh_link of the null section.
+        image[62..64].copy_from_slice(&SHN_XINDEX.to_le_bytes());
+        image[sh_offset + 40..sh_offset + 44].copy_from_slice(&u32::from(shstrndx).to_le_bytes());
+        let elf_header = parse(&image);
+        assert_eq!(Ok(()), elf_header.file_header.validate_shstrndx());
+        assert_eq!(
+            Some(".text"),
+            elf_header.section_name(&elf_header.section_headers[1], &image)
+        );
+    }
+
+    #[test]
+    fn should_parse_a_single_section_header_by_index() {
+        let image = generate_elf64_le_image(
+            Type::Rel,
+            vec![],
+            vec![
+                (".text", section_header64(ShType::ProgBits), vec![0x90; 8]),
+                (".data", section_header64(ShType::ProgBits), vec![0x00; 8]),
+            ],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        let fh = &elf_header.file_header;
+
+        assert_eq!(
+            Ok(elf_header.section_headers[0]),
+            parse_section_header(&image, fh, EiData::Little, 0)
+        );
+        assert_eq!(
+            Ok(elf_header.section_headers[2]),
+            parse_section_header(&image, fh, EiData::Little, 2)
+        );
+        assert_eq!(
+            Err(FileErr::InvalidFile),
+            parse_section_header(&image, fh, EiData::Little, 4)
+        );
+
+        // a table extending past the end of the file.
+        assert_eq!(
+            Err(FileErr::InvalidFile),
+            parse_section_header(&image[..image.len() - 1], fh, EiData::Little, 3)
+        );
+    }
+
+    #[test]
+    fn should_parse_the_auxiliary_vector_of_a_core_file() {
+        let auxv_entries: Vec<u8> = [
+            (AuxType::Phdr as u64, 0x40_0040u64),
+            (AuxType::Pagesz as u64, 0x1000),
+            (AuxType::Entry as u64, 0x40_1000),
+            (AuxType::Random as u64, 0x7ffc_0000_1000),
+            (AuxType::Null as u64, 0),
+            // trailing data following AT_NULL is ignored.
+            (AuxType::Base as u64, 0xdead),
+        ]
+        .iter()
+        .flat_map(|&(a_type, a_val)| [a_type.to_le_bytes(), a_val.to_le_bytes()].concat())
+        .collect();
+        let note = |name: &[u8], n_type: u32, desc: &[u8]| {
+            let mut note = [
+                (name.len() as u32).to_le_bytes(),
+                (desc.len() as u32).to_le_bytes(),
+                n_type.to_le_bytes(),
+            ]
+            .concat();
+            note.extend_from_slice(name);
+            note.resize((note.len() + 3) & !3, 0);
+            note.extend_from_slice(desc);
+            note.resize((note.len() + 3) & !3, 0);
+            note
+        };
+        let notes = [
+            note(b"CORE\0", 1, &[0xff; 6]),
+            note(b"CORE\0", NT_AUXV, &auxv_entries),
+        ]
+        .concat();
+
+        let segment = ProgramHeader64 {
+            r#type: ProgramHeaderType::Note,
+            flags: 0,
+            offset: 120,
+            vaddr: 0,
+            paddr: 0,
+            filesz: notes.len() as u64,
+            memsz: 0,
+            align: 4,
+        };
+        // the first section's data immediately follows the program headers.
+        let image = generate_elf64_le_image(
+            Type::Core,
+            vec![segment],
+            vec![("note0", section_header64(ShType::Note), notes)],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        let auxv = elf_header.auxv(&image);
+        assert_eq!(
+            vec![
+                Some(AuxType::Phdr),
+                Some(AuxType::Pagesz),
+                Some(AuxType::Entry),
+                Some(AuxType::Random)
+            ],
+            auxv.iter().map(AuxvEntry::aux_type).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            AuxvEntry {
+                a_type: 6,
+                a_val: 0x1000
+            },
+            auxv[1]
+        );
+
+        let image = generate_elf64_le_image(Type::Core, vec![], vec![]);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        assert!(elf_header.auxv(&image).is_empty());
+    }
+
+    #[test]
+    fn should_expect_integers_of_any_width() {
+        let input = [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0, 0xff];
+
+        for &endianness in [EiData::Little, EiData::Big].iter() {
+            let (u16_val, u32_val, u64_val): (u16, u32, u64) = match endianness {
+                EiData::Little => (0x3412, 0x7856_3412, 0xf0de_bc9a_7856_3412),
+                EiData::Big => (0x1234, 0x1234_5678, 0x1234_5678_9abc_def0),
+            };
+
+            assert_eq!(
+                Ok(MatchStatus::Match((&input[2..], u16_val))),
+                expect_int(endianness, u16_val).parse(&input)
+            );
+            assert_eq!(
+                Ok(MatchStatus::Match((&input[4..], u32_val))),
+                expect_int(endianness, u32_val).parse(&input)
+            );
+            assert_eq!(
+                Ok(MatchStatus::Match((&input[8..], u64_val))),
+                expect_int(endianness, u64_val).parse(&input)
+            );
+
+            // parity with the width-specific wrappers, including mismatches
+            // and truncated input.
+            for expected in [u16_val, 0].iter() {
+                assert_eq!(
+                    expect_u16(endianness, *expected).parse(&input),
+                    expect_int(endianness, *expected).parse(&input)
+                );
+            }
+            for expected in [u32_val, 0].iter() {
+                assert_eq!(
+                    expect_u32(endianness, *expected).parse(&input),
+                    expect_int(endianness, *expected).parse(&input)
+                );
+            }
+            assert_eq!(
+                Ok(MatchStatus::NoMatch(&input[..3])),
+                expect_int(endianness, u32_val).parse(&input[..3])
+            );
+        }
+    }
+
+    #[test]
+    fn should_parse_the_dynamic_segment_of_a_section_stripped_file() {
+        let image = generate_section_stripped_elf64_le_image(
+            vec![(DynamicTag::Needed, 1)],
+            b"\0libc.so.6\0",
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        assert!(elf_header.section_headers.is_empty());
+
+        let entries = elf_header.dynamic_from_segment(&image);
+        assert_eq!(
+            vec![
+                Some(DynamicTag::Needed),
+                Some(DynamicTag::StrTab),
+                Some(DynamicTag::Null)
+            ],
+            entries.iter().map(|entry| entry.tag()).collect::<Vec<_>>()
+        );
+        assert_eq!(entries, elf_header.dynamic(&image));
+
+        let image = generate_elf64_le_image(Type::Dyn, vec![], vec![]);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        assert!(elf_header.dynamic_from_segment(&image).is_empty());
+    }
+
+    #[test]
+    fn should_resolve_dynamic_strings_of_a_section_stripped_file() {
+        let image = generate_section_stripped_elf64_le_image(
+            vec![(DynamicTag::Needed, 1), (DynamicTag::SoName, 11)],
+            b"\0libc.so.6\0libfoo.so.1\0",
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            vec!["libc.so.6".to_string()],
+            elf_header.needed_libraries(&image)
+        );
+        assert_eq!(Some("libfoo.so.1".to_string()), elf_header.soname(&image));
+    }
+
+    #[test]
+    fn should_match_signed_integers_in_either_byte_order() {
+        let (i32_le, i64_be) = ((-8i32).to_le_bytes(), (-8i64).to_be_bytes());
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], -8))),
+            match_i32(EiData::Little).parse(&i32_le)
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], -8))),
+            match_i64(EiData::Big).parse(&i64_be)
+        );
+
+        // a relocation with a negative addend.
+        let rela_le = [
+            0x10u64.to_le_bytes(),
+            0x0000_0001_0000_0001u64.to_le_bytes(),
+            (-8i64).to_le_bytes(),
+        ]
+        .concat();
+        let rela_be = [
+            0x10u32.to_be_bytes(),
+            0x0000_0101u32.to_be_bytes(),
+            (-8i32).to_be_bytes(),
+        ]
+        .concat();
+        assert_eq!(
+            -8,
+            RelaParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&rela_le)
+                .unwrap()
+                .unwrap()
+                .r_addend
+        );
+        assert_eq!(
+            -8,
+            RelaParser::<ElfAddr32, BigEndian>::new()
+                .parse(&rela_be)
+                .unwrap()
+                .unwrap()
+                .r_addend
+        );
+    }
+
+    #[test]
+    fn should_select_the_byte_order_of_each_file_at_runtime() {
+        let parser = AutoElfParser::new();
+        let le_image = generate_elf64_le_image(Type::Exec, vec![], vec![]);
+        let be_image = ElfHeaderBuilder::<ElfAddr32, BigEndian>::new(Type::Rel, Machine::PPC)
+            .build()
+            .unwrap();
+        let be_image: Vec<u8> = be_image.into();
+
+        let header = |input: &[u8]| match parser.parse(input) {
+            Ok(MatchStatus::Match((_, header))) => Some(header),
+            _ => None,
+        };
+        match header(&le_image) {
+            Some(AnyFileHeader::Elf64(fh)) => assert_eq!(Machine::X86_64, fh.machine),
+            other => panic!("unexpected header {:?}", other),
+        }
+        match header(&be_image) {
+            Some(AnyFileHeader::Elf32(fh)) => {
+                assert_eq!(Machine::PPC, fh.machine);
+                assert_eq!(Type::Rel, fh.r#type);
+            }
+            other => panic!("unexpected header {:?}", other),
+        }
+
+        fn parsed(parser: AutoElfParser, input: &[u8]) -> Option<ParsedElf<'_>> {
+            match parser.parse(input) {
+                Ok(MatchStatus::Match((_, parsed))) => Some(parsed),
+                _ => None,
+            }
+        }
+        assert_eq!(ParsedElf::parse(&le_image).ok(), parsed(parser, &le_image));
+        assert!(matches!(
+            parsed(parser, &be_image).map(|parsed| parsed.elf),
+            Some(ElfFile::Elf32Big(_))
+        ));
+        assert_eq!(None, header(&[0x7f, 0x45, 0x4c, 0x47]));
+
+        // an oversized note segment exceeds the default limits.
+        let oversized = generate_elf64_le_image(
+            Type::Exec,
+            vec![program_header64(
+                ProgramHeaderType::Note,
+                0x04,
+                0,
+                0,
+                1 << 25,
+                1 << 25,
+            )],
+            vec![],
+        );
+        assert_eq!(None, parsed(parser, &oversized));
+        assert!(header(&oversized).is_some());
+    }
+
+    #[test]
+    fn should_take_length_prefixed_blobs() {
+        let input = [
+            0x00, 0x00, 0x00, 0x05, b'h', b'e', b'l', b'l', b'o', 0x00, 0x00, 0x00, 0xff,
+        ];
+
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[12..], &b"hello"[..]))),
+            take_len_prefixed(EiData::Big, 4, true).parse(&input)
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[9..], &b"hello"[..]))),
+            take_len_prefixed(EiData::Big, 4, false).parse(&input)
+        );
+
+        // an already aligned blob and padding omitted at the end of input.
+        let aligned = [0x04, 0x00, 0x00, 0x00, 0xde, 0xad, 0xbe, 0xef];
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], &aligned[4..]))),
+            take_len_prefixed(EiData::Little, 4, true).parse(&aligned)
+        );
+        assert_eq!(
+            Ok(MatchStatus::Match((&[][..], &input[4..9]))),
+            take_len_prefixed(EiData::Big, 4, true).parse(&input[..9])
+        );
+
+        // a length running past the end of the input, and unsupported widths.
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&input[..8])),
+            take_len_prefixed(EiData::Big, 4, true).parse(&input[..8])
+        );
+        assert_eq!(
+            Ok(MatchStatus::NoMatch(&input[..])),
+            take_len_prefixed(EiData::Big, 3, true).parse(&input)
+        );
+    }
+
+    #[test]
+    fn should_render_header_types_without_allocating() {
+        assert_eq!("Load", ProgramHeaderType::Load.type_str());
+        assert_eq!("GNU_STACK", ProgramHeaderType::GnuStack.type_str());
+        assert_eq!("PROG_Bits", ShType::ProgBits.type_str());
+        assert_eq!("DYN_SYM", ShType::DynSym.type_str());
+
+        // Display renders the same string and honors width and alignment.
+        assert_eq!("TLS", ProgramHeaderType::Tls.to_string());
+        assert_eq!("NOTE      |", format!("{: <10}|", ShType::Note));
+    }
+
+    #[test]
+    fn should_split_local_and_global_symbols() {
+        let symbols: Vec<u8> = [
+            symbol64(0, 0x00, SHN_UNDEF, 0),
+            symbol64(0, 0x04, SHN_ABS, 0),
+            symbol64(0, 0x03, 1, 0),
+            symbol64(0, 0x12, 1, 0x10),
+            symbol64(0, 0x22, 1, 0x20),
+        ]
+        .concat();
+        let symtab = SectionHeader64 {
+            sh_info: 3,
+            sh_entsize: 24,
+            ..section_header64(ShType::SymTab)
+        };
+        let image = generate_elf64_le_image(Type::Rel, vec![], vec![(".symtab", symtab, symbols)]);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        let mut symtab = elf_header.section_headers[1];
+
+        assert_eq!(5, symtab.symbol_count());
+        assert_eq!(3, symtab.local_symbol_count());
+        assert_eq!(Ok(()), symtab.validate_symbol_info());
+        let (locals, globals) = elf_header.split_symbols(&symtab, &image);
+        assert_eq!(3, locals.len());
+        assert_eq!(
+            vec![0x10, 0x20],
+            globals.iter().map(|sym| sym.st_value).collect::<Vec<_>>()
+        );
+
+        symtab.sh_info = 9;
+        assert_eq!(5, symtab.local_symbol_count());
+        assert_eq!(
+            Err(ValidationError::SymbolInfoOutOfRange {
+                sh_info: 9,
+                symbol_count: 5
+            }),
+            symtab.validate_symbol_info()
+        );
+        assert_eq!(5, elf_header.split_symbols(&symtab, &image).0.len());
+    }
+
+    #[test]
+    fn should_map_load_segments_with_their_zero_fill() {
+        let image = generate_elf64_le_image(
+            Type::Exec,
+            vec![
+                program_header64(ProgramHeaderType::Load, 0x05, 0x0, 0x40_0000, 0x800, 0x800),
+                program_header64(
+                    ProgramHeaderType::Load,
+                    0x06,
+                    0x800,
+                    0x40_1800,
+                    0x100,
+                    0x340,
+                ),
+                program_header64(ProgramHeaderType::GnuStack, 0x06, 0, 0, 0, 0),
+            ],
+            vec![],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        let load_map = elf_header.load_map();
+        assert_eq!(2, load_map.len());
+        assert_eq!(0, load_map[0].zero_fill_size());
+        assert_eq!(
+            LoadSegment {
+                file_offset: 0x800,
+                file_size: 0x100,
+                mem_addr: 0x40_1800,
+                mem_size: 0x340,
+                flags: Permissions {
+                    read: true,
+                    write: true,
+                    execute: false
+                },
+            },
+            load_map[1]
+        );
+        assert_eq!(0x240, load_map[1].zero_fill_size());
+        assert_eq!(
+            Ok(load_map),
+            parse_loadable(&image).map(|elf| elf.load_map())
+        );
     }
 
     #[test]
-    fn should_serialize_a_header_to_bytes() {
-        let elf_header = ElfHeader64::<LittleEndian>::new(
-            EiIdent {
-                ei_data: EiData::Little,
-                ei_class: EiClass::SixtyFourBit,
-                ei_osabi: EiOsAbi::SysV,
-                ei_version: EiVersion::One,
-                ei_abiversion: EiAbiVersion::One,
-            },
-            FileHeader::<ElfAddr64> {
-                r#type: Type::None,
-                machine: Machine::X86_64,
-                version: Version::One,
-                entry_point: 5u64,
-                ph_offset: 10u64,
-                sh_offset: 11u64,
-                flags: 2,
-                eh_size: 0,
-                phent_size: 1,
-                phnum: 1,
-                shent_size: 1,
-                shnum: 1,
-                shstrndx: 1,
-            },
+    fn should_preserve_non_zero_padding_through_a_round_trip() {
+        let mut input = generate_elf64_le_image(Type::Exec, vec![], vec![]);
+        input[9..16].copy_from_slice(b"stash!\0");
+
+        let ident = EiIdentParser::new().parse(&input).unwrap().unwrap();
+        assert_eq!(*b"stash!\0", ident.pad);
+        assert_eq!(&input[..16], &ident.serialize()[..]);
+
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+        let serialized: Vec<u8> = elf_header.into();
+        assert_eq!(&input[..16], &serialized[..16]);
+    }
+
+    #[test]
+    fn should_compare_headers_across_encodings_by_their_fields() {
+        let input = generate_elf64_le_image(
+            Type::Exec,
             vec![ProgramHeader64 {
-                r#type: ProgramHeaderType::Null,
-                offset: 0x00,
-                vaddr: 0x00,
-                paddr: 0x00,
-                filesz: 0x00,
-                memsz: 0x00,
-                flags: 0x00,
-                align: 0x00,
+                r#type: ProgramHeaderType::Load,
+                flags: 0x05,
+                offset: 0,
+                vaddr: 0x400000,
+                paddr: 0x400000,
+                filesz: 0x200,
+                memsz: 0x200,
+                align: 0x1000,
             }],
+            vec![(".text", section_header64(ShType::ProgBits), vec![0x90; 4])],
+        );
+        let le = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+
+        let be_ident = EiIdent {
+            ei_data: EiData::Big,
+            ..le.ei_ident
+        };
+        let be = ElfHeader64::<BigEndian>::new(
+            be_ident,
+            le.file_header,
+            le.program_headers.clone(),
+            le.section_headers.clone(),
+        );
+
+        assert!(le.eq_fields(&be));
+        assert!(be.eq_fields(&le));
+
+        let mut retyped = be.clone();
+        retyped.file_header.r#type = Type::Dyn;
+        assert!(!le.eq_fields(&retyped));
+    }
+
+    #[test]
+    fn should_resolve_section_relative_symbol_addresses_in_relocatables() {
+        let text = SectionHeader64 {
+            sh_addr: 0x1000,
+            ..section_header64(ShType::ProgBits)
+        };
+        let input =
+            generate_elf64_le_image(Type::Rel, vec![], vec![(".text", text, vec![0x90; 0x20])]);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            Some(0x1010),
+            elf_header.resolved_address(&symbol64_entry(0x12, 1, 0x10))
+        );
+        assert_eq!(
+            Some(0x42),
+            elf_header.resolved_address(&symbol64_entry(0x00, SHN_ABS, 0x42))
+        );
+        assert_eq!(
+            None,
+            elf_header.resolved_address(&symbol64_entry(0x11, SHN_UNDEF, 0))
+        );
+        assert_eq!(
+            None,
+            elf_header.resolved_address(&symbol64_entry(0x11, SHN_COMMON, 0x08))
+        );
+        assert_eq!(
+            None,
+            elf_header.resolved_address(&symbol64_entry(0x12, 0x40, 0x10))
+        );
+        assert_eq!(
+            None,
+            elf_header.resolved_address(&symbol64_entry(0x12, 1, u64::MAX))
+        );
+    }
+
+    #[test]
+    fn should_return_symbol_values_as_is_outside_relocatables() {
+        let text = SectionHeader64 {
+            sh_addr: 0x401000,
+            ..section_header64(ShType::ProgBits)
+        };
+        let input =
+            generate_elf64_le_image(Type::Exec, vec![], vec![(".text", text, vec![0x90; 0x20])]);
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            Some(0x401010),
+            elf_header.resolved_address(&symbol64_entry(0x12, 1, 0x401010))
+        );
+    }
+
+    #[test]
+    fn should_annotate_a_missing_required_field() {
+        let input = [0x01, 0x00, 0x00, 0x00, 0x02, 0x00];
+        let shnum = || require(match_u32(EiData::Little), || "expected shnum");
+
+        assert_eq!(
+            Ok(MatchStatus::Match((&input[4..], 1))),
+            shnum().parse(&input)
+        );
+        assert_eq!(
+            Err("expected shnum".to_string()),
+            shnum().parse(&input[4..])
+        );
+        assert_eq!(
+            Err("not an elf formatted file".to_string()),
+            require(match_u32(EiData::Little), || FileErr::InvalidFile).parse(&input[4..])
+        );
+    }
+
+    #[test]
+    fn should_detect_elf_magic() {
+        let input = generate_elf64_le_image(Type::Exec, vec![], vec![]);
+
+        assert!(is_elf(&input));
+        assert!(is_elf(&ELF_MAGIC));
+        assert!(!is_elf(&input[..3]));
+        assert!(!is_elf(b"!<arch>\n"));
+    }
+
+    #[test]
+    fn should_read_the_full_section_flags_bitmask() {
+        let mut input = generate_elf64_le_image(
+            Type::Exec,
+            vec![],
+            vec![(".text", section_header64(ShType::ProgBits), vec![0x90; 4])],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+        let flags_offset = elf_header.file_header.sh_offset as usize + 64 + 8;
+        input[flags_offset..flags_offset + 8]
+            .copy_from_slice(&(SHF_ALLOC | SHF_EXECINSTR).to_le_bytes());
+
+        assert_eq!(
+            Some(SHF_ALLOC | SHF_EXECINSTR),
+            elf_header.section_flags(1, &input)
+        );
+        assert_eq!(None, elf_header.section_flags(3, &input));
+    }
+
+    #[test]
+    fn should_parse_with_an_overriding_encoding() {
+        // a big-endian file whose EI_DATA byte claims little-endian.
+        let mut input = include_bytes!("../tests/fixtures/elf64_be_exec.bin").to_vec();
+        input[5] = EiData::Little as u8;
+
+        assert_eq!(Err(FileErr::InvalidFile), parse_elf(&input));
+
+        match parse_elf_with_encoding(&input, EiData::Big) {
+            Ok(ElfFile::Elf64Big(eh)) => {
+                assert_eq!(EiData::Little, eh.ei_ident.ei_data);
+                assert_eq!(Machine::PPC64, eh.file_header.machine);
+                assert_eq!(0x10000138, eh.file_header.entry_point);
+            }
+            other => panic!("unexpected parse result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_deduplicate_headers_in_a_hash_set() {
+        let load = ProgramHeader64 {
+            r#type: ProgramHeaderType::Load,
+            flags: 0x05,
+            offset: 0,
+            vaddr: 0x400000,
+            paddr: 0x400000,
+            filesz: 0x200,
+            memsz: 0x200,
+            align: 0x1000,
+        };
+        let stack = ProgramHeader64 {
+            r#type: ProgramHeaderType::GnuStack,
+            flags: 0x06,
+            ..load
+        };
+
+        let segments: std::collections::HashSet<ProgramHeader64> =
+            vec![load, stack, load].into_iter().collect();
+        assert_eq!(2, segments.len());
+        assert!(segments.contains(&stack));
+
+        let sections: std::collections::HashSet<SectionHeader64> = vec![
+            section_header64(ShType::ProgBits),
+            section_header64(ShType::ProgBits),
+            section_header64(ShType::Note),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(2, sections.len());
+    }
+
+    #[test]
+    fn should_validate_the_reserved_null_section() {
+        let mut input = generate_elf64_le_image(
+            Type::Exec,
+            vec![],
+            vec![(".text", section_header64(ShType::ProgBits), vec![0x90; 4])],
+        );
+        let parse = |input: &[u8]| {
+            ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(input)
+                .unwrap()
+                .unwrap()
+        };
+        let elf_header = parse(&input);
+        let sh_offset = elf_header.file_header.sh_offset as usize;
+        assert_eq!(3, elf_header.section_headers().len());
+        assert_eq!(&elf_header.section_headers()[1..], elf_header.sections());
+        assert_eq!(Ok(()), elf_header.validate_null_section());
+
+        // a nonzero sh_addr on section 0.
+        input[sh_offset + 16..sh_offset + 24].copy_from_slice(&0x1000u64.to_le_bytes());
+        assert_eq!(
+            Err(ValidationError::NullSectionNotEmpty { field: "sh_addr" }),
+            parse(&input).validate_null_section()
+        );
+        assert!(warnings(&input).contains(&"section header 0 has a nonzero sh_addr".to_string()));
+        input[sh_offset + 16..sh_offset + 24].copy_from_slice(&[0x00; 8]);
+
+        // sh_link holds the real shstrndx when shstrndx is SHN_XINDEX.
+        input[sh_offset + 40..sh_offset + 44].copy_from_slice(&2u32.to_le_bytes());
+        assert_eq!(
+            Err(ValidationError::NullSectionNotEmpty { field: "sh_link" }),
+            parse(&input).validate_null_section()
+        );
+        input[62..64].copy_from_slice(&SHN_XINDEX.to_le_bytes());
+        assert_eq!(Ok(()), parse(&input).validate_null_section());
+        assert!(!warnings(&input)
+            .iter()
+            .any(|w| w.contains("section header 0")));
+    }
+
+    /// Assembles a little-endian 64-bit shared object with three PLT stubs
+    /// bound to `puts`, `malloc` and `free` through `.rela.plt`, optionally
+    /// carrying an IBT `.plt.sec` section.
+    fn generate_plt_elf64_le_image(ibt: bool) -> Vec<u8> {
+        let jump_slot = |sym: u64, r_offset: u64| {
+            [
+                r_offset.to_le_bytes(),
+                ((sym << 32) | 7).to_le_bytes(),
+                [0; 8],
+            ]
+            .concat()
+        };
+        let mut sections = vec![
+            (
+                ".dynstr",
+                section_header64(ShType::StrTab),
+                b"\0puts\0malloc\0free\0".to_vec(),
+            ),
+            (
+                ".dynsym",
+                SectionHeader64 {
+                    sh_link: 1,
+                    sh_info: 1,
+                    sh_entsize: 24,
+                    ..section_header64(ShType::DynSym)
+                },
+                [
+                    symbol64(0, 0x00, 0, 0),
+                    symbol64(1, 0x12, 0, 0),
+                    symbol64(6, 0x12, 0, 0),
+                    symbol64(13, 0x12, 0, 0),
+                ]
+                .concat(),
+            ),
+            (
+                ".rela.plt",
+                SectionHeader64 {
+                    sh_link: 2,
+                    sh_entsize: 24,
+                    ..section_header64(ShType::Rela)
+                },
+                [
+                    jump_slot(2, 0x4018),
+                    jump_slot(3, 0x4020),
+                    jump_slot(1, 0x4028),
+                ]
+                .concat(),
+            ),
+            (
+                ".plt",
+                SectionHeader64 {
+                    sh_addr: 0x1020,
+                    sh_entsize: 16,
+                    ..section_header64(ShType::ProgBits)
+                },
+                vec![0xcc; 64],
+            ),
+        ];
+        if ibt {
+            sections.push((
+                ".plt.sec",
+                SectionHeader64 {
+                    sh_addr: 0x1060,
+                    sh_entsize: 16,
+                    ..section_header64(ShType::ProgBits)
+                },
+                vec![0xcc; 48],
+            ));
+        }
+
+        generate_elf64_le_image(Type::Dyn, vec![], sections)
+    }
+
+    #[test]
+    fn should_enumerate_plt_entries_with_their_symbols() {
+        for (ibt, base) in [(false, 0x1030), (true, 0x1060)] {
+            let image = generate_plt_elf64_le_image(ibt);
+            let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+                .parse(&image)
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(
+                vec![
+                    PltEntry {
+                        address: base,
+                        symbol: Some("malloc"),
+                    },
+                    PltEntry {
+                        address: base + 0x10,
+                        symbol: Some("free"),
+                    },
+                    PltEntry {
+                        address: base + 0x20,
+                        symbol: Some("puts"),
+                    },
+                ],
+                elf_header.plt_entries(&image)
+            );
+        }
+    }
+
+    #[test]
+    fn should_stop_enumerating_plt_entries_on_address_overflow() {
+        let image = [0xcc; 0x40];
+        let plt = SectionHeader64 {
+            sh_addr: u64::MAX - 0x20,
+            sh_offset: 0,
+            sh_size: 0x40,
+            sh_entsize: 0x10,
+            ..section_header64(ShType::ProgBits)
+        };
+
+        assert_eq!(
+            vec![
+                PltEntry {
+                    address: u64::MAX - 0x10,
+                    symbol: Some("malloc"),
+                },
+                PltEntry {
+                    address: u64::MAX,
+                    symbol: Some("free"),
+                },
+            ],
+            plt_entries(
+                &plt,
+                1,
+                Some(vec![Some("malloc"), Some("free"), Some("puts")]),
+                &image
+            )
+        );
+    }
+
+    #[test]
+    fn should_bound_plt_entries_by_the_section_data() {
+        let image = [0xcc; 0x40];
+        let plt = SectionHeader64 {
+            sh_addr: 0x1020,
+            sh_offset: 0,
+            sh_size: u64::MAX,
+            sh_entsize: 0x10,
+            ..section_header64(ShType::ProgBits)
+        };
+        assert_eq!(Vec::<PltEntry>::new(), plt_entries(&plt, 1, None, &image));
+
+        let plt = SectionHeader64 {
+            sh_size: 0x40,
+            ..plt
+        };
+        assert_eq!(
+            vec![0x1030, 0x1040, 0x1050],
+            plt_entries(&plt, 1, None, &image)
+                .into_iter()
+                .map(|entry| entry.address)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn should_hash_symbol_names() {
+        assert_eq!(0, elf_hash(b""));
+        assert_eq!(0x0779_05a6, elf_hash(b"printf"));
+        assert_eq!(0x0c33_5095, elf_hash(b"freelocale"));
+
+        assert_eq!(0x0000_1505, gnu_hash(b""));
+        assert_eq!(0x156b_2bb8, gnu_hash(b"printf"));
+        assert_eq!(0x49fe_b217, gnu_hash(b"freelocale"));
+    }
+
+    #[test]
+    fn should_map_sections_to_their_containing_load_segments() {
+        // the headers, followed by .text at 232 and .bss after it.
+        let program_headers = vec![
+            program_header64(ProgramHeaderType::Load, 0x04, 0, 0x400000, 232, 232),
+            program_header64(ProgramHeaderType::Load, 0x05, 232, 0x4000e8, 16, 16),
+            program_header64(ProgramHeaderType::Load, 0x06, 248, 0x401000, 0, 0x100),
+        ];
+        let text = SectionHeader64 {
+            sh_addr: 0x4000e8,
+            ..section_header64(ShType::ProgBits)
+        };
+        let bss = SectionHeader64 {
+            sh_addr: 0x401000,
+            sh_size: 0x100,
+            ..section_header64(ShType::NoBits)
+        };
+        let input = generate_elf64_le_image(
+            Type::Exec,
+            program_headers,
+            vec![
+                (".text", text, vec![0x90; 16]),
+                (".bss", bss, vec![]),
+                (
+                    ".comment",
+                    section_header64(ShType::ProgBits),
+                    b"GCC\0".to_vec(),
+                ),
+            ],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+        assert_eq!(232, elf_header.section_headers[1].sh_offset);
+
+        assert_eq!(
+            vec![None, Some(1), Some(2), None, None],
+            elf_header.section_to_segment_map()
+        );
+    }
+
+    #[test]
+    fn should_enumerate_the_gaps_between_load_segments() {
+        let input = generate_elf64_le_image(
+            Type::Exec,
+            vec![
+                program_header64(ProgramHeaderType::Load, 0x04, 0, 0x403000, 0, 0x1000),
+                program_header64(ProgramHeaderType::GnuStack, 0x04, 0, 0, 0, 0),
+                program_header64(ProgramHeaderType::Load, 0x04, 0, 0x400000, 0, 0x1000),
+            ],
+            vec![],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(vec![(0x401000, 0x403000)], elf_header.address_gaps());
+    }
+
+    #[test]
+    fn should_decode_dynamic_flags() {
+        let image = generate_dynamic_elf64_le_image(
+            Type::Dyn,
+            vec![
+                (DynamicTag::Flags, DF_BIND_NOW),
+                (DynamicTag::Flags1, DF_1_PIE | DF_1_NOW),
+            ],
+            b"\0",
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+
+        let (flags, flags_1) = elf_header.dynamic_flags(&image);
+        assert!(flags.bind_now());
+        assert!(!flags.textrel());
+        assert!(flags_1.now() && flags_1.pie());
+        assert!(!flags_1.nodelete());
+        assert_eq!("BIND_NOW", flags.to_string());
+        assert_eq!("Flags: NOW PIE", flags_1.to_string());
+        assert_eq!("ORIGIN 0x100", DfFlags(DF_ORIGIN | 0x100).to_string());
+    }
+
+    #[test]
+    fn should_report_the_natural_address_width_of_a_machine() {
+        assert_eq!(16, Machine::IntelMCU.natural_address_bits());
+        assert_eq!(16, Machine::AVR.natural_address_bits());
+        assert_eq!(32, Machine::ARM.natural_address_bits());
+        assert_eq!(32, Machine::RISCV.natural_address_bits());
+        assert_eq!(64, Machine::X86_64.natural_address_bits());
+    }
+
+    #[test]
+    fn should_reject_segments_overlapping_the_header_tables() {
+        let load = |offset, filesz| {
+            program_header64(
+                ProgramHeaderType::Load,
+                0x05,
+                offset,
+                0x400000 + offset,
+                filesz,
+                filesz,
+            )
+        };
+        // the file header, two program headers and two section headers
+        // occupy the first 304 bytes.
+        let build = |text| {
+            ElfHeaderBuilder::<ElfAddr64, LittleEndian>::new(Type::Exec, Machine::X86_64)
+                .program_header(load(0, 0x1000))
+                .program_header(text)
+                .section_header(section_header64(ShType::Null))
+                .section_header(section_header64(ShType::ProgBits))
+                .build()
+                .map(|eh| eh.file_header)
+        };
+
+        let file_header = build(load(0x1000, 0x200)).unwrap();
+        assert_eq!((64, 176), (file_header.ph_offset, file_header.sh_offset));
+        assert!(build(load(304, 0x200)).is_ok());
+
+        assert_eq!(
+            Err(ValidationError::SegmentOverlapsHeaders { segment: 1 }),
+            build(load(0x100, 0x200))
+        );
+        assert_eq!(
+            Err(ValidationError::SegmentOverlapsHeaders { segment: 1 }),
+            build(load(0x20, 0x10))
+        );
+        // straddling the end of the program header table, and within the
+        // section header table.
+        assert_eq!(
+            Err(ValidationError::SegmentOverlapsHeaders { segment: 1 }),
+            build(load(120, 0x100))
+        );
+        assert_eq!(
+            Err(ValidationError::SegmentOverlapsHeaders { segment: 1 }),
+            build(load(200, 0x10))
+        );
+    }
+
+    #[test]
+    fn should_collect_symbols_across_both_symbol_tables() {
+        let image = generate_elf64_le_image(
+            Type::Dyn,
+            vec![],
+            vec![
+                (
+                    ".strtab",
+                    section_header64(ShType::StrTab),
+                    b"\0main\0helper\0".to_vec(),
+                ),
+                (
+                    ".symtab",
+                    SectionHeader64 {
+                        sh_link: 1,
+                        sh_info: 2,
+                        sh_entsize: 24,
+                        ..section_header64(ShType::SymTab)
+                    },
+                    [
+                        symbol64(0, 0x00, 0, 0),
+                        symbol64(6, 0x02, 1, 0x1100),
+                        symbol64(1, 0x12, 1, 0x1000),
+                    ]
+                    .concat(),
+                ),
+                (
+                    ".dynstr",
+                    section_header64(ShType::StrTab),
+                    b"\0main\0puts\0".to_vec(),
+                ),
+                (
+                    ".dynsym",
+                    SectionHeader64 {
+                        sh_link: 3,
+                        sh_info: 1,
+                        sh_entsize: 24,
+                        ..section_header64(ShType::DynSym)
+                    },
+                    [
+                        symbol64(0, 0x00, 0, 0),
+                        symbol64(1, 0x12, 1, 0x1000),
+                        symbol64(6, 0x12, SHN_UNDEF, 0),
+                    ]
+                    .concat(),
+                ),
+            ],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        fn describe<'a>(symbols: Vec<ResolvedSymbol<'a>>) -> Vec<(&'a str, u64, SymbolSource)> {
+            symbols
+                .into_iter()
+                .map(|symbol| (symbol.name.unwrap(), symbol.value, symbol.source))
+                .collect()
+        }
+
+        assert_eq!(
+            vec![
+                ("helper", 0x1100, SymbolSource::SymTab),
+                ("main", 0x1000, SymbolSource::SymTab),
+                ("main", 0x1000, SymbolSource::DynSym),
+                ("puts", 0, SymbolSource::DynSym),
+            ],
+            describe(elf_header.all_symbols(&image))
+        );
+        assert_eq!(
+            vec![
+                ("helper", 0x1100, SymbolSource::SymTab),
+                ("main", 0x1000, SymbolSource::SymTab),
+                ("puts", 0, SymbolSource::DynSym),
+            ],
+            describe(elf_header.all_unique_symbols(&image))
+        );
+    }
+
+    #[test]
+    fn should_extract_the_tls_template() {
+        // .tdata follows the file header and two program headers at 176.
+        let tdata = [0x2a, 0x00, 0x00, 0x00, 0xef, 0xbe, 0xad, 0xde];
+        let input = generate_elf64_le_image(
+            Type::Exec,
+            vec![
+                program_header64(ProgramHeaderType::Load, 0x04, 176, 0x4000b0, 8, 8),
+                program_header64(ProgramHeaderType::Tls, 0x04, 176, 0x4000b0, 8, 0x20),
+            ],
+            vec![(".tdata", section_header64(ShType::ProgBits), tdata.to_vec())],
+        );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&input)
+            .unwrap()
+            .unwrap();
+
+        let template = elf_header.tls_template(&input).unwrap();
+        assert_eq!(elf_header.program_headers[1].filesz, template.len() as u64);
+        assert_eq!(&tdata, template);
+
+        let no_tls = generate_elf64_le_image(
+            Type::Exec,
+            vec![program_header64(
+                ProgramHeaderType::Load,
+                0x04,
+                176,
+                0x4000b0,
+                8,
+                8,
+            )],
             vec![],
         );
+        let elf_header = ElfHeaderParser::<ElfAddr64, LittleEndian>::new()
+            .parse(&no_tls)
+            .unwrap()
+            .unwrap();
+        assert_eq!(None, elf_header.tls_template(&no_tls));
+    }
 
-        assert_eq!(101, Into::<Vec<u8>>::into(elf_header).len())
+    #[test]
+    fn should_distinguish_standalone_from_an_unknown_osabi() {
+        let mut image = generate_elf64_le_image(Type::Exec, vec![], vec![]);
+        image[7] = 0xff;
+        let ident = EiIdentParser.parse(&image).unwrap().unwrap();
+        assert_eq!(EiOsAbi::Standalone, ident.ei_osabi);
+        assert_eq!("Standalone App", ident.ei_osabi.to_string());
+
+        image[7] = 0x99;
+        let ident = EiIdentParser::with_options(ParseOptions { strict: false })
+            .parse(&image)
+            .unwrap()
+            .unwrap();
+        assert_eq!(EiOsAbi::Unknown(0x99), ident.ei_osabi);
+        assert_eq!(&image[..16], &ident.serialize()[..]);
     }
 }
@@ -0,0 +1,51 @@
+use keebler::*;
+
+const FIXTURE: &[u8] = include_bytes!("fixtures/elf64_be_exec.bin");
+
+fn parse_fixture() -> ElfHeader64<BigEndian> {
+    match parse_elf(FIXTURE) {
+        Ok(ElfFile::Elf64Big(elf)) => elf,
+        other => panic!("expected a 64-bit big-endian file, got {:?}", other),
+    }
+}
+
+#[test]
+fn should_parse_a_64_bit_big_endian_executable() {
+    let elf = parse_fixture();
+
+    assert_eq!(EiClass::SixtyFourBit, elf.ei_ident.ei_class);
+    assert_eq!(EiData::Big, elf.ei_ident.ei_data);
+    assert_eq!(Type::Exec, elf.file_header.r#type);
+    assert_eq!(Machine::PPC64, elf.file_header.machine);
+    assert_eq!(0x1000_0138, elf.file_header.entry_point);
+    assert_eq!(1, elf.program_headers.len());
+    assert_eq!(3, elf.section_headers.len());
+
+    let load = &elf.program_headers[0];
+    assert_eq!(ProgramHeaderType::Load, load.r#type);
+    assert_eq!(0x1000_0000, load.vaddr);
+    assert_eq!(0x13c, load.filesz);
+
+    let text = elf.section_by_name(".text", FIXTURE).unwrap();
+    assert_eq!(ShType::ProgBits, text.sh_type);
+    assert_eq!(SHF_ALLOC | SHF_EXECINSTR, text.sh_flags());
+    assert_eq!(elf.file_header.entry_point, text.sh_addr);
+    assert_eq!(
+        Some(&[0x4e, 0x80, 0x00, 0x20][..]),
+        FIXTURE.get(text.sh_offset as usize..(text.sh_offset + text.sh_size) as usize)
+    );
+}
+
+#[test]
+fn should_round_trip_a_64_bit_big_endian_executable() {
+    let elf = parse_fixture();
+    let sh_offset = elf.file_header.sh_offset as usize;
+    let header_end = sh_offset + elf.section_headers.len() * elf.file_header.shent_size as usize;
+
+    let serialized: Vec<u8> = elf.clone().into();
+    assert_eq!(&FIXTURE[..header_end], &serialized[..]);
+
+    let mut image = serialized;
+    image.extend_from_slice(&FIXTURE[header_end..]);
+    assert_eq!(Ok(ElfFile::Elf64Big(elf)), parse_elf(&image));
+}
@@ -0,0 +1,32 @@
+use keebler::*;
+
+const FIXTURE: &[u8] = include_bytes!("fixtures/elf64_le_exec.bin");
+
+fn parse_fixture() -> ElfHeader64<LittleEndian> {
+    match parse_elf(FIXTURE) {
+        Ok(ElfFile::Elf64Little(elf)) => elf,
+        other => panic!("expected a 64-bit little-endian file, got {:?}", other),
+    }
+}
+
+#[test]
+fn should_round_trip_a_64_bit_little_endian_executable() {
+    let elf = parse_fixture();
+    assert_eq!(Machine::X86_64, elf.file_header.machine);
+    assert_eq!(0x40_0078, elf.file_header.entry_point);
+    assert_eq!(1, elf.program_headers.len());
+    assert!(elf.section_headers.is_empty());
+
+    let ph_offset = elf.file_header.ph_offset as usize;
+    let header_end = ph_offset + elf.program_headers.len() * elf.file_header.phent_size as usize;
+
+    // the file and program headers are followed directly by the code of the
+    // only segment, with no section header table.
+    let serialized: Vec<u8> = elf.clone().into();
+    assert_eq!(&FIXTURE[..header_end], &serialized[..]);
+
+    let mut image = serialized;
+    image.extend_from_slice(&FIXTURE[header_end..]);
+    assert_eq!(FIXTURE, &image[..]);
+    assert_eq!(Ok(ElfFile::Elf64Little(elf)), parse_elf(&image));
+}
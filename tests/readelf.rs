@@ -0,0 +1,160 @@
+use std::process::Command;
+
+const FIXTURE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/elf64_le_exec.bin"
+);
+
+const NOTES_FIXTURE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/elf64_le_notes.bin"
+);
+
+#[test]
+fn should_hex_dump_the_first_load_segment() {
+    let output = Command::new(env!("CARGO_BIN_EXE_readelf"))
+        .args(["-p", "0", FIXTURE])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!("Hex dump of segment 0:", lines[1]);
+    assert_eq!(
+        "  0x00400000 7f454c46 02010100 00000000 00000000 .ELF............",
+        lines[2]
+    );
+    assert_eq!(
+        "  0x00400080 0f05                                ..",
+        lines[10]
+    );
+    assert_eq!(11, lines.len());
+}
+
+#[test]
+fn should_reject_an_out_of_range_segment_index() {
+    let output = Command::new(env!("CARGO_BIN_EXE_readelf"))
+        .args(["-p", "1", FIXTURE])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("segment index 1 out of range"));
+}
+
+#[test]
+fn should_render_the_entry_point_in_the_requested_base() {
+    let entry_point = |flag: Option<&str>| {
+        let output = Command::new(env!("CARGO_BIN_EXE_readelf"))
+            .args(flag.into_iter().chain(Some(FIXTURE)))
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .find(|line| line.contains("Entry point address:"))
+            .map(|line| line.split_whitespace().last().unwrap().to_string())
+    };
+
+    assert_eq!(Some("0x400078".to_string()), entry_point(None));
+    assert_eq!(Some("0x400078".to_string()), entry_point(Some("--hex")));
+    assert_eq!(Some("4194424".to_string()), entry_point(Some("--decimal")));
+}
+
+#[test]
+fn should_dump_decoded_notes() {
+    let output = Command::new(env!("CARGO_BIN_EXE_readelf"))
+        .args(["-n", NOTES_FIXTURE])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert_eq!("Displaying notes:", lines[1]);
+    assert_eq!(
+        "  GNU                  0x00000010\tNT_GNU_ABI_TAG (ABI version tag)",
+        lines[3]
+    );
+    assert_eq!("    OS: Linux, ABI: 3.2.0", lines[4]);
+    assert_eq!(
+        "  GNU                  0x00000014\tNT_GNU_BUILD_ID (unique build ID bitstring)",
+        lines[5]
+    );
+    assert_eq!(
+        "    Build ID: 8f3a1c5e0b9d4f62a7e1c3b5d9f0a2e4c6b8d0f1",
+        lines[6]
+    );
+    assert_eq!(7, lines.len());
+}
+
+#[test]
+fn should_report_files_without_notes() {
+    let output = Command::new(env!("CARGO_BIN_EXE_readelf"))
+        .args(["-n", FIXTURE])
+        .output()
+        .unwrap();
+
+    assert!(output.status.success());
+    assert!(String::from_utf8(output.stdout)
+        .unwrap()
+        .contains("There are no notes in this file."));
+}
+
+#[test]
+fn should_filter_section_headers_by_flag() {
+    let section_types = |args: &[&str]| {
+        let output = Command::new(env!("CARGO_BIN_EXE_readelf"))
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .lines()
+            .skip(4)
+            .step_by(2)
+            .map(|line| line.split_whitespace().nth(1).unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        vec!["NULL", "NOTE", "NOTE", "STR_TAB"],
+        section_types(&["-S", NOTES_FIXTURE])
+    );
+    assert_eq!(
+        vec!["NOTE", "NOTE"],
+        section_types(&["-S", "A", NOTES_FIXTURE])
+    );
+    assert!(section_types(&["-S", "AX", NOTES_FIXTURE]).is_empty());
+}
+
+#[test]
+fn should_report_non_elf_input_as_an_error() {
+    let manifest = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+
+    for args in [
+        vec![manifest],
+        vec!["-n", manifest],
+        vec!["-S", manifest],
+        vec!["-p", "0", manifest],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_readelf"))
+            .args(&args)
+            .output()
+            .unwrap();
+
+        assert_eq!(Some(1), output.status.code());
+        assert!(String::from_utf8(output.stderr)
+            .unwrap()
+            .contains("not an ELF file"));
+    }
+}